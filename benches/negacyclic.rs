@@ -0,0 +1,38 @@
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+use polynomials::Polynomial;
+
+const N: u32 = 1024;
+
+/// Returns a dense polynomial of degree `N - 1` with small integer coefficients.
+fn ring_element(seed: u32) -> Polynomial {
+    let mut poly = Polynomial::zero();
+    for power in 0..N {
+        let coefficient = ((power * 31 + seed) % 7) as f64 - 3.0;
+        poly.set_coefficient_at(power, coefficient);
+    }
+    poly
+}
+
+fn mul_negacyclic(c: &mut Criterion) {
+    let (p, q) = (ring_element(1), ring_element(5));
+    c.bench_function("mul_negacyclic n = 1024", |b| {
+        b.iter(|| black_box(&p).mul_negacyclic(black_box(&q), N))
+    });
+}
+
+fn mul_mod_binomial(c: &mut Criterion) {
+    let (p, q) = (ring_element(1), ring_element(5));
+    let mut modulus = Polynomial::one();
+    modulus.set_coefficient_at(N, 1.0);
+    c.bench_function("mul_mod x^1024 + 1", |b| {
+        b.iter(|| black_box(&p).mul_mod(black_box(&q), &modulus))
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = mul_negacyclic, mul_mod_binomial
+}
+criterion_main!(benches);
@@ -0,0 +1,40 @@
+use std::hint::black_box;
+use criterion::{criterion_group, criterion_main, Criterion};
+use polynomials::Polynomial;
+
+/// Returns a string of a polynomial with the given number of terms.
+fn long_polynomial_string(terms: u32) -> String {
+    let mut poly = Polynomial::zero();
+    for power in 0..terms {
+        let coefficient = (power % 17) as f64 - 8.5;
+        poly.set_coefficient_at(power, coefficient);
+    }
+    poly.to_string()
+}
+
+fn parse_long_polynomial(c: &mut Criterion) {
+    let string = long_polynomial_string(10_000);
+    c.bench_function("parse 10k-term polynomial", |b| {
+        b.iter(|| black_box(&string).parse::<Polynomial>().unwrap())
+    });
+}
+
+fn parse_small_polynomials(c: &mut Criterion) {
+    let strings: Vec<String> = (0..100_000)
+        .map(|i| format!("{}x^3 - {}x + {}", i % 10, i % 7, i % 13))
+        .collect();
+    c.bench_function("parse 100k small polynomials", |b| {
+        b.iter(|| {
+            for string in strings.iter() {
+                black_box(string.parse::<Polynomial>().unwrap());
+            }
+        })
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = parse_long_polynomial, parse_small_polynomials
+}
+criterion_main!(benches);
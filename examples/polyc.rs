@@ -0,0 +1,244 @@
+//! A command-line calculator of polynomials.
+//!
+//! ```text
+//! polyc eval "2x^2 - 3x + 1" --at 1.5
+//! polyc add "x + 1" --coeffs 1,0,-1
+//! polyc divrem "x^3 - 1" "x - 1"
+//! polyc derive "x^3" --latex
+//! ```
+//!
+//! The polynomials are given as strings or as comma-separated coefficients after `--coeffs`,
+//! from the highest power down. The results are printed in the standard format, or with
+//! `--latex`, `--unicode` or `--json`, the last of which requires the `serde` feature.
+//!
+//! The exit code is 0 on success, 1 if a polynomial cannot be parsed, 2 on invalid usage and
+//! 3 on a division by zero.
+use std::fmt;
+use std::process::ExitCode;
+use polynomials::{Polynomial, PolynomialFormat};
+
+const USAGE: &str = "\
+Usage: polyc <command> [polynomials] [options]
+
+Commands:
+  eval <p> --at <x>   evaluate p at x
+  add <p> <q>         print p + q
+  sub <p> <q>         print p - q
+  mul <p> <q>         print p * q
+  divrem <p> <q>      print the quotient and the remainder of p / q
+  derive <p>          print the derivative of p
+  fmt <p>             print p in the selected format
+
+Polynomials are strings, or comma-separated coefficients after --coeffs.
+
+Options:
+  --latex     print in the LaTeX format
+  --unicode   print with superscript powers
+  --json      print as JSON (requires the serde feature)";
+
+enum Error {
+    Parse(String),
+    Usage(String),
+    DivisionByZero,
+}
+
+impl Error {
+    fn exit_code(&self) -> u8 {
+        match self {
+            Error::Parse(_) => 1,
+            Error::Usage(_) => 2,
+            Error::DivisionByZero => 3,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse(message) => write!(f, "error: {message}"),
+            Error::Usage(message) => write!(f, "error: {message}\n\n{USAGE}"),
+            Error::DivisionByZero => write!(f, "error: division by the zero polynomial"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Output {
+    Standard,
+    Latex,
+    Unicode,
+    Json,
+}
+
+/// A result of a command, printed in the selected output format.
+enum Value {
+    Number(f64),
+    Polynomial(Polynomial),
+    Division { quotient: Polynomial, remainder: Polynomial },
+}
+
+struct Arguments {
+    command: String,
+    polynomials: Vec<Polynomial>,
+    at: Option<f64>,
+    output: Output,
+}
+
+fn parse_coefficients(text: &str) -> Result<Polynomial, Error> {
+    let coefficients = text
+        .split(',')
+        .map(|coefficient| coefficient.trim().parse::<f64>())
+        .collect::<Result<Vec<f64>, _>>()
+        .map_err(|_| Error::Parse(format!("invalid coefficients '{text}'")))?;
+    Ok(Polynomial::from_coefficients(&coefficients))
+}
+
+fn parse_arguments(args: impl IntoIterator<Item = String>) -> Result<Arguments, Error> {
+    let mut args = args.into_iter();
+    let command = args.next().ok_or_else(|| Error::Usage("missing command".to_string()))?;
+    let mut polynomials = Vec::new();
+    let mut at = None;
+    let mut output = Output::Standard;
+
+    while let Some(arg) = args.next() {
+        let mut value = |option: &str| {
+            args.next().ok_or_else(|| Error::Usage(format!("missing value of {option}")))
+        };
+        match arg.as_str() {
+            "--at" => {
+                let x = value("--at")?;
+                at = Some(x.parse().map_err(|_| Error::Usage(format!("invalid number '{x}'")))?);
+            }
+            "--coeffs" => polynomials.push(parse_coefficients(&value("--coeffs")?)?),
+            "--latex" => output = Output::Latex,
+            "--unicode" => output = Output::Unicode,
+            "--json" => output = Output::Json,
+            option if option.starts_with("--") => {
+                return Err(Error::Usage(format!("unknown option {option}")));
+            }
+            string => polynomials.push(
+                string.parse().map_err(|err| Error::Parse(format!("{err}")))?
+            ),
+        }
+    }
+    Ok(Arguments { command, polynomials, at, output })
+}
+
+fn run(arguments: Arguments) -> Result<Value, Error> {
+    let arity = match arguments.command.as_str() {
+        "eval" | "derive" | "fmt" => 1,
+        "add" | "sub" | "mul" | "divrem" => 2,
+        command => return Err(Error::Usage(format!("unknown command {command}"))),
+    };
+    if arguments.polynomials.len() != arity {
+        return Err(Error::Usage(format!(
+            "{} expects {arity} polynomial(s), got {}",
+            arguments.command,
+            arguments.polynomials.len()
+        )));
+    }
+    let mut polynomials = arguments.polynomials.into_iter();
+    let p = polynomials.next().unwrap();
+
+    Ok(match arguments.command.as_str() {
+        "eval" => {
+            let x = arguments.at.ok_or_else(|| Error::Usage("eval requires --at".to_string()))?;
+            Value::Number(p.evaluate(x))
+        }
+        "derive" => Value::Polynomial(p.derivative()),
+        "fmt" => Value::Polynomial(p),
+        command => {
+            let q = polynomials.next().unwrap();
+            match command {
+                "add" => Value::Polynomial(p + q),
+                "sub" => Value::Polynomial(p - q),
+                "mul" => Value::Polynomial(p * q),
+                _ => {
+                    if q.is_zero() {
+                        return Err(Error::DivisionByZero);
+                    }
+                    let result = p / q;
+                    Value::Division { quotient: result.quotient, remainder: result.remainder }
+                }
+            }
+        }
+    })
+}
+
+/// Replaces the powers after carets with superscript digits.
+fn to_unicode(text: &str) -> String {
+    const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    let mut result = String::with_capacity(text.len());
+    let mut in_power = false;
+    for c in text.chars() {
+        match c {
+            '^' => in_power = true,
+            '0'..='9' if in_power => result.push(SUPERSCRIPTS[c as usize - '0' as usize]),
+            _ => {
+                in_power = false;
+                result.push(c);
+            }
+        }
+    }
+    result
+}
+
+fn format_polynomial(poly: &Polynomial, output: Output) -> String {
+    match output {
+        Output::Latex => poly.format_with(PolynomialFormat::Latex),
+        Output::Unicode => to_unicode(&poly.to_string()),
+        _ => poly.to_string(),
+    }
+}
+
+#[cfg(feature = "serde")]
+fn to_json(value: &Value) -> String {
+    let json = match value {
+        Value::Number(number) => serde_json::json!(number),
+        Value::Polynomial(poly) => serde_json::json!(poly),
+        Value::Division { quotient, remainder } => {
+            serde_json::json!({ "quotient": quotient, "remainder": remainder })
+        }
+    };
+    json.to_string()
+}
+
+#[cfg(not(feature = "serde"))]
+fn to_json(_: &Value) -> String {
+    unreachable!("--json is rejected without the serde feature")
+}
+
+fn print(value: &Value, output: Output) {
+    if output == Output::Json {
+        println!("{}", to_json(value));
+        return;
+    }
+    match value {
+        Value::Number(number) => println!("{number}"),
+        Value::Polynomial(poly) => println!("{}", format_polynomial(poly, output)),
+        Value::Division { quotient, remainder } => {
+            println!("quotient: {}", format_polynomial(quotient, output));
+            println!("remainder: {}", format_polynomial(remainder, output));
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let result = parse_arguments(std::env::args().skip(1)).and_then(|arguments| {
+        if arguments.output == Output::Json && cfg!(not(feature = "serde")) {
+            return Err(Error::Usage("--json requires the serde feature".to_string()));
+        }
+        let output = arguments.output;
+        run(arguments).map(|value| (value, output))
+    });
+    match result {
+        Ok((value, output)) => {
+            print(&value, output);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::from(err.exit_code())
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! Python bindings of the `polynomials` crate.
+//!
+//! The module is built with [maturin](https://www.maturin.rs) and exposes the `Polynomial`
+//! class, a wrapper of [`polynomials::Polynomial`].
+use pyo3::exceptions::{PyValueError, PyZeroDivisionError};
+use pyo3::prelude::*;
+use polynomials::{Polynomial, PolynomialFormat};
+
+/// A univariate polynomial with real coefficients.
+///
+/// The constructor accepts a sequence of coefficients, from the highest power down, e.g. a
+/// list or a numpy array, or a string such as `"2x^2 - 1"`.
+#[pyclass(name = "Polynomial", eq, from_py_object)]
+#[derive(PartialEq, Clone)]
+struct PyPolynomial {
+    inner: Polynomial,
+}
+
+/// The right-hand side of an arithmetic operation, either a polynomial or a number.
+#[derive(FromPyObject)]
+enum Operand {
+    Polynomial(PyPolynomial),
+    Scalar(f64),
+}
+
+impl Operand {
+    fn into_polynomial(self) -> Polynomial {
+        match self {
+            Operand::Polynomial(poly) => poly.inner,
+            Operand::Scalar(scalar) => Polynomial::zero() + scalar,
+        }
+    }
+}
+
+impl From<Polynomial> for PyPolynomial {
+    fn from(inner: Polynomial) -> Self {
+        PyPolynomial { inner }
+    }
+}
+
+#[pymethods]
+impl PyPolynomial {
+    #[new]
+    fn new(value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(string) = value.extract::<&str>() {
+            return Polynomial::from_string(string)
+                .map(PyPolynomial::from)
+                .map_err(|err| PyValueError::new_err(err.to_string()));
+        }
+        let coefficients: Vec<f64> = value.extract()?;
+        Ok(Polynomial::from_coefficients(&coefficients).into())
+    }
+
+    /// The coefficients from the highest power down.
+    #[getter]
+    fn coefficients(&self) -> Vec<f64> {
+        self.inner.get_coefficients()
+    }
+
+    /// The degree of the polynomial, or `None` for the zero polynomial.
+    #[getter]
+    fn degree(&self) -> Option<u32> {
+        self.inner.degree()
+    }
+
+    fn derivative(&self) -> Self {
+        self.inner.derivative().into()
+    }
+
+    fn __call__(&self, x: f64) -> f64 {
+        self.inner.evaluate(x)
+    }
+
+    fn __add__(&self, other: Operand) -> Self {
+        (self.inner.clone() + other.into_polynomial()).into()
+    }
+
+    fn __radd__(&self, other: Operand) -> Self {
+        (other.into_polynomial() + &self.inner).into()
+    }
+
+    fn __sub__(&self, other: Operand) -> Self {
+        (self.inner.clone() - other.into_polynomial()).into()
+    }
+
+    fn __rsub__(&self, other: Operand) -> Self {
+        (other.into_polynomial() - &self.inner).into()
+    }
+
+    fn __mul__(&self, other: Operand) -> Self {
+        (self.inner.clone() * other.into_polynomial()).into()
+    }
+
+    fn __rmul__(&self, other: Operand) -> Self {
+        (other.into_polynomial() * &self.inner).into()
+    }
+
+    fn __neg__(&self) -> Self {
+        (-&self.inner).into()
+    }
+
+    fn __divmod__(&self, other: Operand) -> PyResult<(Self, Self)> {
+        let denominator = other.into_polynomial();
+        if denominator.is_zero() {
+            return Err(PyZeroDivisionError::new_err("Cannot divide by the zero polynomial."));
+        }
+        let result = self.inner.clone() / denominator;
+        Ok((result.quotient.into(), result.remainder.into()))
+    }
+
+    fn __floordiv__(&self, other: Operand) -> PyResult<Self> {
+        Ok(self.__divmod__(other)?.0)
+    }
+
+    fn __mod__(&self, other: Operand) -> PyResult<Self> {
+        Ok(self.__divmod__(other)?.1)
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Polynomial('{}')", self.inner)
+    }
+
+    /// Returns the LaTeX representation rendered by Jupyter.
+    fn _repr_latex_(&self) -> String {
+        format!("${}$", self.inner.format_with(PolynomialFormat::Latex))
+    }
+}
+
+#[pymodule]
+#[pyo3(name = "polynomials")]
+fn polynomials_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyPolynomial>()
+}
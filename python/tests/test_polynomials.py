@@ -0,0 +1,59 @@
+import pytest
+
+from polynomials import Polynomial
+
+
+def test_construct_from_list_and_string():
+    assert Polynomial([2, 0, -1]) == Polynomial("2x^2 - 1")
+    assert Polynomial([0, 0, 3, 1]).coefficients == [3.0, 1.0]
+    assert Polynomial([]).degree is None
+    assert Polynomial("x^3 + x").degree == 3
+
+
+def test_construct_from_numpy_array():
+    numpy = pytest.importorskip("numpy")
+    assert Polynomial(numpy.array([1.0, -2.0, 1.0])) == Polynomial("x^2 - 2x + 1")
+
+
+def test_invalid_string_raises_value_error():
+    with pytest.raises(ValueError, match="Unexpected input"):
+        Polynomial("2x^2 + * 1")
+
+
+def test_call_evaluates():
+    poly = Polynomial([1, 0, -1])
+    assert poly(3) == 8.0
+    assert poly(0.5) == -0.75
+
+
+def test_arithmetic():
+    p = Polynomial("x + 1")
+    q = Polynomial("x - 1")
+    assert p + q == Polynomial("2x")
+    assert p - q == Polynomial("2")
+    assert p * q == Polynomial("x^2 - 1")
+    assert -p == Polynomial("-x - 1")
+    assert 2 * p == p + p
+    assert p + 1 == Polynomial("x + 2")
+    assert 1 - p == Polynomial("-x")
+
+
+def test_divmod():
+    quotient, remainder = divmod(Polynomial("x^3 - 2x + 1"), Polynomial("x - 2"))
+    assert quotient == Polynomial("x^2 + 2x + 2")
+    assert remainder == Polynomial("5")
+    assert Polynomial("x^2 - 1") // Polynomial("x + 1") == Polynomial("x - 1")
+    assert Polynomial("x^2") % Polynomial("x + 1") == Polynomial("1")
+    with pytest.raises(ZeroDivisionError):
+        divmod(Polynomial("x"), Polynomial([]))
+
+
+def test_derivative():
+    assert Polynomial("x^3 - 2x").derivative() == Polynomial("3x^2 - 2")
+
+
+def test_representations():
+    poly = Polynomial("2x^2 - 1")
+    assert str(poly) == "2x^2 - 1"
+    assert repr(poly) == "Polynomial('2x^2 - 1')"
+    assert poly._repr_latex_().startswith("$") and poly._repr_latex_().endswith("$")
@@ -1,6 +1,18 @@
 //! A library for working with univariate polynomials.
 //!
 //! The library exposes the [`Polynomial`] struct, which provides all the functionality.
+//! The [`LaurentPolynomial`] struct additionally allows the negative powers of the
+//! indeterminate.
+//!
+//! The optional `serde` feature implements the `Serialize` and `Deserialize` traits for
+//! [`Polynomial`], see the `serialization` module for the available representations. The
+//! optional `proptest` and `arbitrary` features provide the generators of polynomials for
+//! property testing and fuzzing, see the `strategy` module. The optional `nalgebra` feature
+//! adds conversions to coefficient vectors and the matrices of multiplication and
+//! differentiation, and the optional `ndarray` feature adds conversions to arrays of
+//! coefficients and the evaluation at arrays of points. The optional `plotters` feature
+//! adds plotting polynomials to SVG files, and the optional `num-traits` feature implements
+//! the `Pow<u32>` trait.
 //!
 //! # Examples
 //! 
@@ -91,6 +103,11 @@
 //! let poly = Polynomial::from_coefficients(&vec![1.0, -2.0]);
 //! let poly_times_five = Polynomial::from_coefficients(&vec![5.0, -10.0]);
 //! assert_eq!(poly_times_five, poly * 5.0);
+//!
+//! // scalars may also be written on the left-hand side
+//! let poly = Polynomial::from_coefficients(&vec![1.0, -2.0]);
+//! let result = Polynomial::from_coefficients(&vec![3.0, -5.0]);
+//! assert_eq!(result, 3.0 * poly + 1.0);
 //! 
 //! // division
 //! let numerator = Polynomial::from_coefficients(&vec![1.0, -2.0, -5.0, 6.0]);
@@ -111,5 +128,17 @@
 
 mod polynomial;
 
-pub use polynomial::Polynomial;
-pub use polynomial::display::PolynomialFormat;
\ No newline at end of file
+pub use polynomial::{
+    convolve_slices, CoefficientSeparator, CoefficientsMut, CrtError, DivisionStep,
+    DivisionTrace, Interval, LaurentPolynomial, NonFiniteCoefficientError, Polynomial, ReadError,
+    Sign, SizeMismatch, SolveError, SparseParseError,
+};
+pub use polynomial::degree::Degree;
+pub use polynomial::expression::ExpressionLang;
+pub use polynomial::parsing::{ParseOptions, PolynomialParsingError};
+pub use polynomial::encoding::{self, DecodeError};
+pub use polynomial::display::{FormatOptions, PolynomialFormat, TermOrder};
+#[cfg(feature = "serde")]
+pub use polynomial::serialization;
+#[cfg(feature = "proptest")]
+pub use polynomial::strategy;
\ No newline at end of file
@@ -1,8 +1,55 @@
+use std::cmp::Ordering;
 use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
 mod coefficients;
-mod parsing;
+pub mod parsing;
 mod arithmetic;
+mod composition;
+mod modular;
+mod positivity;
+mod inequality;
+mod roots;
+mod sturm;
+mod discriminant;
+mod intersection;
+mod orthogonal;
+mod associates;
+mod quadrature;
+mod scaling;
+mod integer;
+mod complex;
+mod fft;
+mod sparse;
 pub mod display;
+pub mod degree;
+pub mod expression;
+pub mod encoding;
+mod laurent;
+mod text_io;
+mod variable;
+#[cfg(feature = "serde")]
+pub mod serialization;
+#[cfg(any(test, feature = "proptest"))]
+pub mod strategy;
+#[cfg(any(test, feature = "arbitrary"))]
+mod fuzzing;
+#[cfg(any(test, feature = "nalgebra"))]
+mod linalg;
+#[cfg(any(test, feature = "ndarray"))]
+mod array;
+#[cfg(any(test, feature = "plotters"))]
+mod plot;
+
+use degree::Degree;
+pub use arithmetic::{convolve_slices, DivisionStep, DivisionTrace};
+pub use coefficients::{CoefficientsMut, NonFiniteCoefficientError, SizeMismatch};
+pub use modular::CrtError;
+pub use inequality::{Interval, Sign};
+pub use roots::SolveError;
+pub use laurent::LaurentPolynomial;
+pub use text_io::{CoefficientSeparator, ReadError};
+pub use sparse::SparseParseError;
 
 /// Represents a univariate polynomial with real coefficients.
 ///
@@ -20,7 +67,7 @@ pub mod display;
 /// let value = derivative.evaluate(2.0);
 /// assert_eq!(5.0, value);
 /// ```
-#[derive(PartialEq, Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Polynomial {
     coefficients: BTreeMap<u32, f64>,
 }
@@ -42,6 +89,22 @@ impl Polynomial {
         }
     }
 
+    /// Returns a new constant polynomial equal to one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::one();
+    /// assert_eq!(vec![1.0], poly.get_coefficients());
+    /// ```
+    pub fn one() -> Polynomial {
+        let mut poly = Polynomial::zero();
+        poly.set_coefficient_at(0, 1.0);
+        poly
+    }
+
     /// Checks if the polynomial is a zero polynomial.
     ///
     /// # Examples
@@ -82,7 +145,27 @@ impl Polynomial {
     /// assert!(poly.degree().is_none());
     /// ```
     pub fn degree(&self) -> Option<u32> {
-        self.coefficients.keys().rev().next().copied()
+        self.coefficients.keys().next_back().copied()
+    }
+
+    /// Returns the degree of the polynomial as a [`Degree`].
+    ///
+    /// Unlike [`degree`](Polynomial::degree), this method assigns the degree
+    /// `Degree::NegInfinity` to the zero polynomial, so the degrees of any two polynomials
+    /// can be compared and added directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{Degree, Polynomial};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![2.0, -1.0, 1.0]);
+    /// assert_eq!(Degree::Finite(2), poly.deg());
+    /// assert_eq!(Degree::NegInfinity, Polynomial::zero().deg());
+    /// assert!(poly.deg() > Polynomial::zero().deg());
+    /// ```
+    pub fn deg(&self) -> Degree {
+        Degree::from(self.degree())
     }
 
     /// Sets all coefficients to zero.
@@ -100,6 +183,22 @@ impl Polynomial {
         self.coefficients.clear();
     }
 
+    /// Removes the terms whose coefficients became zero, e.g. after an underflow.
+    ///
+    /// Methods that modify the stored coefficients directly, bypassing
+    /// `set_coefficient_at`, must call this afterward so that no zeros are present in the map.
+    fn remove_zero_coefficients(&mut self) {
+        self.coefficients.retain(|_, coefficient| *coefficient != 0.0);
+    }
+
+    /// Panics in debug builds if a zero coefficient is stored in the map.
+    fn debug_assert_normalized(&self) {
+        debug_assert!(
+            self.coefficients.values().all(|coefficient| *coefficient != 0.0),
+            "Zero coefficient stored in the polynomial."
+        );
+    }
+
     /// Evaluates the polynomial at a given x using Horner's method.
     ///
     /// # Examples
@@ -123,9 +222,69 @@ impl Polynomial {
             result += coefficient;
             last_power = Some(*power);
         }
+
+        // Multiply by the lowest power of x present in the polynomial
+        if let Some(last_x_power) = last_power {
+            result *= x.powi(last_x_power as i32);
+        }
         result
     }
 
+    /// Evaluates the polynomial at every point and writes the values into the beginning of the
+    /// buffer, without allocating memory. The rest of the buffer is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeMismatch`] and leaves the buffer unchanged if it is shorter than the
+    /// slice of points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -1.0]);
+    /// let mut values = [0.0; 3];
+    /// poly.evaluate_many_into(&[-2.0, 0.0, 2.0], &mut values).unwrap();
+    /// assert_eq!([3.0, -1.0, 3.0], values);
+    /// ```
+    pub fn evaluate_many_into(&self, xs: &[f64], buffer: &mut [f64]) -> Result<(), SizeMismatch> {
+        let actual = buffer.len();
+        let output = buffer.get_mut(..xs.len()).ok_or(SizeMismatch { required: xs.len(), actual })?;
+        for (value, x) in output.iter_mut().zip(xs) {
+            *value = self.evaluate(*x);
+        }
+        Ok(())
+    }
+
+    /// Returns `n` evenly spaced points of the range, including both endpoints, paired with the
+    /// values of the polynomial at them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is less than 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0]);
+    /// let points = poly.sample(-1.0..1.0, 5);
+    /// assert_eq!(vec![(-1.0, 1.0), (-0.5, 0.25), (0.0, 0.0), (0.5, 0.25), (1.0, 1.0)], points);
+    /// ```
+    pub fn sample(&self, range: Range<f64>, n: usize) -> Vec<(f64, f64)> {
+        assert!(n >= 2, "Cannot sample fewer than two points.");
+        let step = (range.end - range.start) / (n - 1) as f64;
+        (0..n)
+            .map(|i| {
+                // Avoid the accumulated rounding error at the end of the range
+                let x = if i == n - 1 { range.end } else { range.start + step * i as f64 };
+                (x, self.evaluate(x))
+            })
+            .collect()
+    }
+
     /// Returns the derivative of a polynomial function.
     ///
     /// # Examples
@@ -149,11 +308,301 @@ impl Polynomial {
         }
         result
     }
+
+    /// Replaces the polynomial with its derivative and returns it for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let mut poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 0.0, -10.0]);
+    /// poly.derivative_in_place().derivative_in_place();
+    /// assert_eq!(vec![6.0, -4.0], poly.get_coefficients());
+    /// ```
+    pub fn derivative_in_place(&mut self) -> &mut Self {
+        *self = self.derivative();
+        self
+    }
+
+    /// Returns the `k`-th derivative of a polynomial function.
+    ///
+    /// Every term is differentiated in a single pass, with its coefficient multiplied by the
+    /// falling factorial `power (power - 1) ... (power - k + 1)`. The zeroth derivative is the
+    /// polynomial itself, and the derivatives of the order greater than the degree are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 0.0, -10.0]);
+    /// assert_eq!(vec![6.0, -4.0], poly.nth_derivative(2).get_coefficients());
+    /// assert_eq!(poly, poly.nth_derivative(0));
+    /// assert_eq!(Polynomial::zero(), poly.nth_derivative(4));
+    /// ```
+    pub fn nth_derivative(&self, k: u32) -> Self {
+        let mut result = Polynomial::zero();
+        for (power, coefficient) in self.coefficients.range(k..) {
+            let falling_factorial: f64 = (*power - k..*power).map(|p| f64::from(p) + 1.0).product();
+            result.set_coefficient_at(*power - k, *coefficient * falling_factorial);
+        }
+        result
+    }
+
+    /// Returns the antiderivative of a polynomial function with the zero constant of
+    /// integration, i.e. the one that is zero at zero.
+    ///
+    /// Every coefficient is divided by its power increased by one, so the derivative of the
+    /// antiderivative is equal to the polynomial up to the rounding errors of the divisions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polynomial has a term of the power `u32::MAX`, whose antiderivative
+    /// cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![3.0, -4.0, 1.0]);
+    /// assert_eq!(vec![1.0, -2.0, 1.0, 0.0], poly.antiderivative().get_coefficients());
+    /// assert_eq!(poly, poly.antiderivative().derivative());
+    /// ```
+    pub fn antiderivative(&self) -> Self {
+        self.antiderivative_with_constant(0.0)
+    }
+
+    /// Returns the antiderivative of a polynomial function with the given constant of
+    /// integration, which is its value at zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the polynomial has a term of the power `u32::MAX`, whose antiderivative
+    /// cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![2.0, 0.0]);
+    /// let antiderivative = poly.antiderivative_with_constant(5.0);
+    /// assert_eq!(vec![1.0, 0.0, 5.0], antiderivative.get_coefficients());
+    /// ```
+    pub fn antiderivative_with_constant(&self, constant: f64) -> Self {
+        let mut result = Polynomial::zero();
+        for (power, coefficient) in self.coefficients.iter() {
+            let next_power = power.checked_add(1).expect("Cannot integrate a term of the power u32::MAX.");
+            result.set_coefficient_at(next_power, *coefficient / (*power as f64 + 1.0));
+        }
+        result.set_coefficient_at(0, constant);
+        result
+    }
+}
+
+/// Returns the coefficient with every `NaN` replaced by the same `NaN` value, so that the
+/// equality, hashing and ordering of polynomials agree with each other.
+fn canonical(coefficient: f64) -> f64 {
+    if coefficient.is_nan() { f64::NAN } else { coefficient }
+}
+
+/// Polynomials are equal when all of their coefficients are equal.
+///
+/// Unlike for `f64`, all `NaN` coefficients are equal to each other, so that the equality is
+/// reflexive and polynomials with `NaN` coefficients can be used as keys in hash-based or
+/// sorted collections.
+///
+/// # Examples
+///
+/// ```
+/// use polynomials::Polynomial;
+///
+/// let poly = Polynomial::from_coefficients(&vec![1.0, f64::NAN]);
+/// assert_eq!(poly, poly.clone());
+/// assert_eq!(poly, Polynomial::from_coefficients(&vec![1.0, -f64::NAN]));
+/// ```
+impl PartialEq for Polynomial {
+    fn eq(&self, other: &Self) -> bool {
+        self.coefficients.len() == other.coefficients.len()
+            && self.coefficients.iter().zip(other.coefficients.iter()).all(
+                |((power, coefficient), (other_power, other_coefficient))| {
+                    power == other_power
+                        && canonical(*coefficient).to_bits() == canonical(*other_coefficient).to_bits()
+                }
+            )
+    }
+}
+
+impl Eq for Polynomial {}
+
+/// A polynomial is equal to a scalar when it is the constant polynomial equal to it. The zero
+/// polynomial is equal to `0.0` and `-0.0`.
+///
+/// Since `Polynomial` can be compared with several types, the type of the other operand must
+/// be known, e.g. `assert_eq!(poly, serde_json::from_str(&json).unwrap())` needs a
+/// `from_str::<Polynomial>` annotation.
+///
+/// # Examples
+///
+/// ```
+/// use polynomials::Polynomial;
+///
+/// assert_eq!(Polynomial::from_coefficients(&vec![0.0, 5.0]), 5.0);
+/// assert_eq!(0.0, Polynomial::zero());
+/// assert_ne!(Polynomial::from_coefficients(&vec![1.0, 5.0]), 5.0);
+/// ```
+impl PartialEq<f64> for Polynomial {
+    fn eq(&self, other: &f64) -> bool {
+        match self.degree() {
+            None => *other == 0.0,
+            Some(0) => self.get_coefficient_at(0) == *other,
+            Some(_) => false,
+        }
+    }
+}
+
+impl PartialEq<Polynomial> for f64 {
+    fn eq(&self, other: &Polynomial) -> bool {
+        other == self
+    }
+}
+
+/// A polynomial is equal to a slice of coefficients in the descending order of powers, as
+/// returned by [`Polynomial::get_coefficients`], when the polynomial created from them by
+/// [`Polynomial::from_coefficients`] is equal to it.
+///
+/// The leading zeros of the slice are ignored, so the zero polynomial is equal both to the
+/// empty slice, which `get_coefficients` returns for it, and to `[0.0]`.
+///
+/// # Examples
+///
+/// ```
+/// use polynomials::Polynomial;
+///
+/// let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 1.0]);
+/// assert_eq!(poly, [1.0, -2.0, 1.0][..]);
+/// assert_eq!(poly, vec![0.0, 1.0, -2.0, 1.0]);
+/// assert_ne!(poly, [1.0, -2.0][..]);
+///
+/// assert_eq!(Polynomial::zero(), [][..]);
+/// assert_eq!(Polynomial::zero(), [0.0][..]);
+/// ```
+impl PartialEq<[f64]> for Polynomial {
+    fn eq(&self, other: &[f64]) -> bool {
+        let leading_zeros = other.iter().take_while(|coefficient| **coefficient == 0.0).count();
+        let other = &other[leading_zeros..];
+        let Some(degree) = self.degree() else {
+            return other.is_empty();
+        };
+        other.len() == degree as usize + 1
+            && other
+                .iter()
+                .enumerate()
+                .all(|(index, coefficient)| self.get_coefficient_at(degree - index as u32) == *coefficient)
+    }
+}
+
+impl PartialEq<Vec<f64>> for Polynomial {
+    fn eq(&self, other: &Vec<f64>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl PartialEq<Polynomial> for [f64] {
+    fn eq(&self, other: &Polynomial) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<Polynomial> for Vec<f64> {
+    fn eq(&self, other: &Polynomial) -> bool {
+        other == self.as_slice()
+    }
+}
+
+/// Hashes the (power, coefficient) pairs in the ascending order of powers.
+///
+/// The coefficients are hashed by their bit patterns, with all `NaN` values hashed alike. Since
+/// zero coefficients are never stored, equal polynomials always produce equal hashes.
+impl Hash for Polynomial {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.debug_assert_normalized();
+        state.write_usize(self.coefficients.len());
+        for (power, coefficient) in self.coefficients.iter() {
+            power.hash(state);
+            canonical(*coefficient).to_bits().hash(state);
+        }
+    }
+}
+
+/// Orders the polynomials structurally: by their degrees first, with the zero polynomial
+/// least, and then by the coefficients from the highest power downward.
+///
+/// This is not a pointwise order of the polynomial functions, e.g. `x` is greater than `5` even
+/// though it is less at `x = 0`. The coefficients are compared with [`f64::total_cmp`], with all
+/// `NaN` values equal to each other and greater than the infinity, so the order is total and
+/// consistent with the equality, and the polynomials can be stored in sorted collections such
+/// as `BTreeSet`.
+///
+/// # Examples
+///
+/// ```
+/// use polynomials::Polynomial;
+///
+/// let mut polys = vec![
+///     Polynomial::from_coefficients(&vec![1.0, 0.0]),
+///     Polynomial::from_coefficients(&vec![5.0]),
+///     Polynomial::from_coefficients(&vec![-1.0, 3.0]),
+///     Polynomial::zero(),
+/// ];
+/// polys.sort();
+/// let sorted: Vec<String> = polys.iter().map(|poly| poly.to_string()).collect();
+/// assert_eq!(vec!["0", "5", "- x + 3", "x"], sorted);
+/// ```
+impl Ord for Polynomial {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.deg().cmp(&other.deg()).then_with(|| {
+            let mut terms = self.coefficients.iter().rev().peekable();
+            let mut other_terms = other.coefficients.iter().rev().peekable();
+            loop {
+                let ordering = match (terms.peek().copied(), other_terms.peek().copied()) {
+                    (None, None) => return Ordering::Equal,
+                    (Some((power, coefficient)), Some((other_power, other_coefficient))) => {
+                        match power.cmp(other_power) {
+                            Ordering::Equal => {
+                                terms.next();
+                                other_terms.next();
+                                canonical(*coefficient).total_cmp(&canonical(*other_coefficient))
+                            }
+                            Ordering::Greater => canonical(*coefficient).total_cmp(&0.0),
+                            Ordering::Less => 0f64.total_cmp(&canonical(*other_coefficient)),
+                        }
+                    }
+                    (Some((_, coefficient)), None) => canonical(*coefficient).total_cmp(&0.0),
+                    (None, Some((_, other_coefficient))) => 0f64.total_cmp(&canonical(*other_coefficient)),
+                };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+        })
+    }
+}
+
+impl PartialOrd for Polynomial {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Polynomial;
+    use std::cmp::Ordering;
+    use std::collections::{BTreeSet, HashMap, HashSet};
+    use std::hash::{BuildHasher, RandomState};
+    use super::{Degree, Polynomial, SizeMismatch};
 
     #[test]
     fn is_zero_works() {
@@ -187,6 +636,18 @@ mod tests {
         assert_eq!(poly.degree(), None);
     }
 
+    #[test]
+    fn deg_works() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -2.0]);
+        assert_eq!(Degree::Finite(2), poly.deg());
+        assert_eq!(Degree::NegInfinity, Polynomial::zero().deg());
+    }
+
+    #[test]
+    fn default_is_zero_polynomial() {
+        assert_eq!(Polynomial::zero(), Polynomial::default());
+    }
+
     #[test]
     fn polynomial_clear() {
         let mut poly = Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
@@ -207,10 +668,358 @@ mod tests {
         assert_eq!(-19.0, poly.evaluate(-2.0));
     }
 
+    #[test]
+    fn evaluate_without_constant_term() {
+        let poly = Polynomial::from_coefficients(&vec![-1.0, 0.0]);
+        assert_eq!(0.0, poly.evaluate(0.0));
+        assert_eq!(-3.0, poly.evaluate(3.0));
+
+        let poly = Polynomial::from_coefficients(&vec![1.0, 2.0, 0.0, 0.0]);
+        assert_eq!(16.0, poly.evaluate(2.0));
+    }
+
+    #[test]
+    fn sample_includes_endpoints() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, -1.0]);
+        let points = poly.sample(0.1..0.7, 7);
+        assert_eq!(7, points.len());
+        assert_eq!((0.1, 0.1 - 1.0), points[0]);
+        assert_eq!((0.7, 0.7 - 1.0), points[6]);
+        assert!(points.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn chained_in_place_operations() {
+        let p = Polynomial::from_coefficients(&vec![1.0, -2.0, 0.5, 3.0]);
+        let q = Polynomial::from_coefficients(&vec![2.0, 0.0, -1.0]);
+
+        let mut chained = p.clone();
+        let result = chained
+            .add_assign_ref(&q)
+            .mul_assign_ref(&q)
+            .mul_scalar_in_place(2.0)
+            .truncate_in_place(4)
+            .sub_assign_ref(&p)
+            .derivative_in_place()
+            .negate_in_place();
+        result.add_scalar_in_place(1.0);
+
+        let mut expected = ((p.clone() + &q) * &q * 2.0).get_coefficients();
+        expected.drain(..expected.len() - 4);
+        let expected = -(Polynomial::from_coefficients(&expected) - &p).derivative() + 1.0;
+
+        // The borrow of the chain has ended, so the value is usable again
+        assert_eq!(expected, chained);
+        chained.trim_in_place(100.0);
+        assert!(chained.is_zero());
+    }
+
+    #[test]
+    fn in_place_operations_keep_zeros_out() {
+        let mut poly = Polynomial::from_coefficients(&vec![1.0, -1.0, 2.0]);
+        poly.add_assign_ref(&Polynomial::from_coefficients(&vec![-1.0, 1.0, 0.0]));
+        assert_eq!(vec![2.0], poly.get_coefficients());
+        poly.add_scalar_in_place(-2.0);
+        assert!(poly.is_zero());
+
+        let mut poly = Polynomial::from_coefficients(&vec![1.0, 1.0]);
+        poly.sub_assign_ref(&Polynomial::from_coefficients(&vec![1.0, 1.0])).negate_in_place();
+        assert!(poly.is_zero());
+
+        let mut poly = Polynomial::from_coefficients(&vec![1.0, 1.0]);
+        poly.mul_scalar_in_place(0.0);
+        assert!(poly.is_zero());
+
+        let mut poly = Polynomial::from_coefficients(&vec![3.0, 0.0]);
+        poly.derivative_in_place().derivative_in_place();
+        assert!(poly.is_zero());
+
+        let mut poly = Polynomial::from_coefficients(&vec![1.0, 2.0, 3.0]);
+        poly.truncate_in_place(0);
+        assert!(poly.is_zero());
+        poly.debug_assert_normalized();
+    }
+
+    #[test]
+    fn equality_with_scalars() {
+        let constant = Polynomial::from_coefficients(&vec![-3.5]);
+        assert_eq!(constant, -3.5);
+        assert_eq!(-3.5, constant);
+        assert_ne!(constant, 3.5);
+        assert_ne!(Polynomial::from_coefficients(&vec![1.0, 0.0]), 0.0);
+        assert_ne!(Polynomial::from_coefficients(&vec![1.0, -3.5]), -3.5);
+
+        assert_eq!(Polynomial::zero(), 0.0);
+        assert_eq!(Polynomial::zero(), -0.0);
+        assert_ne!(Polynomial::zero(), 1.0);
+        assert_ne!(Polynomial::from_coefficients(&vec![f64::NAN]), f64::NAN);
+    }
+
+    #[test]
+    fn equality_with_coefficient_slices() {
+        let poly = Polynomial::from_coefficients(&vec![2.0, 0.0, -1.0, 0.0]);
+        assert_eq!(poly, poly.get_coefficients());
+        assert_eq!(poly, [2.0, 0.0, -1.0, 0.0][..]);
+        assert_eq!(poly, [-0.0, 0.0, 2.0, 0.0, -1.0, 0.0][..]);
+        assert_eq!(vec![2.0, 0.0, -1.0, 0.0], poly);
+        assert_eq!([2.0, 0.0, -1.0, 0.0][..], poly);
+
+        // The trailing zeros are the lower powers, so they are significant
+        assert_ne!(poly, [2.0, 0.0, -1.0][..]);
+        assert_ne!(poly, [2.0, 0.0, -1.0, 0.0, 0.0][..]);
+        assert_ne!(poly, [2.0, 0.0, 1.0, 0.0][..]);
+    }
+
+    #[test]
+    fn zero_polynomial_equals_empty_and_zero_slices() {
+        let zero = Polynomial::zero();
+        assert_eq!(zero, zero.get_coefficients());
+        assert_eq!(zero, [][..]);
+        assert_eq!(zero, [0.0, 0.0][..]);
+        assert_eq!(Vec::<f64>::new(), zero);
+        assert_ne!(zero, [1.0][..]);
+        assert_ne!(Polynomial::one(), [][..]);
+    }
+
+    #[test]
+    fn evaluate_many_into_buffers_of_every_size() {
+        let poly = Polynomial::from_coefficients(&vec![2.0, -1.0]);
+        let xs = [0.0, 1.0, 2.5];
+
+        let mut exact = [0.0; 3];
+        assert_eq!(Ok(()), poly.evaluate_many_into(&xs, &mut exact));
+        assert_eq!([-1.0, 1.0, 4.0], exact);
+
+        let mut oversized = [9.0; 5];
+        assert_eq!(Ok(()), poly.evaluate_many_into(&xs, &mut oversized));
+        assert_eq!([-1.0, 1.0, 4.0, 9.0, 9.0], oversized);
+
+        let mut undersized = [9.0; 2];
+        assert_eq!(
+            Err(SizeMismatch { required: 3, actual: 2 }),
+            poly.evaluate_many_into(&xs, &mut undersized)
+        );
+        assert_eq!([9.0; 2], undersized);
+        assert_eq!(Ok(()), poly.evaluate_many_into(&[], &mut []));
+    }
+
+    #[test]
+    fn sample_two_points() {
+        let poly = Polynomial::from_coefficients(&vec![2.0, 0.0]);
+        assert_eq!(vec![(-3.0, -6.0), (5.0, 10.0)], poly.sample(-3.0..5.0, 2));
+    }
+
+    #[test]
+    fn sample_constant_polynomial() {
+        let poly = Polynomial::from_coefficients(&vec![4.0]);
+        let points = poly.sample(-1.0..1.0, 11);
+        assert!(points.iter().all(|(_, y)| *y == 4.0));
+        assert_eq!(vec![(0.0, 0.0); 3], Polynomial::zero().sample(0.0..0.0, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot sample fewer than two points.")]
+    fn sample_one_point() {
+        let _ = Polynomial::one().sample(0.0..1.0, 1);
+    }
+
     #[test]
     fn derivative_works() {
         let poly = Polynomial::from_coefficients(&vec![3.0, 2.0, 0.0, -3.0]);
         let derivative = poly.derivative();
         assert_eq!(vec![9.0, 4.0, 0.0], derivative.get_coefficients());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn nth_derivative_matches_repeated_derivative() {
+        let mut sparse = Polynomial::from_coefficients(&vec![-0.5, 3.0, 0.0, 1.0, 2.0, -7.0]);
+        sparse.set_coefficient_at(40, 1.25);
+        for poly in [Polynomial::from_coefficients(&vec![3.0, 2.0, 0.0, -3.0]), sparse, Polynomial::zero()] {
+            let mut expected = poly.clone();
+            for k in 0..=6 {
+                assert_eq!(expected, poly.nth_derivative(k));
+                expected.derivative_in_place();
+            }
+        }
+    }
+
+    #[test]
+    fn antiderivative_is_inverse_of_derivative() {
+        let mut sparse = Polynomial::from_coefficients(&vec![-0.5, 3.0, 0.0, 1.0, 2.0, -7.0]);
+        sparse.set_coefficient_at(31, 4.0);
+        for poly in [Polynomial::from_coefficients(&vec![3.0, 2.0, 0.0, -3.0]), sparse, Polynomial::zero()] {
+            assert_eq!(poly, poly.antiderivative().derivative());
+            assert_eq!(poly, poly.antiderivative_with_constant(-2.5).derivative());
+        }
+    }
+
+    #[test]
+    fn antiderivative_constant_term() {
+        let poly = Polynomial::from_coefficients(&vec![6.0, -1.0]);
+        assert_eq!(vec![3.0, -1.0, 0.0], poly.antiderivative().get_coefficients());
+        assert_eq!(0.0, poly.antiderivative().evaluate(0.0));
+        assert_eq!(vec![3.0, -1.0, 3.0], poly.antiderivative_with_constant(3.0).get_coefficients());
+        assert_eq!(Polynomial::zero(), Polynomial::zero().antiderivative());
+        assert_eq!(7.0, Polynomial::zero().antiderivative_with_constant(7.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot integrate a term of the power u32::MAX.")]
+    fn antiderivative_of_the_highest_power() {
+        let mut poly = Polynomial::zero();
+        poly.set_coefficient_at(u32::MAX, 1.0);
+        poly.antiderivative_with_constant(1.0);
+    }
+
+    #[test]
+    fn nth_derivative_beyond_degree_is_zero() {
+        let poly = Polynomial::from_coefficients(&vec![3.0, 2.0, 0.0, -3.0]);
+        assert_eq!(vec![18.0], poly.nth_derivative(3).get_coefficients());
+        assert_eq!(Polynomial::zero(), poly.nth_derivative(4));
+        assert_eq!(Polynomial::zero(), poly.nth_derivative(u32::MAX));
+    }
+
+    #[test]
+    fn nth_derivative_of_the_highest_power() {
+        let mut poly = Polynomial::zero();
+        poly.set_coefficient_at(u32::MAX, 2.0);
+        assert_eq!(poly, poly.nth_derivative(0));
+        assert_eq!(poly.derivative(), poly.nth_derivative(1));
+        assert_eq!(2.0 * f64::from(u32::MAX), poly.nth_derivative(1).get_coefficient_at(u32::MAX - 1));
+    }
+
+    #[test]
+    fn hash_map_insertion_and_lookup() {
+        let mut map = HashMap::new();
+        map.insert(Polynomial::from_coefficients(&vec![1.0, -2.0]), "linear");
+        map.insert(Polynomial::from_coefficients(&vec![1.0, 0.0, -1.0]), "quadratic");
+        map.insert(Polynomial::zero(), "zero");
+
+        assert_eq!(Some(&"linear"), map.get(&Polynomial::from_coefficients(&vec![1.0, -2.0])));
+        assert_eq!(Some(&"zero"), map.get(&Polynomial::zero()));
+        assert_eq!(None, map.get(&Polynomial::from_coefficients(&vec![1.0, 2.0])));
+    }
+
+    #[test]
+    fn hash_set_deduplicates_equal_polynomials() {
+        let mut set = HashSet::new();
+        set.insert(Polynomial::from_coefficients(&vec![2.0, 0.0, 1.0]));
+        set.insert(Polynomial::from_string("2x^2 + 1").unwrap());
+        set.insert(Polynomial::from_coefficients(&vec![2.0, 1.0]));
+        assert_eq!(2, set.len());
+    }
+
+    #[test]
+    fn equality_of_nan_coefficients_is_reflexive() {
+        let poly = Polynomial::from_coefficients(&vec![f64::NAN, 0.0, 1.0]);
+        assert_eq!(poly, poly);
+        assert_eq!(poly, Polynomial::from_coefficients(&vec![-f64::NAN, 0.0, 1.0]));
+        assert_ne!(poly, Polynomial::from_coefficients(&vec![f64::NAN, 0.0, 2.0]));
+        assert_ne!(poly, Polynomial::from_coefficients(&vec![f64::NAN, 1.0]));
+
+        let state = RandomState::new();
+        let other_nan = f64::from_bits(f64::NAN.to_bits() | 1);
+        let other = Polynomial::from_coefficients(&vec![other_nan, 0.0, 1.0]);
+        assert_eq!(poly, other);
+        assert_eq!(state.hash_one(&poly), state.hash_one(&other));
+
+        let mut set = HashSet::new();
+        set.insert(poly.clone());
+        assert!(set.contains(&other));
+    }
+
+    #[test]
+    fn equal_polynomials_hash_equally() {
+        let poly1 = Polynomial::from_coefficients(&vec![0.0, 3.0, 0.0, -1.0]);
+
+        let mut poly2 = Polynomial::zero();
+        poly2.set_coefficient_at(5, 4.0);
+        poly2.set_coefficient_at(0, -1.0);
+        poly2.set_coefficient_at(2, 3.0);
+        poly2.set_coefficient_at(5, 0.0);
+
+        let poly3 = Polynomial::from_coefficients(&vec![1.0, 1.0, -1.0])
+            + &Polynomial::from_coefficients(&vec![2.0, -1.0, 0.0]);
+
+        let state = RandomState::new();
+        assert_eq!(poly1, poly2);
+        assert_eq!(poly1, poly3);
+        assert_eq!(state.hash_one(&poly1), state.hash_one(&poly2));
+        assert_eq!(state.hash_one(&poly1), state.hash_one(&poly3));
+    }
+
+    #[test]
+    fn sorting_orders_by_degree_then_coefficients() {
+        let mut sparse = Polynomial::zero();
+        sparse.set_coefficient_at(10, -1.0);
+        let mut polys = vec![
+            Polynomial::from_coefficients(&vec![1.0, 0.0, 2.0]),
+            sparse.clone(),
+            Polynomial::from_coefficients(&vec![-3.0]),
+            Polynomial::from_coefficients(&vec![1.0, -1.0, 5.0]),
+            Polynomial::zero(),
+            Polynomial::from_coefficients(&vec![1.0, 0.0, -2.0]),
+            Polynomial::from_coefficients(&vec![2.0]),
+        ];
+        polys.sort();
+        assert_eq!(vec![
+            Polynomial::zero(),
+            Polynomial::from_coefficients(&vec![-3.0]),
+            Polynomial::from_coefficients(&vec![2.0]),
+            Polynomial::from_coefficients(&vec![1.0, -1.0, 5.0]),
+            Polynomial::from_coefficients(&vec![1.0, 0.0, -2.0]),
+            Polynomial::from_coefficients(&vec![1.0, 0.0, 2.0]),
+            sparse,
+        ], polys);
+    }
+
+    #[test]
+    fn ordering_is_consistent_with_equality() {
+        let poly1 = Polynomial::from_coefficients(&vec![1.0, 0.0, 3.0]);
+        let poly2 = Polynomial::from_string("x^2 + 3").unwrap();
+        assert_eq!(Ordering::Equal, poly1.cmp(&poly2));
+        assert!(Polynomial::from_coefficients(&vec![1.0, -1.0, 0.0]) < Polynomial::from_coefficients(&vec![1.0, 0.0, -7.0]));
+    }
+
+    #[test]
+    fn ordering_compares_degrees_before_coefficients() {
+        let small_cubic = Polynomial::from_coefficients(&vec![1e-300, 0.0, 0.0, 0.0]);
+        let large_quadratic = Polynomial::from_coefficients(&vec![1e300, 1e300, 1e300]);
+        assert_eq!(Ordering::Greater, small_cubic.cmp(&large_quadratic));
+        assert_eq!(Ordering::Less, Polynomial::zero().cmp(&Polynomial::from_coefficients(&vec![-1e300])));
+
+        // The same degree, compared from the leading coefficient downward, with a missing term
+        // treated as zero
+        let p = Polynomial::from_coefficients(&vec![2.0, -1.0, 5.0]);
+        let q = Polynomial::from_coefficients(&vec![2.0, 0.0, -5.0]);
+        assert_eq!(Ordering::Less, p.cmp(&q));
+        assert_eq!(Ordering::Greater, q.cmp(&p));
+    }
+
+    #[test]
+    fn ordering_ignores_the_sign_of_zero() {
+        // A negative zero is not stored, although `f64::total_cmp` orders it below zero
+        assert_eq!(Ordering::Less, (-0.0f64).total_cmp(&0.0));
+        assert_eq!(Ordering::Equal, Polynomial::from_coefficients(&vec![-0.0]).cmp(&Polynomial::zero()));
+        let p = Polynomial::from_coefficients(&vec![1.0, -0.0, 3.0]);
+        let q = Polynomial::from_coefficients(&vec![1.0, 0.0, 3.0]);
+        assert_eq!(Ordering::Equal, p.cmp(&q));
+        assert_eq!(p, q);
+    }
+
+    #[test]
+    fn ordering_of_nan_coefficients_agrees_with_equality() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, f64::NAN]);
+        let other = Polynomial::from_coefficients(&vec![1.0, -f64::NAN]);
+        assert_eq!(Ordering::Equal, poly.cmp(&other));
+        assert_eq!(poly == other, poly.cmp(&other) == Ordering::Equal);
+
+        let infinite = Polynomial::from_coefficients(&vec![1.0, f64::INFINITY]);
+        assert_eq!(Ordering::Greater, other.cmp(&infinite));
+
+        let mut set = BTreeSet::new();
+        set.insert(poly);
+        set.insert(other);
+        assert_eq!(1, set.len());
+    }
+}
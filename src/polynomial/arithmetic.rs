@@ -3,5 +3,12 @@ mod subtraction;
 mod multiplication;
 mod division;
 mod negation;
+mod scalar;
+mod checked;
+mod sqrt;
+
+pub use division::{DivisionStep, DivisionTrace};
+pub use multiplication::convolve_slices;
+pub(super) use multiplication::binary_pow;
 
 use super::Polynomial;
\ No newline at end of file
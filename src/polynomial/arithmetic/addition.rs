@@ -1,3 +1,4 @@
+use std::iter::Sum;
 use std::ops::{Add, AddAssign};
 use super::super::Polynomial;
 
@@ -7,6 +8,31 @@ fn add_in_place(poly1: &mut Polynomial, poly2: &Polynomial) {
     }
 }
 
+impl Polynomial {
+    /// Adds the other polynomial in place and returns the polynomial for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let mut poly = Polynomial::from_coefficients(&vec![1.0, 2.0]);
+    /// poly.add_assign_ref(&Polynomial::from_coefficients(&vec![1.0, 0.0, -2.0]))
+    ///     .add_scalar_in_place(3.0);
+    /// assert_eq!(vec![1.0, 1.0, 3.0], poly.get_coefficients());
+    /// ```
+    pub fn add_assign_ref(&mut self, other: &Polynomial) -> &mut Self {
+        add_in_place(self, other);
+        self
+    }
+
+    /// Adds the scalar to the constant term in place and returns the polynomial for chaining.
+    pub fn add_scalar_in_place(&mut self, scalar: f64) -> &mut Self {
+        self.add_coefficient_at(0, scalar);
+        self
+    }
+}
+
 impl Add<&Self> for Polynomial {
     type Output = Polynomial;
 
@@ -16,6 +42,37 @@ impl Add<&Self> for Polynomial {
     }
 }
 
+impl Add for Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // Reuse the map of the operand with more terms
+        let (mut result, other) = if self.coefficients.len() >= rhs.coefficients.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        add_in_place(&mut result, &other);
+        result
+    }
+}
+
+impl Add for &Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // Clone the operand with more terms and add the other one to it
+        let (larger, smaller) = if self.coefficients.len() >= rhs.coefficients.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut result = larger.clone();
+        add_in_place(&mut result, smaller);
+        result
+    }
+}
+
 impl Add<f64> for Polynomial {
     type Output = Polynomial;
     
@@ -40,6 +97,17 @@ impl AddAssign<&Self> for Polynomial {
     }
 }
 
+impl AddAssign for Polynomial {
+    fn add_assign(&mut self, rhs: Self) {
+        if rhs.coefficients.len() > self.coefficients.len() {
+            let lhs = std::mem::replace(self, rhs);
+            add_in_place(self, &lhs);
+        } else {
+            add_in_place(self, &rhs);
+        }
+    }
+}
+
 impl AddAssign<f64> for Polynomial {
     fn add_assign(&mut self, rhs: f64) {
         self.add_coefficient_at(0, rhs);
@@ -52,6 +120,28 @@ impl AddAssign<i32> for Polynomial {
     }
 }
 
+impl Sum for Polynomial {
+    fn sum<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+        let Some(mut result) = iter.next() else {
+            return Polynomial::zero();
+        };
+        for poly in iter {
+            add_in_place(&mut result, &poly);
+        }
+        result
+    }
+}
+
+impl<'a> Sum<&'a Polynomial> for Polynomial {
+    fn sum<I: Iterator<Item = &'a Polynomial>>(iter: I) -> Self {
+        let mut result = Polynomial::zero();
+        for poly in iter {
+            add_in_place(&mut result, poly);
+        }
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Polynomial;
@@ -64,6 +154,17 @@ mod tests {
         assert_eq!(vec![-1.0, 0.0, -4.0], poly3.get_coefficients());
     }
 
+    #[test]
+    fn add_owned() {
+        let poly1 = Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
+        let poly2 = Polynomial::from_coefficients(&vec![-2.0, -2.0, -1.0]);
+        assert_eq!(vec![-1.0, 0.0, -4.0], (poly1 + poly2).get_coefficients());
+
+        let short = Polynomial::from_coefficients(&vec![1.0, 0.0]);
+        let long = Polynomial::from_coefficients(&vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(vec![1.0, 2.0, 4.0, 4.0], (short + long).get_coefficients());
+    }
+
     #[test]
     fn add_float() {
         let poly = Polynomial::from_coefficients(&vec![-2.0, 0.0, 1.0]);
@@ -86,6 +187,17 @@ mod tests {
         assert_eq!(vec![-1.0, 0.0, -4.0], poly1.get_coefficients());
     }
 
+    #[test]
+    fn add_assign_owned() {
+        let mut poly1 = Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
+        poly1 += Polynomial::from_coefficients(&vec![-2.0, -2.0, -1.0]);
+        assert_eq!(vec![-1.0, 0.0, -4.0], poly1.get_coefficients());
+
+        let mut short = Polynomial::from_coefficients(&vec![5.0]);
+        short += Polynomial::from_coefficients(&vec![1.0, 2.0, 3.0]);
+        assert_eq!(vec![1.0, 2.0, 8.0], short.get_coefficients());
+    }
+
     #[test]
     fn add_assign_float() {
         let mut poly = Polynomial::from_coefficients(&vec![-2.0, 0.0, 1.0]);
@@ -99,4 +211,36 @@ mod tests {
         poly += 2;
         assert_eq!(vec![-2.0, 0.0, 3.0], poly.get_coefficients());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn sum_of_lagrange_basis_pieces() {
+        // Interpolating (0, 1), (1, 3), (2, 7) gives x^2 + x + 1
+        let pieces = vec![
+            Polynomial::from_coefficients(&vec![0.5, -1.5, 1.0]),
+            Polynomial::from_coefficients(&vec![-3.0, 6.0, 0.0]),
+            Polynomial::from_coefficients(&vec![3.5, -3.5, 0.0]),
+        ];
+        let expected = Polynomial::from_coefficients(&vec![1.0, 1.0, 1.0]);
+        assert_eq!(expected, pieces.iter().sum::<Polynomial>());
+        assert_eq!(expected, pieces.into_iter().sum::<Polynomial>());
+    }
+
+    #[test]
+    fn sum_of_empty_iterator_is_zero() {
+        let polys: Vec<Polynomial> = Vec::new();
+        assert_eq!(Polynomial::zero(), polys.iter().sum::<Polynomial>());
+        assert_eq!(Polynomial::zero(), polys.into_iter().sum::<Polynomial>());
+    }
+
+    #[test]
+    fn add_borrowed() {
+        let a = Polynomial::from_coefficients(&vec![1.0, 2.0]);
+        let b = Polynomial::from_coefficients(&vec![-1.0, 0.0, 3.0]);
+        let c = Polynomial::from_coefficients(&vec![4.0]);
+        assert_eq!(vec![-1.0, 1.0, 9.0], (&a + &b + &c).get_coefficients());
+        assert_eq!(&b + &a, &a + &b);
+        assert_eq!(vec![1.0, 2.0], a.get_coefficients());
+        assert_eq!(vec![-1.0, 0.0, 3.0], b.get_coefficients());
+        assert_eq!(Polynomial::zero(), &a + &-&a);
+    }
+}
@@ -0,0 +1,145 @@
+use super::Polynomial;
+use super::multiplication::binary_pow;
+
+/// Returns the polynomial if all of its coefficients are finite.
+fn finite(poly: Polynomial) -> Option<Polynomial> {
+    poly.coefficients.values().all(|coefficient| coefficient.is_finite()).then_some(poly)
+}
+
+impl Polynomial {
+    /// Adds the polynomials, returning `None` if a coefficient of the result is not finite,
+    /// e.g. after an overflow to infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![f64::MAX, 1.0]);
+    /// assert_eq!(None, poly.checked_add(&poly));
+    ///
+    /// let half = Polynomial::from_coefficients(&vec![f64::MAX / 2.0, 1.0]);
+    /// assert_eq!(Some(vec![f64::MAX, 2.0]), half.checked_add(&half).map(|p| p.get_coefficients()));
+    /// ```
+    pub fn checked_add(&self, other: &Polynomial) -> Option<Polynomial> {
+        finite(self.clone() + other)
+    }
+
+    /// Subtracts the polynomials, returning `None` if a coefficient of the result is not
+    /// finite, e.g. after an overflow to infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![f64::MAX, 1.0]);
+    /// assert_eq!(None, poly.checked_sub(&-poly.clone()));
+    /// assert_eq!(Some(Polynomial::zero()), poly.checked_sub(&poly));
+    /// ```
+    pub fn checked_sub(&self, other: &Polynomial) -> Option<Polynomial> {
+        finite(self.clone() - other)
+    }
+
+    /// Multiplies the polynomials, returning `None` if a coefficient of the result is not
+    /// finite, e.g. after an overflow to infinity.
+    ///
+    /// An overflow while the products of the terms are accumulated leaves an infinite or `NaN`
+    /// coefficient, so it is detected even if the exact result would be finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1e200, 1.0]);
+    /// assert_eq!(None, poly.checked_mul(&poly));
+    /// ```
+    pub fn checked_mul(&self, other: &Polynomial) -> Option<Polynomial> {
+        finite(self.clone() * other)
+    }
+
+    /// Raises the polynomial to the power with binary exponentiation, returning `None` if a
+    /// coefficient of an intermediate result is not finite, e.g. after an overflow to infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 1.0]);
+    /// assert_eq!(Some(vec![1.0, 3.0, 3.0, 1.0]), poly.checked_pow(3).map(|p| p.get_coefficients()));
+    /// assert_eq!(Some(Polynomial::one()), poly.checked_pow(0));
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1e100]);
+    /// assert_eq!(None, poly.checked_pow(4));
+    /// ```
+    pub fn checked_pow(&self, exponent: u32) -> Option<Polynomial> {
+        let base = finite(self.clone())?;
+        binary_pow(base, exponent, Polynomial::one(), |a, b| a.checked_mul(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use crate::Polynomial;
+    use crate::polynomial::strategy::polynomials_with;
+
+    #[test]
+    fn checked_add_at_the_boundary() {
+        let max = Polynomial::from_coefficients(&vec![f64::MAX]);
+        let ulp = Polynomial::from_coefficients(&vec![f64::MAX - f64::MAX.next_down()]);
+        assert_eq!(None, max.checked_add(&ulp));
+        assert_eq!(None, (-max.clone()).checked_sub(&ulp));
+        assert_eq!(Some(max.clone()), max.checked_add(&Polynomial::from_coefficients(&vec![1.0])));
+    }
+
+    #[test]
+    fn checked_mul_detects_overflow_of_a_single_term() {
+        let mut poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0]);
+        poly.set_coefficient_at(0, 1e160);
+        let product = poly.checked_mul(&poly);
+        assert_eq!(None, product);
+
+        let mut poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0]);
+        poly.set_coefficient_at(0, 1e150);
+        assert_eq!(Some(1e150 * 1e150), poly.checked_mul(&poly).map(|p| p.get_coefficient_at(0)));
+    }
+
+    #[test]
+    fn checked_mul_detects_overflow_in_accumulation() {
+        // All the products of the terms are finite, but the two summed into x overflow
+        let poly = Polynomial::from_coefficients(&vec![f64::MAX, f64::MAX]);
+        let one_plus_x = Polynomial::from_coefficients(&vec![1.0, 1.0]);
+        assert_eq!(None, poly.checked_mul(&one_plus_x));
+    }
+
+    #[test]
+    fn checked_ops_reject_non_finite_inputs() {
+        let poly = Polynomial::from_coefficients(&vec![f64::INFINITY, 1.0]);
+        assert_eq!(None, poly.checked_add(&Polynomial::zero()));
+        assert_eq!(None, poly.checked_mul(&Polynomial::one()));
+        assert_eq!(None, poly.checked_pow(0));
+    }
+
+    #[test]
+    fn checked_pow_at_the_boundary() {
+        let two = Polynomial::from_coefficients(&vec![2.0]);
+        assert_eq!(Some(2f64.powi(1023)), two.checked_pow(1023).map(|p| p.get_coefficient_at(0)));
+        assert_eq!(None, two.checked_pow(1024));
+    }
+
+    proptest! {
+        #[test]
+        fn checked_results_match_unchecked(
+            p in polynomials_with((-5..=5).prop_map(f64::from), 8),
+            q in polynomials_with((-5..=5).prop_map(f64::from), 8),
+        ) {
+            prop_assert_eq!(Some(p.clone() + &q), p.checked_add(&q));
+            prop_assert_eq!(Some(p.clone() - &q), p.checked_sub(&q));
+            prop_assert_eq!(Some(p.clone() * &q), p.checked_mul(&q));
+            prop_assert_eq!(Some(p.clone() * &p * &p), p.checked_pow(3));
+        }
+    }
+}
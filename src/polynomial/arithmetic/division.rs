@@ -1,5 +1,7 @@
+use std::fmt;
 use std::ops::{Div, DivAssign, Rem, RemAssign};
 use super::Polynomial;
+use crate::{Degree, PolynomialFormat};
 
 pub struct PolynomialDivisionResult {
     pub quotient: Polynomial,
@@ -11,17 +13,19 @@ struct Term {
     power: u32
 }
 
-/// Returns a leading term of a [`Polynomial`].
-fn leading_term(poly: &Polynomial) -> Term {
-    let degree = poly.degree().unwrap();
-    Term {
-        coefficient: poly.get_coefficient_at(degree),
-        power: degree
+/// Returns a leading term of a [`Polynomial`], or `None` for the zero polynomial.
+fn leading_term(poly: &Polynomial) -> Option<Term> {
+    match poly.deg() {
+        Degree::Finite(degree) => Some(Term {
+            coefficient: poly.get_coefficient_at(degree),
+            power: degree
+        }),
+        Degree::NegInfinity => None
     }
 }
 
 /// Returns a quotient of two terms as a [`Polynomial`].
-fn divide_terms(term1: Term, term2: Term) -> Polynomial {
+fn divide_terms(term1: &Term, term2: &Term) -> Polynomial {
     let mut quotient = Polynomial::zero();
     quotient.set_coefficient_at(
         term1.power - term2.power,
@@ -36,24 +40,163 @@ fn divide_terms(term1: Term, term2: Term) -> Polynomial {
 /// After a function invocation, a quotient is returned and the numerator becomes a
 /// remainder of the division.
 fn divide_in_place(numerator: &mut Polynomial, denominator: &Polynomial) -> Polynomial {
-    if denominator.is_zero() {
+    divide_in_place_with::<false, _>(numerator, denominator, |_, _, _| {})
+}
+
+/// The division algorithm of [`divide_in_place`], which calls `on_step` with the quotient
+/// term, the subtracted product and the remainder after every step if `TRACE` is set. Without
+/// it the product is not kept, so the plain division does not pay for the tracing.
+fn divide_in_place_with<const TRACE: bool, F>(
+    numerator: &mut Polynomial,
+    denominator: &Polynomial,
+    mut on_step: F
+) -> Polynomial
+where
+    F: FnMut(&Polynomial, &Polynomial, &Polynomial)
+{
+    let Some(denominator_term) = leading_term(denominator) else {
         panic!("Cannot divide by the zero polynomial.");
-    }
+    };
 
     let mut quotient = Polynomial::zero();
     let remainder = numerator;
 
-    while !remainder.is_zero() && remainder.degree().unwrap() >= denominator.degree().unwrap() {
-        let next_quotient_term = divide_terms(
-            leading_term(&remainder), leading_term(denominator)
-        );
+    while let Some(remainder_term) = leading_term(remainder) {
+        if remainder_term.power < denominator_term.power {
+            break;
+        }
+        let next_quotient_term = divide_terms(&remainder_term, &denominator_term);
         quotient += &next_quotient_term;
-        *remainder -= &(next_quotient_term * denominator);
+        // The leading term cancels out up to a rounding error, which is dropped, as dividing it
+        // again would never end once it is too small for its quotient to be representable
+        if TRACE {
+            let product = &next_quotient_term * denominator;
+            *remainder -= &product;
+            remainder.set_coefficient_at(remainder_term.power, 0.0);
+            on_step(&next_quotient_term, &product, remainder);
+        } else {
+            *remainder -= &(next_quotient_term * denominator);
+            remainder.set_coefficient_at(remainder_term.power, 0.0);
+        }
     }
 
     quotient
 }
 
+/// A step of the polynomial long division recorded in a [`DivisionTrace`].
+#[derive(PartialEq, Debug, Clone)]
+pub struct DivisionStep {
+    /// The term of the quotient chosen in this step.
+    pub quotient_term: Polynomial,
+    /// The product of the quotient term and the divisor subtracted from the remainder.
+    pub product: Polynomial,
+    /// The remainder after the subtraction.
+    pub remainder: Polynomial
+}
+
+/// The steps of the polynomial long division returned by the
+/// [`div_rem_steps`](Polynomial::div_rem_steps) method.
+///
+/// The [`Display`](fmt::Display) implementation writes the division in the worked layout,
+/// with the product subtracted and the running remainder on separate lines, and the
+/// [`to_latex`](DivisionTrace::to_latex) method writes the same layout as a LaTeX array.
+#[derive(PartialEq, Debug, Clone)]
+pub struct DivisionTrace {
+    pub dividend: Polynomial,
+    pub divisor: Polynomial,
+    pub steps: Vec<DivisionStep>,
+    pub quotient: Polynomial,
+    pub remainder: Polynomial
+}
+
+impl DivisionTrace {
+    /// Returns the division in the worked layout as a LaTeX `array`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let dividend = Polynomial::from_coefficients(&vec![1.0, 0.0, -1.0]);
+    /// let divisor = Polynomial::from_coefficients(&vec![1.0, 1.0]);
+    /// let latex = dividend.div_rem_steps(&divisor).to_latex();
+    /// assert!(latex.starts_with("\\begin{array}{rl}\n & x^{2} - 1"));
+    /// ```
+    pub fn to_latex(&self) -> String {
+        let latex = |poly: &Polynomial| poly.format_with(PolynomialFormat::Latex);
+        let mut result = String::from("\\begin{array}{rl}\n");
+        result.push_str(&format!(" & {} \\\\\n", latex(&self.dividend)));
+        for step in &self.steps {
+            result.push_str(&format!(
+                "- & ({}) = {} \\cdot ({}) \\\\ \\hline\n",
+                latex(&step.product),
+                latex(&step.quotient_term),
+                latex(&self.divisor)
+            ));
+            result.push_str(&format!(" & {} \\\\\n", latex(&step.remainder)));
+        }
+        result.push_str(&format!("\\text{{quotient}} & {} \\\\\n", latex(&self.quotient)));
+        result.push_str(&format!("\\text{{remainder}} & {}\n", latex(&self.remainder)));
+        result.push_str("\\end{array}");
+        result
+    }
+}
+
+impl fmt::Display for DivisionTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "({}) / ({})", self.dividend, self.divisor)?;
+        writeln!(f, "  {}", self.dividend)?;
+        for step in &self.steps {
+            writeln!(f, "- ({}) = {} * ({})", step.product, step.quotient_term, self.divisor)?;
+            writeln!(f, "= {}", step.remainder)?;
+        }
+        writeln!(f, "quotient: {}", self.quotient)?;
+        write!(f, "remainder: {}", self.remainder)
+    }
+}
+
+impl Polynomial {
+    /// Divides the polynomial by the divisor and records the steps of the long division.
+    ///
+    /// The quotient and the remainder are the same as returned by the `/` operator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let dividend = Polynomial::from_coefficients(&vec![1.0, -2.0, 0.0, -4.0]);
+    /// let divisor = Polynomial::from_coefficients(&vec![1.0, -3.0]);
+    /// let trace = dividend.div_rem_steps(&divisor);
+    /// assert_eq!(3, trace.steps.len());
+    /// assert_eq!("x^2 - 4", trace.steps[0].remainder.to_string());
+    /// assert_eq!("x^2 + x + 3", trace.quotient.to_string());
+    /// assert_eq!("5", trace.remainder.to_string());
+    /// ```
+    pub fn div_rem_steps(&self, divisor: &Polynomial) -> DivisionTrace {
+        let mut steps = Vec::new();
+        let mut remainder = self.clone();
+        let quotient = divide_in_place_with::<true, _>(&mut remainder, divisor, |term, product, remainder| {
+            steps.push(DivisionStep {
+                quotient_term: term.clone(),
+                product: product.clone(),
+                remainder: remainder.clone()
+            });
+        });
+        DivisionTrace {
+            dividend: self.clone(),
+            divisor: divisor.clone(),
+            steps,
+            quotient,
+            remainder
+        }
+    }
+}
+
 fn divide_by_scalar_in_place(poly: &mut Polynomial, scalar: f64) {
     if scalar == 0.0 {
         panic!("Cannot divide by zero.");
@@ -61,6 +204,7 @@ fn divide_by_scalar_in_place(poly: &mut Polynomial, scalar: f64) {
     for (_, coefficient) in poly.coefficients.iter_mut() {
         *coefficient /= scalar;
     }
+    poly.remove_zero_coefficients();
 }
 
 impl Div<&Self> for Polynomial {
@@ -75,6 +219,22 @@ impl Div<&Self> for Polynomial {
     }
 }
 
+impl Div for Polynomial {
+    type Output = PolynomialDivisionResult;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self / &rhs
+    }
+}
+
+impl Div for &Polynomial {
+    type Output = PolynomialDivisionResult;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        self.clone() / rhs
+    }
+}
+
 impl Div<f64> for Polynomial {
     type Output = Polynomial;
 
@@ -99,6 +259,12 @@ impl DivAssign<&Self> for Polynomial {
     }
 }
 
+impl DivAssign for Polynomial {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = divide_in_place(self, &rhs);
+    }
+}
+
 impl DivAssign<f64> for Polynomial {
     fn div_assign(&mut self, rhs: f64) {
         divide_by_scalar_in_place(self, rhs);
@@ -120,12 +286,35 @@ impl Rem<&Self> for Polynomial {
     }
 }
 
+impl Rem for Polynomial {
+    type Output = Polynomial;
+
+    fn rem(mut self, rhs: Self) -> Self::Output {
+        divide_in_place(&mut self, &rhs);
+        self
+    }
+}
+
+impl Rem for &Polynomial {
+    type Output = Polynomial;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.clone() % rhs
+    }
+}
+
 impl RemAssign<&Self> for Polynomial {
     fn rem_assign(&mut self, rhs: &Self) {
         divide_in_place(self, rhs);
     }
 }
 
+impl RemAssign for Polynomial {
+    fn rem_assign(&mut self, rhs: Self) {
+        divide_in_place(self, &rhs);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Polynomial;
@@ -139,6 +328,15 @@ mod tests {
         assert_eq!(vec![1.0, 5.0], div_result.remainder.get_coefficients());
     }
 
+    #[test]
+    fn div_owned() {
+        let numerator = Polynomial::from_coefficients(&vec![-4.0, 12.0, -21.0, 19.0, 0.0]);
+        let denominator = Polynomial::from_coefficients(&vec![2.0, -3.0, 5.0]);
+        let div_result = numerator / denominator;
+        assert_eq!(vec![-2.0, 3.0, -1.0], div_result.quotient.get_coefficients());
+        assert_eq!(vec![1.0, 5.0], div_result.remainder.get_coefficients());
+    }
+
     #[test]
     fn div_float() {
         let poly = Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
@@ -153,6 +351,14 @@ mod tests {
         assert_eq!(vec![0.5, 1.0, -1.5], poly_divided_by_two.get_coefficients());
     }
 
+    #[test]
+    fn div_float_drops_underflowed_coefficients() {
+        let poly = Polynomial::from_coefficients(&vec![1e-300, 1e300]);
+        let quotient = poly / 1e300;
+        assert_eq!(Some(0), quotient.degree());
+        assert_eq!(vec![1.0], quotient.get_coefficients());
+    }
+
     #[test]
     fn div_assign() {
         let mut poly = Polynomial::from_coefficients(&vec![1.0, 4.0, -1.0, -3.0]);
@@ -161,6 +367,13 @@ mod tests {
         assert_eq!(vec![1.0, 2.0], poly.get_coefficients());
     }
 
+    #[test]
+    fn div_assign_owned() {
+        let mut poly = Polynomial::from_coefficients(&vec![1.0, 4.0, -1.0, -3.0]);
+        poly /= Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
+        assert_eq!(vec![1.0, 2.0], poly.get_coefficients());
+    }
+
     #[test]
     fn div_assign_float() {
         let mut poly = Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
@@ -183,6 +396,20 @@ mod tests {
         assert_eq!(vec![-2.0, 3.0], remainder.get_coefficients());
     }
 
+    #[test]
+    fn rem_owned() {
+        let numerator = Polynomial::from_coefficients(&vec![1.0, 4.0, -1.0, -3.0]);
+        let denominator = Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
+        assert_eq!(vec![-2.0, 3.0], (numerator % denominator).get_coefficients());
+    }
+
+    #[test]
+    fn rem_assign_owned() {
+        let mut poly = Polynomial::from_coefficients(&vec![1.0, 4.0, -1.0, -3.0]);
+        poly %= Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
+        assert_eq!(vec![-2.0, 3.0], poly.get_coefficients());
+    }
+
     #[test]
     fn rem_assign() {
         let mut poly = Polynomial::from_coefficients(&vec![1.0, 4.0, -1.0, -3.0]);
@@ -191,6 +418,102 @@ mod tests {
         assert_eq!(vec![-2.0, 3.0], poly.get_coefficients());
     }
 
+    /// The textbook example (x^3 - 2x^2 - 4) / (x - 3) = x^2 + x + 3, remainder 5.
+    fn textbook_division() -> (Polynomial, Polynomial) {
+        (
+            Polynomial::from_coefficients(&vec![1.0, -2.0, 0.0, -4.0]),
+            Polynomial::from_coefficients(&vec![1.0, -3.0])
+        )
+    }
+
+    #[test]
+    fn div_rem_steps_records_textbook_steps() {
+        let (dividend, divisor) = textbook_division();
+        let trace = dividend.div_rem_steps(&divisor);
+        let steps: Vec<[String; 3]> = trace.steps.iter()
+            .map(|step| [
+                step.quotient_term.to_string(),
+                step.product.to_string(),
+                step.remainder.to_string()
+            ])
+            .collect();
+        assert_eq!(vec![
+            ["x^2", "x^3 - 3x^2", "x^2 - 4"].map(String::from),
+            ["x", "x^2 - 3x", "3x - 4"].map(String::from),
+            ["3", "3x - 9", "5"].map(String::from),
+        ], steps);
+    }
+
+    #[test]
+    fn div_rem_steps_recombine() {
+        let (dividend, divisor) = textbook_division();
+        let trace = dividend.div_rem_steps(&divisor);
+
+        let mut remainder = dividend.clone();
+        let mut quotient = Polynomial::zero();
+        for step in &trace.steps {
+            assert_eq!(step.product, step.quotient_term.clone() * &divisor);
+            remainder -= &step.product;
+            quotient += &step.quotient_term;
+            assert_eq!(remainder, step.remainder);
+        }
+        assert_eq!(quotient, trace.quotient);
+        assert_eq!(remainder, trace.remainder);
+        assert_eq!(dividend, trace.quotient.clone() * &divisor + &trace.remainder);
+
+        let result = dividend / &divisor;
+        assert_eq!(result.quotient, trace.quotient);
+        assert_eq!(result.remainder, trace.remainder);
+    }
+
+    #[test]
+    fn div_rem_steps_of_lower_degree_dividend() {
+        let dividend = Polynomial::from_coefficients(&vec![2.0, 1.0]);
+        let trace = dividend.div_rem_steps(&Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]));
+        assert!(trace.steps.is_empty());
+        assert!(trace.quotient.is_zero());
+        assert_eq!(dividend, trace.remainder);
+    }
+
+    #[test]
+    fn division_trace_display() {
+        let (dividend, divisor) = textbook_division();
+        let expected = "\
+(x^3 - 2x^2 - 4) / (x - 3)
+  x^3 - 2x^2 - 4
+- (x^3 - 3x^2) = x^2 * (x - 3)
+= x^2 - 4
+- (x^2 - 3x) = x * (x - 3)
+= 3x - 4
+- (3x - 9) = 3 * (x - 3)
+= 5
+quotient: x^2 + x + 3
+remainder: 5";
+        assert_eq!(expected, dividend.div_rem_steps(&divisor).to_string());
+    }
+
+    #[test]
+    fn division_trace_to_latex() {
+        let dividend = Polynomial::from_coefficients(&vec![1.0, 0.0, -1.0]);
+        let divisor = Polynomial::from_coefficients(&vec![1.0, 1.0]);
+        let expected = r"\begin{array}{rl}
+ & x^{2} - 1 \\
+- & (x^{2} + x) = x \cdot (x + 1) \\ \hline
+ & - x - 1 \\
+- & (- x - 1) = - 1 \cdot (x + 1) \\ \hline
+ & 0 \\
+\text{quotient} & x - 1 \\
+\text{remainder} & 0
+\end{array}";
+        assert_eq!(expected, dividend.div_rem_steps(&divisor).to_latex());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot divide")]
+    fn div_rem_steps_by_zero_polynomial() {
+        let _ = Polynomial::one().div_rem_steps(&Polynomial::zero());
+    }
+
     #[test]
     #[should_panic(expected = "Cannot divide")]
     fn div_by_zero_polynomial() {
@@ -211,4 +534,27 @@ mod tests {
         let poly = Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
         let _ = poly / 0;
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn div_and_rem_borrowed() {
+        let numerator = Polynomial::from_coefficients(&vec![1.0, 0.0, -3.0, 2.0]);
+        let denominator = Polynomial::from_coefficients(&vec![1.0, -1.0]);
+        let result = &numerator / &denominator;
+        assert_eq!(vec![1.0, 1.0, -2.0], result.quotient.get_coefficients());
+        assert_eq!(Polynomial::zero(), result.remainder);
+        assert_eq!(Polynomial::from_coefficients(&vec![1.0]), &(&numerator + &Polynomial::one()) % &denominator);
+        assert_eq!(vec![1.0, 0.0, -3.0, 2.0], numerator.get_coefficients());
+        assert_eq!(vec![1.0, -1.0], denominator.get_coefficients());
+    }
+
+    #[test]
+    fn leading_terms_cancel_despite_rounding() {
+        // The leading terms of the remainders don't cancel exactly in floating point
+        let numerator = Polynomial::from_coefficients(&vec![-6.0, 2.0, -2.0, 6.0, 28.0, 18.0, 6.0]);
+        let denominator = Polynomial::from_coefficients(&vec![16.0, 28.0, 10.0, -6.0, 2.0, 6.0]);
+        let result = &denominator / &(&numerator % &denominator);
+        assert!(result.remainder.degree() < Some(4));
+        let result = &Polynomial::from_coefficients(&vec![0.1, 0.7]) / &Polynomial::from_coefficients(&vec![0.3, 0.2]);
+        assert_eq!(Some(0), result.remainder.degree());
+    }
+}
@@ -1,5 +1,7 @@
+use std::iter::Product;
 use std::ops::{Mul, MulAssign};
 use super::Polynomial;
+use crate::TermOrder;
 
 fn multiply(poly1: &Polynomial, poly2: &Polynomial) -> Polynomial {
     let mut poly = Polynomial::zero();
@@ -14,6 +16,27 @@ fn multiply(poly1: &Polynomial, poly2: &Polynomial) -> Polynomial {
     poly
 }
 
+/// Raises `base` to the power with binary exponentiation, starting from `one` and combining
+/// the values with `mul`. Stops and returns `None` as soon as a multiplication does.
+pub(crate) fn binary_pow<T: Clone>(
+    mut base: T,
+    mut exponent: u32,
+    one: T,
+    mut mul: impl FnMut(&T, &T) -> Option<T>
+) -> Option<T> {
+    let mut result = one;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul(&result, &base)?;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = mul(&base, &base)?;
+        }
+    }
+    Some(result)
+}
+
 fn multiply_in_place_by_scalar(poly: &mut Polynomial, scalar: f64) {
     // Prevent zeros from being present in the map
     if scalar == 0.0 {
@@ -23,6 +46,117 @@ fn multiply_in_place_by_scalar(poly: &mut Polynomial, scalar: f64) {
     for (_, coefficient) in poly.coefficients.iter_mut() {
         *coefficient *= scalar;
     }
+    poly.remove_zero_coefficients();
+}
+
+/// Returns the full convolution of the slices, i.e. the coefficients of the product of the
+/// polynomials with the given coefficients, of length `a.len() + b.len() - 1`.
+///
+/// The convolution doesn't depend on whether the coefficients are in the ascending or the
+/// descending order of powers, as long as both slices and the result use the same one. The
+/// convolution with an empty slice is empty.
+///
+/// # Examples
+///
+/// ```
+/// use polynomials::convolve_slices;
+///
+/// // (1 + 2x)(3 - x + x^2)
+/// assert_eq!(vec![3.0, 5.0, -1.0, 2.0], convolve_slices(&[1.0, 2.0], &[3.0, -1.0, 1.0]));
+/// assert!(convolve_slices(&[], &[1.0]).is_empty());
+/// ```
+pub fn convolve_slices(a: &[f64], b: &[f64]) -> Vec<f64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut result = vec![0.0; a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (y, sum) in b.iter().zip(&mut result[i..]) {
+            *sum += x * y;
+        }
+    }
+    result
+}
+
+impl Polynomial {
+    /// Returns the product of the polynomial and the polynomial with the given coefficients,
+    /// without building the other polynomial.
+    ///
+    /// The coefficients are in the given [order](TermOrder), so the last one is the constant
+    /// term in the `TermOrder::Descending` order. The product with an empty slice is the zero
+    /// polynomial. The result is the same as multiplying by the polynomial built from the
+    /// coefficients.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{Polynomial, TermOrder};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -1.0]);
+    /// let product = poly.convolve(&[0.5, 0.5], TermOrder::Ascending);
+    /// assert_eq!(vec![0.5, 0.5, -0.5, -0.5], product.get_coefficients());
+    /// ```
+    pub fn convolve(&self, taps: &[f64], order: TermOrder) -> Polynomial {
+        let mut poly = Polynomial::zero();
+        let last = taps.len().saturating_sub(1);
+        for (power, coefficient) in self.coefficients.iter() {
+            // The taps are visited in the ascending order of powers, as in the multiplication
+            for tap_power in 0..taps.len() {
+                let tap = match order {
+                    TermOrder::Ascending => taps[tap_power],
+                    TermOrder::Descending => taps[last - tap_power],
+                };
+                if tap != 0.0 {
+                    poly.add_coefficient_at(*power + tap_power as u32, *coefficient * tap);
+                }
+            }
+        }
+        poly
+    }
+
+    /// Multiplies the polynomial by the other one in place and returns it for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let mut poly = Polynomial::from_coefficients(&vec![1.0, 1.0]);
+    /// poly.mul_assign_ref(&Polynomial::from_coefficients(&vec![1.0, -1.0]))
+    ///     .mul_scalar_in_place(2.0);
+    /// assert_eq!(vec![2.0, 0.0, -2.0], poly.get_coefficients());
+    /// ```
+    pub fn mul_assign_ref(&mut self, other: &Polynomial) -> &mut Self {
+        *self = multiply(self, other);
+        self
+    }
+
+    /// Multiplies every coefficient by the scalar in place and returns the polynomial for
+    /// chaining.
+    pub fn mul_scalar_in_place(&mut self, scalar: f64) -> &mut Self {
+        multiply_in_place_by_scalar(self, scalar);
+        self
+    }
+
+    /// Raises the polynomial to the power with binary exponentiation, using a number of
+    /// multiplications logarithmic in the exponent.
+    ///
+    /// Any polynomial raised to the power zero is the constant one, including the zero
+    /// polynomial, i.e. `0^0 = 1`, the same as for [`f64::powi`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 1.0]);
+    /// assert_eq!(vec![1.0, 4.0, 6.0, 4.0, 1.0], poly.pow(4).get_coefficients());
+    /// assert_eq!(Polynomial::one(), Polynomial::zero().pow(0));
+    /// ```
+    pub fn pow(&self, exponent: u32) -> Polynomial {
+        binary_pow(self.clone(), exponent, Polynomial::one(), |a, b| Some(multiply(a, b)))
+            .expect("Multiplication cannot fail")
+    }
 }
 
 impl Mul<&Self> for Polynomial {
@@ -33,6 +167,22 @@ impl Mul<&Self> for Polynomial {
     }
 }
 
+impl Mul for Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        multiply(&self, &rhs)
+    }
+}
+
+impl Mul for &Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        multiply(self, rhs)
+    }
+}
+
 impl Mul<f64> for Polynomial {
     type Output = Polynomial;
 
@@ -51,9 +201,33 @@ impl Mul<i32> for Polynomial {
     }
 }
 
+#[cfg(any(test, feature = "num-traits"))]
+impl num_traits::Pow<u32> for Polynomial {
+    type Output = Polynomial;
+
+    fn pow(self, rhs: u32) -> Self::Output {
+        Polynomial::pow(&self, rhs)
+    }
+}
+
+#[cfg(any(test, feature = "num-traits"))]
+impl num_traits::Pow<u32> for &Polynomial {
+    type Output = Polynomial;
+
+    fn pow(self, rhs: u32) -> Self::Output {
+        Polynomial::pow(self, rhs)
+    }
+}
+
 impl MulAssign<&Self> for Polynomial {
     fn mul_assign(&mut self, rhs: &Self) {
-        *self = multiply(&self, rhs);
+        *self = multiply(self, rhs);
+    }
+}
+
+impl MulAssign for Polynomial {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = multiply(self, &rhs);
     }
 }
 
@@ -69,9 +243,37 @@ impl MulAssign<i32> for Polynomial {
     }
 }
 
+impl Product for Polynomial {
+    fn product<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+        let Some(mut result) = iter.next() else {
+            return Polynomial::one();
+        };
+        for poly in iter {
+            result *= &poly;
+        }
+        result
+    }
+}
+
+impl<'a> Product<&'a Polynomial> for Polynomial {
+    fn product<I: Iterator<Item = &'a Polynomial>>(mut iter: I) -> Self {
+        let Some(first) = iter.next() else {
+            return Polynomial::one();
+        };
+        let mut result = first.clone();
+        for poly in iter {
+            result *= poly;
+        }
+        result
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Polynomial;
+    use proptest::prelude::*;
+    use super::{convolve_slices, Polynomial};
+    use crate::TermOrder;
+    use crate::polynomial::strategy::{polynomials, polynomials_with};
 
     #[test]
     fn mul() {
@@ -81,6 +283,13 @@ mod tests {
         assert_eq!(vec![-2.0, 4.0, 3.0, -6.0], poly3.get_coefficients());
     }
 
+    #[test]
+    fn mul_owned() {
+        let poly1 = Polynomial::from_coefficients(&vec![1.0, -2.0]);
+        let poly2 = Polynomial::from_coefficients(&vec![-2.0, 0.0, 3.0]);
+        assert_eq!(vec![-2.0, 4.0, 3.0, -6.0], (poly1 * poly2).get_coefficients());
+    }
+
     #[test]
     fn mul_float() {
         let poly = Polynomial::from_coefficients(&vec![-2.0, 0.0, 1.0]);
@@ -103,6 +312,13 @@ mod tests {
         assert_eq!(vec![-2.0, 4.0, 3.0, -6.0], poly1.get_coefficients());
     }
 
+    #[test]
+    fn mul_assign_owned() {
+        let mut poly1 = Polynomial::from_coefficients(&vec![1.0, -2.0]);
+        poly1 *= Polynomial::from_coefficients(&vec![-2.0, 0.0, 3.0]);
+        assert_eq!(vec![-2.0, 4.0, 3.0, -6.0], poly1.get_coefficients());
+    }
+
     #[test]
     fn mul_assign_float() {
         let mut poly = Polynomial::from_coefficients(&vec![-2.0, 0.0, 1.0]);
@@ -123,4 +339,130 @@ mod tests {
         let poly_times_zero = poly * 0.0;
         assert_eq!(Polynomial::zero(), poly_times_zero);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn mul_by_scalar_drops_underflowed_coefficients() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, 1e-300]);
+        let product = poly * 1e-300;
+        assert_eq!(0.0, product.get_coefficient_at(0));
+        assert_eq!(vec![1e-300, 0.0], product.get_coefficients());
+        assert_eq!(Polynomial::from_coefficients(&vec![1e-300, 0.0]), product);
+    }
+
+    #[test]
+    fn product_of_linear_factors() {
+        // (x - 1)(x - 2)(x + 3) = x^3 - 7x + 6
+        let factors = vec![
+            Polynomial::from_coefficients(&vec![1.0, -1.0]),
+            Polynomial::from_coefficients(&vec![1.0, -2.0]),
+            Polynomial::from_coefficients(&vec![1.0, 3.0]),
+        ];
+        let expected = Polynomial::from_coefficients(&vec![1.0, 0.0, -7.0, 6.0]);
+        assert_eq!(expected, factors.iter().product::<Polynomial>());
+        assert_eq!(expected, factors.into_iter().product::<Polynomial>());
+    }
+
+    #[test]
+    fn product_of_empty_iterator_is_one() {
+        let polys: Vec<Polynomial> = Vec::new();
+        assert_eq!(Polynomial::one(), polys.iter().product::<Polynomial>());
+        assert_eq!(Polynomial::one(), polys.into_iter().product::<Polynomial>());
+    }
+
+    #[test]
+    fn convolve_in_both_orders() {
+        let poly = Polynomial::from_coefficients(&vec![2.0, 0.0, 0.0, 1.0]);
+        // 1 - 3x + x^2 in both orders
+        let expected = vec![2.0, -6.0, 2.0, 1.0, -3.0, 1.0];
+        assert_eq!(expected, poly.convolve(&[1.0, -3.0, 1.0], TermOrder::Ascending).get_coefficients());
+        assert_eq!(expected, poly.convolve(&[1.0, -3.0, 1.0], TermOrder::Descending).get_coefficients());
+
+        // 1 + 2x ascending is 2 + x descending
+        let ascending = poly.convolve(&[1.0, 2.0, 0.0], TermOrder::Ascending);
+        let descending = poly.convolve(&[0.0, 2.0, 1.0], TermOrder::Descending);
+        assert_eq!(ascending, descending);
+        assert_eq!(poly.clone() * &Polynomial::from_coefficients(&vec![2.0, 1.0]), ascending);
+    }
+
+    #[test]
+    fn convolve_with_empty_or_zero() {
+        let poly = Polynomial::from_coefficients(&vec![2.0, 1.0]);
+        assert_eq!(Polynomial::zero(), poly.convolve(&[], TermOrder::Ascending));
+        assert_eq!(Polynomial::zero(), poly.convolve(&[0.0, 0.0], TermOrder::Descending));
+        assert_eq!(Polynomial::zero(), Polynomial::zero().convolve(&[1.0, 2.0], TermOrder::Ascending));
+        assert!(convolve_slices(&[1.0], &[]).is_empty());
+        assert_eq!(vec![0.0, 0.0], convolve_slices(&[0.0], &[1.0, 2.0]));
+    }
+
+    proptest! {
+        #[test]
+        fn convolve_matches_mul(
+            p in polynomials(20),
+            taps in prop::collection::vec(-1e6..1e6f64, 0..12),
+        ) {
+            let mut descending = taps.clone();
+            descending.reverse();
+            let other = if taps.is_empty() { Polynomial::zero() } else { Polynomial::from_coefficients(&descending) };
+            prop_assert_eq!(p.clone() * &other, p.convolve(&taps, TermOrder::Ascending));
+            prop_assert_eq!(p.clone() * &other, p.convolve(&descending, TermOrder::Descending));
+        }
+
+        #[test]
+        fn convolve_slices_matches_mul(
+            p in polynomials_with((-9..=9).prop_map(f64::from), 12),
+            q in polynomials_with((-9..=9).prop_map(f64::from), 12),
+        ) {
+            let product = convolve_slices(&p.get_coefficients(), &q.get_coefficients());
+            prop_assert_eq!(p * &q, product);
+        }
+    }
+
+    #[test]
+    fn mul_borrowed() {
+        let a = Polynomial::from_coefficients(&vec![1.0, 2.0]);
+        let b = Polynomial::from_coefficients(&vec![1.0, -2.0]);
+        let c = Polynomial::from_coefficients(&vec![3.0]);
+        assert_eq!(vec![3.0, 0.0, -12.0], (&a * &b * &c).get_coefficients());
+        assert_eq!(vec![1.0, 2.0], a.get_coefficients());
+        assert_eq!(vec![1.0, -2.0], b.get_coefficients());
+        assert_eq!(Polynomial::zero(), &a * &Polynomial::zero());
+    }
+
+    #[test]
+    fn pow_of_zero() {
+        assert_eq!(Polynomial::one(), Polynomial::zero().pow(0));
+        assert_eq!(Polynomial::zero(), Polynomial::zero().pow(1));
+        assert_eq!(Polynomial::zero(), Polynomial::zero().pow(20));
+    }
+
+    #[test]
+    fn pow_of_binomial() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, 1.0]).pow(64);
+        assert_eq!(Some(64), poly.degree());
+        assert_eq!(1.0, poly.get_coefficient_at(64));
+        assert_eq!(64.0, poly.get_coefficient_at(1));
+        assert_eq!(2016.0, poly.get_coefficient_at(62));
+        assert_eq!(2f64.powi(64), poly.evaluate(1.0));
+    }
+
+    #[test]
+    fn pow_trait() {
+        use num_traits::Pow;
+        let poly = Polynomial::from_coefficients(&vec![1.0, -1.0]);
+        assert_eq!(vec![1.0, -3.0, 3.0, -1.0], Pow::pow(&poly, 3u32).get_coefficients());
+        assert_eq!(Polynomial::one(), Pow::pow(poly, 0u32));
+    }
+
+    proptest! {
+        #[test]
+        fn pow_matches_repeated_multiplication(
+            p in polynomials_with((-1..=1).prop_map(|n| f64::from(n) / 2.0), 2),
+            exponent in 0..=20u32,
+        ) {
+            // The numerators are at most 3^20 and the denominators are powers of two, so both
+            // products are exact
+            let expected = (0..exponent).fold(Polynomial::one(), |product, _| product * &p);
+            prop_assert_eq!(expected, p.pow(exponent));
+        }
+    }
+}
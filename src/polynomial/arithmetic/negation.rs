@@ -1,11 +1,41 @@
 use std::ops::Neg;
 use super::Polynomial;
 
+impl Polynomial {
+    /// Negates all coefficients of the polynomial in place and returns it for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let mut poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 3.0]);
+    /// poly.negate_in_place();
+    /// assert_eq!(vec![-1.0, 2.0, -3.0], poly.get_coefficients());
+    /// ```
+    pub fn negate_in_place(&mut self) -> &mut Self {
+        // Flipping the sign never produces a zero, so the map stays normalized
+        for (_, coefficient) in self.coefficients.iter_mut() {
+            *coefficient = -*coefficient;
+        }
+        self
+    }
+}
+
 impl Neg for Polynomial {
     type Output = Polynomial;
 
+    fn neg(mut self) -> Self::Output {
+        self.negate_in_place();
+        self
+    }
+}
+
+impl Neg for &Polynomial {
+    type Output = Polynomial;
+
     fn neg(self) -> Self::Output {
-        self * -1
+        -self.clone()
     }
 }
 
@@ -19,4 +49,32 @@ mod tests {
         let poly2 = Polynomial::from_coefficients(&vec![-1.0, -2.0, 3.0]);
         assert_eq!(poly1, -poly2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn reference_negation_leaves_operand_usable() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
+        let negated = -&poly;
+        assert_eq!(vec![-1.0, -2.0, 3.0], negated.get_coefficients());
+        assert_eq!(vec![1.0, 2.0, -3.0], poly.get_coefficients());
+    }
+
+    #[test]
+    fn double_negation_is_identity() {
+        let poly = Polynomial::from_coefficients(&vec![4.0, 0.0, -0.5, 2.0]);
+        assert_eq!(poly, -(-&poly));
+
+        let mut negated = poly.clone();
+        negated.negate_in_place();
+        negated.negate_in_place();
+        assert_eq!(poly, negated);
+    }
+
+    #[test]
+    fn negation_of_zero_polynomial() {
+        let mut poly = Polynomial::zero();
+        poly.negate_in_place();
+        assert!(poly.is_zero());
+        assert!((-&poly).is_zero());
+        assert!((-poly).is_zero());
+    }
+}
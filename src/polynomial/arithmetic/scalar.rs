@@ -0,0 +1,87 @@
+//! Module implementing arithmetic operators with a scalar on the left-hand side.
+//!
+//! The orphan rules require a separate implementation for every scalar type, so the
+//! implementations are generated by a macro for the same scalar types that are accepted on the
+//! right-hand side. Dividing a scalar by a polynomial is intentionally not supported, since the
+//! result is not a polynomial in general.
+use std::ops::{Add, Mul, Sub};
+use super::Polynomial;
+
+macro_rules! impl_scalar_lhs_ops {
+    ($($t:ty),*) => {
+        $(
+            impl Add<Polynomial> for $t {
+                type Output = Polynomial;
+
+                fn add(self, rhs: Polynomial) -> Self::Output {
+                    rhs + self
+                }
+            }
+
+            impl Sub<Polynomial> for $t {
+                type Output = Polynomial;
+
+                fn sub(self, rhs: Polynomial) -> Self::Output {
+                    -rhs + self
+                }
+            }
+
+            impl Mul<Polynomial> for $t {
+                type Output = Polynomial;
+
+                fn mul(self, rhs: Polynomial) -> Self::Output {
+                    rhs * self
+                }
+            }
+        )*
+    };
+}
+
+impl_scalar_lhs_ops!(f64, i32);
+
+#[cfg(test)]
+mod tests {
+    use super::Polynomial;
+
+    #[test]
+    fn float_add_polynomial() {
+        let poly = Polynomial::from_coefficients(&vec![-2.0, 0.0, 1.0]);
+        assert_eq!(vec![-2.0, 0.0, 3.0], (2.0 + poly).get_coefficients());
+    }
+
+    #[test]
+    fn int_add_polynomial() {
+        let poly = Polynomial::from_coefficients(&vec![-2.0, 0.0, 1.0]);
+        assert_eq!(vec![-2.0, 0.0, 3.0], (2 + poly).get_coefficients());
+    }
+
+    #[test]
+    fn float_sub_polynomial() {
+        let poly = Polynomial::from_coefficients(&vec![-2.0, 3.0, 1.0]);
+        assert_eq!(vec![2.0, -3.0, 1.0], (2.0 - poly).get_coefficients());
+    }
+
+    #[test]
+    fn int_sub_polynomial() {
+        let poly = Polynomial::from_coefficients(&vec![-2.0, 3.0, 1.0]);
+        assert_eq!(vec![2.0, -3.0, 1.0], (2 - poly).get_coefficients());
+    }
+
+    #[test]
+    fn float_mul_polynomial() {
+        let poly = Polynomial::from_coefficients(&vec![-2.0, 0.0, 1.0]);
+        assert_eq!(vec![-4.0, 0.0, 2.0], (2.0 * poly).get_coefficients());
+    }
+
+    #[test]
+    fn int_mul_polynomial() {
+        let poly = Polynomial::from_coefficients(&vec![-2.0, 0.0, 1.0]);
+        assert_eq!(vec![-4.0, 0.0, 2.0], (2 * poly).get_coefficients());
+    }
+
+    #[test]
+    fn scalar_sub_matches_negated_difference() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, -4.0, 2.5]);
+        assert_eq!(-(poly.clone() - 3.0), 3.0 - poly);
+    }
+}
@@ -0,0 +1,134 @@
+use super::Polynomial;
+
+/// Returns the candidate square root with a positive leading coefficient, whose square agrees
+/// with the polynomial in the upper half of the terms, or `None` if the degree is odd or the
+/// leading coefficient is negative.
+///
+/// The coefficients of the root are solved for from the highest power downward, each from the
+/// coefficient of the square with the same distance from the leading term.
+fn sqrt_candidate(poly: &Polynomial) -> Option<Polynomial> {
+    let Some(degree) = poly.degree() else {
+        return Some(Polynomial::zero());
+    };
+    let leading_coefficient = poly.get_coefficient_at(degree);
+    if degree % 2 == 1 || leading_coefficient < 0.0 {
+        return None;
+    }
+    let root_degree = degree / 2;
+
+    // The coefficients of the root from the highest power downward
+    let mut root = vec![leading_coefficient.sqrt()];
+    for k in 1..=root_degree {
+        let cross_terms: f64 = (1..k).map(|i| root[i as usize] * root[(k - i) as usize]).sum();
+        let coefficient = (poly.get_coefficient_at(degree - k) - cross_terms) / (2.0 * root[0]);
+        root.push(coefficient);
+    }
+    Some(Polynomial::from_coefficients(&root))
+}
+
+impl Polynomial {
+    /// Returns the polynomial `Q` with a positive leading coefficient such that `Q * Q` is
+    /// exactly equal to this polynomial, or `None` if there is no such polynomial.
+    ///
+    /// The polynomials of odd degree or with a negative leading coefficient are rejected
+    /// immediately. The coefficients of `Q` are solved for from the highest power downward and
+    /// the square is verified, so the exact root is found when the floating point operations
+    /// are exact, e.g. for small integer coefficients. Use
+    /// [`sqrt_with_tolerance`](Polynomial::sqrt_with_tolerance) otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // (2x - 3)^2
+    /// let poly = Polynomial::from_coefficients(&vec![4.0, -12.0, 9.0]);
+    /// assert_eq!(Some(vec![2.0, -3.0]), poly.sqrt_exact().map(|root| root.get_coefficients()));
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![4.0, -12.0, 8.0]);
+    /// assert_eq!(None, poly.sqrt_exact());
+    /// ```
+    pub fn sqrt_exact(&self) -> Option<Polynomial> {
+        let root = sqrt_candidate(self)?;
+        (root.clone() * &root == *self).then_some(root)
+    }
+
+    /// Returns the polynomial `Q` with a positive leading coefficient such that `Q * Q` differs
+    /// from this polynomial by at most the tolerance times its largest coefficient in every
+    /// coefficient, or `None` if there is no such polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // (x + 0.1)^2 with the rounding errors of the coefficients
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.2, 0.1 * 0.1]);
+    /// let root = poly.sqrt_with_tolerance(1e-12).unwrap();
+    /// assert!((root.get_coefficient_at(0) - 0.1).abs() < 1e-15);
+    /// ```
+    pub fn sqrt_with_tolerance(&self, tolerance: f64) -> Option<Polynomial> {
+        let root = sqrt_candidate(self)?;
+        let difference = root.clone() * &root - self;
+        let max_abs = |poly: &Polynomial| poly.coefficients.values().fold(0.0, |max: f64, coefficient| max.max(coefficient.abs()));
+        (max_abs(&difference) <= tolerance * max_abs(self)).then_some(root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use crate::Polynomial;
+    use crate::polynomial::strategy::polynomials_with;
+
+    #[test]
+    fn non_squares_are_rejected() {
+        // (x^2 + 1)(x^2 + 2)
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 3.0, 0.0, 2.0]);
+        assert_eq!(None, poly.sqrt_exact());
+        assert_eq!(None, poly.sqrt_with_tolerance(1e-9));
+
+        assert_eq!(None, Polynomial::from_coefficients(&vec![1.0, 2.0, 1.0, 0.0]).sqrt_exact());
+        assert_eq!(None, Polynomial::from_coefficients(&vec![-1.0, 2.0, -1.0]).sqrt_exact());
+        assert_eq!(None, Polynomial::from_coefficients(&vec![-4.0]).sqrt_exact());
+    }
+
+    #[test]
+    fn squares_of_monomials_and_constants() {
+        assert_eq!(Some(Polynomial::zero()), Polynomial::zero().sqrt_exact());
+        assert_eq!(Some(Polynomial::from_coefficients(&vec![3.0])), Polynomial::from_coefficients(&vec![9.0]).sqrt_exact());
+        let x_cubed = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0, 0.0]);
+        let x_sixth = x_cubed.clone() * &x_cubed;
+        assert_eq!(Some(x_cubed), x_sixth.sqrt_exact());
+    }
+
+    #[test]
+    fn low_terms_are_verified() {
+        // (x^2 + x + 1)^2 + 1 agrees with the square in the upper half of the terms
+        let root = Polynomial::from_coefficients(&vec![1.0, 1.0, 1.0]);
+        let poly = root.clone() * &root + 1.0;
+        assert_eq!(None, poly.sqrt_exact());
+        assert_eq!(None, poly.sqrt_with_tolerance(1e-3));
+        assert!(poly.sqrt_with_tolerance(0.5).is_some());
+    }
+
+    #[test]
+    fn numerical_square() {
+        let root = Polynomial::from_coefficients(&vec![0.3, -1.0 / 7.0, 2.0_f64.sqrt()]);
+        let poly = root.clone() * &root;
+        let result = poly.sqrt_with_tolerance(1e-12).unwrap();
+        for power in 0..=2 {
+            assert!((root.get_coefficient_at(power) - result.get_coefficient_at(power)).abs() < 1e-12);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn squares_round_trip(p in polynomials_with((-9..=9).prop_map(f64::from), 6)) {
+            let square = p.clone() * &p;
+            let root = square.sqrt_exact().unwrap();
+            prop_assert!(root.get_coefficient_at(root.degree().unwrap_or(0)) >= 0.0);
+            prop_assert!(root == p || root == -p);
+        }
+    }
+}
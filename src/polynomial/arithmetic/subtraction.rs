@@ -7,6 +7,24 @@ fn subtract_in_place(poly1: &mut Polynomial, poly2: &Polynomial) {
     }
 }
 
+impl Polynomial {
+    /// Subtracts the other polynomial in place and returns the polynomial for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let mut poly = Polynomial::from_coefficients(&vec![1.0, 2.0]);
+    /// poly.sub_assign_ref(&Polynomial::from_coefficients(&vec![1.0, 0.0]));
+    /// assert_eq!(vec![2.0], poly.get_coefficients());
+    /// ```
+    pub fn sub_assign_ref(&mut self, other: &Polynomial) -> &mut Self {
+        subtract_in_place(self, other);
+        self
+    }
+}
+
 impl Sub<&Self> for Polynomial {
     type Output = Polynomial;
 
@@ -16,6 +34,41 @@ impl Sub<&Self> for Polynomial {
     }
 }
 
+impl Sub for Polynomial {
+    type Output = Polynomial;
+
+    fn sub(mut self, mut rhs: Self) -> Self::Output {
+        // Reuse the map of the operand with more terms
+        if rhs.coefficients.len() > self.coefficients.len() {
+            rhs.negate_in_place();
+            for (power, coefficient) in self.coefficients.iter() {
+                rhs.add_coefficient_at(*power, *coefficient);
+            }
+            return rhs;
+        }
+        subtract_in_place(&mut self, &rhs);
+        self
+    }
+}
+
+impl Sub for &Polynomial {
+    type Output = Polynomial;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        // Clone the operand with more terms
+        if rhs.coefficients.len() > self.coefficients.len() {
+            let mut result = -rhs;
+            for (power, coefficient) in self.coefficients.iter() {
+                result.add_coefficient_at(*power, *coefficient);
+            }
+            return result;
+        }
+        let mut result = self.clone();
+        subtract_in_place(&mut result, rhs);
+        result
+    }
+}
+
 impl Sub<f64> for Polynomial {
     type Output = Polynomial;
 
@@ -40,6 +93,12 @@ impl SubAssign<&Self> for Polynomial {
     }
 }
 
+impl SubAssign for Polynomial {
+    fn sub_assign(&mut self, rhs: Self) {
+        subtract_in_place(self, &rhs);
+    }
+}
+
 impl SubAssign<f64> for Polynomial {
     fn sub_assign(&mut self, rhs: f64) {
         self.sub_coefficient_at(0, rhs);
@@ -64,6 +123,17 @@ mod tests {
         assert_eq!(vec![3.0, 0.0, -2.0], poly3.get_coefficients());
     }
 
+    #[test]
+    fn sub_owned() {
+        let poly1 = Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
+        let poly2 = Polynomial::from_coefficients(&vec![-2.0, 2.0, -1.0]);
+        assert_eq!(vec![3.0, 0.0, -2.0], (poly1 - poly2).get_coefficients());
+
+        let short = Polynomial::from_coefficients(&vec![1.0, 0.0]);
+        let long = Polynomial::from_coefficients(&vec![1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(vec![-1.0, -2.0, -2.0, -4.0], (short - long).get_coefficients());
+    }
+
     #[test]
     fn sub_float() {
         let poly = Polynomial::from_coefficients(&vec![-2.0, 0.0, 1.0]);
@@ -86,6 +156,13 @@ mod tests {
         assert_eq!(vec![3.0, 0.0, -2.0], poly1.get_coefficients());
     }
 
+    #[test]
+    fn sub_assign_owned() {
+        let mut poly1 = Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
+        poly1 -= Polynomial::from_coefficients(&vec![-2.0, 2.0, -1.0]);
+        assert_eq!(vec![3.0, 0.0, -2.0], poly1.get_coefficients());
+    }
+
     #[test]
     fn sub_assign_float() {
         let mut poly = Polynomial::from_coefficients(&vec![-2.0, 0.0, 1.0]);
@@ -99,4 +176,16 @@ mod tests {
         poly -= 2;
         assert_eq!(vec![-2.0, 0.0, -1.0], poly.get_coefficients());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn sub_borrowed() {
+        let a = Polynomial::from_coefficients(&vec![1.0, 2.0]);
+        let b = Polynomial::from_coefficients(&vec![-1.0, 0.0, 3.0]);
+        let c = Polynomial::from_coefficients(&vec![4.0]);
+        assert_eq!(vec![1.0, 1.0, -5.0], (&a - &b - &c).get_coefficients());
+        assert_eq!(vec![-1.0, -1.0, 1.0], (&b - &a).get_coefficients());
+        assert_eq!(vec![1.0, 2.0], a.get_coefficients());
+        assert_eq!(vec![-1.0, 0.0, 3.0], b.get_coefficients());
+        assert_eq!(Polynomial::zero(), &b - &b);
+    }
+}
@@ -0,0 +1,129 @@
+//! Module containing the [`ndarray`] interoperability, enabled with the `ndarray` feature.
+//!
+//! The arrays of coefficients are in descending order, the same as used by the
+//! `Polynomial.get_coefficients` method and by `numpy.polyval`.
+use ndarray::{Array1, Array2, ArrayView1, ArrayView2};
+use super::Polynomial;
+
+impl Polynomial {
+    /// Constructs a new instance from an array of coefficients in descending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_array(array![2.0, 0.0, -1.0].view());
+    /// assert_eq!("2x^2 - 1", poly.to_string());
+    /// ```
+    pub fn from_array(coefficients: ArrayView1<f64>) -> Polynomial {
+        let mut poly = Polynomial::zero();
+        let degree = coefficients.len();
+        for (index, coefficient) in coefficients.iter().enumerate() {
+            poly.set_coefficient_at((degree - 1 - index) as u32, *coefficient);
+        }
+        poly
+    }
+
+    /// Returns an array of coefficients in descending order, with the same elements as the
+    /// vector returned by the `Polynomial.get_coefficients` method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![0.0, 1.0, 0.0, -3.0]);
+    /// assert_eq!(array![1.0, 0.0, -3.0], poly.to_array());
+    /// ```
+    pub fn to_array(&self) -> Array1<f64> {
+        Array1::from_vec(self.get_coefficients())
+    }
+
+    /// Evaluates the polynomial at every element of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -1.0]);
+    /// let xs = array![-2.0, 0.0, 0.5, 3.0];
+    /// assert_eq!(array![3.0, -1.0, -0.75, 8.0], poly.evaluate_array(&xs.view()));
+    /// ```
+    pub fn evaluate_array(&self, xs: &ArrayView1<f64>) -> Array1<f64> {
+        xs.map(|x| self.evaluate(*x))
+    }
+
+    /// Evaluates the polynomial at every element of a two-dimensional array, e.g. of a grid
+    /// of points for a heatmap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 1.0]);
+    /// let grid = Array2::from_shape_fn((2, 3), |(i, j)| (3 * i + j) as f64);
+    /// let values = poly.evaluate_grid(&grid.view());
+    /// assert_eq!(Array2::from_shape_fn((2, 3), |(i, j)| (3 * i + j + 1) as f64), values);
+    /// ```
+    pub fn evaluate_grid(&self, xs: &ArrayView2<f64>) -> Array2<f64> {
+        xs.map(|x| self.evaluate(*x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{array, Array1, Array2};
+    use crate::Polynomial;
+
+    #[test]
+    fn array_round_trip() {
+        let polys = [
+            Polynomial::zero(),
+            Polynomial::from_coefficients(&vec![-2.0]),
+            Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0, 4.5, 0.0]),
+        ];
+        for poly in polys {
+            assert_eq!(poly, Polynomial::from_array(poly.to_array().view()));
+        }
+        assert!(Polynomial::from_array(Array1::zeros(0).view()).is_zero());
+    }
+
+    #[test]
+    fn from_array_skips_leading_zeros() {
+        let poly = Polynomial::from_array(array![0.0, 0.0, 3.0, 1.0].view());
+        assert_eq!(Some(1), poly.degree());
+    }
+
+    #[test]
+    fn evaluate_array_matches_evaluate() {
+        let poly = Polynomial::from_coefficients(&vec![0.5, -1.0, 0.0, 2.0, 0.0]);
+        let xs = Array1::linspace(-3.0, 3.0, 25);
+        let values = poly.evaluate_array(&xs.view());
+        for (x, value) in xs.iter().zip(values.iter()) {
+            assert_eq!(poly.evaluate(*x), *value);
+        }
+    }
+
+    #[test]
+    fn evaluate_grid_matches_polyval() {
+        // numpy.polyval([1, -2, 0, 3], numpy.meshgrid([-1, 0, 1, 2], [0.5, 3])[0])
+        let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 0.0, 3.0]);
+        let xs = array![-1.0, 0.0, 1.0, 2.0];
+        let grid = Array2::from_shape_fn((2, 4), |(_, j)| xs[j]);
+        let expected = array![[0.0, 3.0, 2.0, 3.0], [0.0, 3.0, 2.0, 3.0]];
+        assert_eq!(expected, poly.evaluate_grid(&grid.view()));
+
+        // numpy.polyval([0.5, 0, -1], [[0, 0.5], [-2, 4]])
+        let poly = Polynomial::from_coefficients(&vec![0.5, 0.0, -1.0]);
+        let grid = array![[0.0, 0.5], [-2.0, 4.0]];
+        let expected = array![[-1.0, -0.875], [1.0, 7.0]];
+        assert_eq!(expected, poly.evaluate_grid(&grid.view()));
+    }
+}
@@ -0,0 +1,267 @@
+use super::Polynomial;
+
+impl Polynomial {
+    /// Returns the nonzero scalar `c` such that `P = c * Q`, where `Q` is the other polynomial,
+    /// or `None` if there is no such scalar.
+    ///
+    /// The polynomials must have the same nonzero terms and the ratios of their coefficients
+    /// must be exactly equal. The zero polynomial is `1` times itself, and it is not a multiple
+    /// of a nonzero polynomial, nor the other way around. Use
+    /// [`scalar_ratio_with_tolerance`](Polynomial::scalar_ratio_with_tolerance) for
+    /// coefficients with rounding errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let p = Polynomial::from_coefficients(&vec![2.0, 0.0, -6.0]);
+    /// let q = Polynomial::from_coefficients(&vec![1.0, 0.0, -3.0]);
+    /// assert_eq!(Some(2.0), p.scalar_ratio(&q));
+    /// assert_eq!(Some(0.5), q.scalar_ratio(&p));
+    ///
+    /// let r = Polynomial::from_coefficients(&vec![1.0, 1.0, -3.0]);
+    /// assert_eq!(None, p.scalar_ratio(&r));
+    /// assert_eq!(None, p.scalar_ratio(&Polynomial::zero()));
+    /// ```
+    pub fn scalar_ratio(&self, other: &Polynomial) -> Option<f64> {
+        if self.coefficients.len() != other.coefficients.len() {
+            return None;
+        }
+        let mut ratios = self.coefficients.iter()
+            .zip(other.coefficients.iter())
+            .map(|((power, coefficient), (other_power, other_coefficient))| {
+                (power == other_power).then(|| coefficient / other_coefficient)
+            });
+        let Some(ratio) = ratios.next() else {
+            return Some(1.0);
+        };
+        let ratio = ratio?;
+        ratios.all(|other_ratio| other_ratio == Some(ratio)).then_some(ratio)
+    }
+
+    /// Returns the nonzero scalar `c` for which `P` is closest to `c * Q`, where `Q` is the
+    /// other polynomial, if the largest difference of their coefficients is at most the
+    /// tolerance times the largest coefficient of `P`, or `None` otherwise.
+    ///
+    /// The scalar is fitted with the least squares on the coefficients, so the terms small
+    /// enough to be rounding errors may be present in only one of the polynomials. The zero
+    /// polynomials are treated as in [`scalar_ratio`](Polynomial::scalar_ratio).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let p = Polynomial::from_coefficients(&vec![0.1 + 0.2, 1e-17, 0.6]);
+    /// let q = Polynomial::from_coefficients(&vec![0.1, 0.0, 0.2]);
+    /// assert_eq!(None, p.scalar_ratio(&q));
+    ///
+    /// let ratio = p.scalar_ratio_with_tolerance(&q, 1e-12).unwrap();
+    /// assert!((ratio - 3.0).abs() < 1e-12);
+    /// ```
+    pub fn scalar_ratio_with_tolerance(&self, other: &Polynomial, tolerance: f64) -> Option<f64> {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => return Some(1.0),
+            (false, false) => {}
+            _ => return None,
+        }
+        let dot = |p: &Polynomial, q: &Polynomial| -> f64 {
+            p.coefficients.iter().map(|(power, coefficient)| coefficient * q.get_coefficient_at(*power)).sum()
+        };
+        let ratio = dot(self, other) / dot(other, other);
+        let max_difference = self.coefficients.keys()
+            .chain(other.coefficients.keys())
+            .map(|power| (self.get_coefficient_at(*power) - ratio * other.get_coefficient_at(*power)).abs())
+            .fold(0.0, f64::max);
+        let max_coefficient = self.coefficients.values().fold(0.0, |max: f64, coefficient| max.max(coefficient.abs()));
+        (ratio != 0.0 && max_difference <= tolerance * max_coefficient).then_some(ratio)
+    }
+
+    /// Checks if the polynomial is a nonzero scalar multiple of the other polynomial, i.e. if
+    /// [`scalar_ratio`](Polynomial::scalar_ratio) returns a scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let p = Polynomial::from_coefficients(&vec![1.0, -2.0]);
+    /// assert!((-p.clone()).is_scalar_multiple_of(&p));
+    /// assert!(!p.is_scalar_multiple_of(&Polynomial::from_coefficients(&vec![1.0, 2.0])));
+    /// ```
+    pub fn is_scalar_multiple_of(&self, other: &Polynomial) -> bool {
+        self.scalar_ratio(other).is_some()
+    }
+
+    /// Returns whether the leading coefficient is exactly one. The zero polynomial is not
+    /// monic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// assert!(Polynomial::from_coefficients(&vec![1.0, -3.0, 2.0]).is_monic());
+    /// assert!(!Polynomial::from_coefficients(&vec![2.0, -3.0]).is_monic());
+    /// assert!(!Polynomial::zero().is_monic());
+    /// ```
+    pub fn is_monic(&self) -> bool {
+        self.coefficients.last_key_value().is_some_and(|(_, coefficient)| *coefficient == 1.0)
+    }
+
+    /// Divides every coefficient by the leading one in place, so that the polynomial becomes
+    /// monic, and returns it for chaining. The zero polynomial is left unchanged.
+    ///
+    /// The leading coefficient becomes exactly one, and the other ones are rounded quotients.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let mut poly = Polynomial::from_coefficients(&vec![-2.0, 1.0, 4.0]);
+    /// poly.make_monic();
+    /// assert_eq!(vec![1.0, -0.5, -2.0], poly.get_coefficients());
+    /// ```
+    pub fn make_monic(&mut self) -> &mut Self {
+        if let Some((_, leading_coefficient)) = self.coefficients.last_key_value() {
+            *self /= *leading_coefficient;
+        }
+        self
+    }
+
+    /// Returns the monic polynomial with the leading coefficient divided out, or the zero
+    /// polynomial for the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![4.0, 0.0, 2.0]).into_monic();
+    /// assert_eq!(vec![1.0, 0.0, 0.5], poly.get_coefficients());
+    /// assert!(poly.is_monic());
+    /// ```
+    pub fn into_monic(mut self) -> Self {
+        self.make_monic();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use crate::Polynomial;
+    use crate::polynomial::modular::extended_gcd;
+    use crate::polynomial::strategy::polynomials_with;
+
+    #[test]
+    fn negation_is_a_scalar_multiple() {
+        let p = Polynomial::from_coefficients(&vec![3.0, 0.0, -1.0, 0.5]);
+        assert_eq!(Some(-1.0), (-p.clone()).scalar_ratio(&p));
+        assert_eq!(Some(-1.0), p.scalar_ratio(&-p.clone()));
+        assert_eq!(Some(1.0), p.scalar_ratio(&p));
+    }
+
+    #[test]
+    fn different_terms_are_not_scalar_multiples() {
+        let p = Polynomial::from_coefficients(&vec![1.0, 2.0, 0.0]);
+        let q = Polynomial::from_coefficients(&vec![1.0, 0.0, 2.0]);
+        assert_eq!(None, p.scalar_ratio(&q));
+        assert_eq!(None, p.scalar_ratio(&Polynomial::from_coefficients(&vec![1.0, 2.0])));
+        assert_eq!(None, p.scalar_ratio(&Polynomial::from_coefficients(&vec![1.0, 2.0, 1.0])));
+        assert_eq!(None, p.scalar_ratio_with_tolerance(&q, 1e-9));
+    }
+
+    #[test]
+    fn zero_polynomial_conventions() {
+        let zero = Polynomial::zero();
+        let p = Polynomial::from_coefficients(&vec![1.0, 2.0]);
+        assert_eq!(Some(1.0), zero.scalar_ratio(&zero));
+        assert_eq!(None, zero.scalar_ratio(&p));
+        assert_eq!(None, p.scalar_ratio(&zero));
+        assert_eq!(Some(1.0), zero.scalar_ratio_with_tolerance(&zero, 1e-9));
+        assert_eq!(None, zero.scalar_ratio_with_tolerance(&p, 1e-9));
+        assert_eq!(None, p.scalar_ratio_with_tolerance(&zero, 1e-9));
+        assert!(zero.is_scalar_multiple_of(&zero));
+        assert!(!p.is_scalar_multiple_of(&zero));
+    }
+
+    #[test]
+    fn nan_coefficients_are_not_scalar_multiples() {
+        let mut p = Polynomial::from_coefficients(&vec![1.0, 2.0]);
+        p.set_coefficient_at(1, f64::NAN);
+        assert!(!p.is_scalar_multiple_of(&p));
+    }
+
+    #[test]
+    fn monic_gcd_is_associate_of_common_factor() {
+        let common = Polynomial::from_coefficients(&vec![3.0, -1.0, 2.0]);
+        let a = common.clone() * &Polynomial::from_coefficients(&vec![2.0, 5.0]);
+        let b = common.clone() * &Polynomial::from_coefficients(&vec![-1.0, 0.0, 4.0]);
+        let (gcd, _, _) = extended_gcd(&a, &b);
+        let ratio = common.scalar_ratio_with_tolerance(&gcd, 1e-9).unwrap();
+        assert!((ratio - 3.0).abs() < 1e-9);
+        assert!(gcd.scalar_ratio_with_tolerance(&common, 1e-9).is_some());
+    }
+
+    #[test]
+    fn near_multiple_under_noise() {
+        let p = Polynomial::from_coefficients(&vec![1.0, -0.5, 0.25]);
+        let mut noisy = p.clone() * 7.0;
+        noisy.add_coefficient_at(2, 1e-13);
+        noisy.add_coefficient_at(4, 1e-14);
+        assert_eq!(None, noisy.scalar_ratio(&p));
+        let ratio = noisy.scalar_ratio_with_tolerance(&p, 1e-12).unwrap();
+        assert!((ratio - 7.0).abs() < 1e-12);
+        assert_eq!(None, noisy.scalar_ratio_with_tolerance(&p, 1e-16));
+    }
+
+    proptest! {
+        #[test]
+        fn integer_multiples_are_exact(
+            p in polynomials_with((-5..=5).prop_map(f64::from), 8),
+            c in (-8..=8).prop_filter("nonzero", |c| *c != 0).prop_map(f64::from),
+        ) {
+            let expected = if p.is_zero() { 1.0 } else { c };
+            prop_assert_eq!(Some(expected), (p.clone() * c).scalar_ratio(&p));
+            prop_assert_eq!(Some(expected), (p.clone() * c).scalar_ratio_with_tolerance(&p, 1e-12));
+        }
+    }
+
+    #[test]
+    fn make_monic_works() {
+        let mut poly = Polynomial::from_coefficients(&vec![3.0, 1.0, -6.0]);
+        poly.make_monic().mul_scalar_in_place(2.0);
+        assert_eq!(vec![2.0, 2.0 / 3.0, -4.0], poly.get_coefficients());
+        assert!(!poly.is_monic());
+
+        let mut zero = Polynomial::zero();
+        zero.make_monic();
+        assert_eq!(Polynomial::zero(), zero);
+        assert_eq!(Polynomial::zero(), Polynomial::zero().into_monic());
+    }
+
+    #[test]
+    fn monic_with_non_integer_quotients() {
+        // Division by 3 doesn't truncate, the quotients are rounded
+        let poly = Polynomial::from_coefficients(&vec![3.0, 1.0, 2.0]).into_monic();
+        assert_eq!(vec![1.0, 1.0 / 3.0, 2.0 / 3.0], poly.get_coefficients());
+        // Tiny coefficients may underflow and are dropped
+        let mut poly = Polynomial::from_coefficients(&vec![1e300, 0.0, 1e-300]);
+        poly.make_monic();
+        assert_eq!(vec![1.0, 0.0, 0.0], poly.get_coefficients());
+    }
+
+    proptest! {
+        #[test]
+        fn into_monic_is_monic_associate(p in polynomials_with((-9..=9).prop_map(f64::from), 8)) {
+            prop_assume!(!p.is_zero());
+            let monic = p.clone().into_monic();
+            prop_assert!(monic.is_monic());
+            prop_assert!(p.scalar_ratio_with_tolerance(&monic, 1e-12).is_some());
+            prop_assert_eq!(monic.clone(), monic.into_monic());
+        }
+    }
+}
@@ -1,9 +1,150 @@
 //! Module containing methods for manipulating the coefficients of a polynomial.
+//!
+//! Zero coefficients are never stored, and the negative zero is treated as zero, so the
+//! polynomials which differ only in the signs of their zero terms are equal.
+//!
+//! The infallible setters store the non-finite coefficients, and the arithmetic propagates
+//! them as the `f64` operations do, e.g. the difference of two infinite coefficients is `NaN`.
+//! The exception is a product with zero, which is the zero polynomial.
+//! A `NaN` coefficient counts as a term, so it affects the degree. In comparisons of
+//! polynomials all `NaN` coefficients are equal to each other, so a polynomial with a `NaN`
+//! coefficient is equal to itself. Use [`Polynomial::try_set_coefficient_at`] to reject them.
+use std::collections::btree_map;
+use std::error::Error;
+use std::fmt;
+use std::iter;
 use super::Polynomial;
 
+/// Error returned by [`Polynomial::try_set_coefficient_at`] when the coefficient is infinite
+/// or `NaN`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct NonFiniteCoefficientError {
+    /// The power of the term whose coefficient was rejected.
+    pub power: u32,
+
+    /// The rejected coefficient.
+    pub coefficient: f64,
+}
+
+impl fmt::Display for NonFiniteCoefficientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Coefficient {} at power {} is not finite.", self.coefficient, self.power)
+    }
+}
+
+impl Error for NonFiniteCoefficientError {}
+
+/// Error returned when the output buffer passed to a method like
+/// [`Polynomial::coefficients_into`] is too small for the result.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct SizeMismatch {
+    /// The length the buffer needs at least.
+    pub required: usize,
+
+    /// The length of the given buffer.
+    pub actual: usize,
+}
+
+impl fmt::Display for SizeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Buffer of length {} is shorter than the required {}.", self.actual, self.required)
+    }
+}
+
+impl Error for SizeMismatch {}
+
+/// A guard giving mutable access to the stored coefficients of a polynomial, returned by
+/// [`Polynomial::coefficients_mut`].
+///
+/// The coefficients set to zero stay in the map while the guard is alive, and they are removed
+/// when it is dropped, also when a panic unwinds through it, so the polynomial never exposes a
+/// stored zero.
+pub struct CoefficientsMut<'a> {
+    poly: &'a mut Polynomial,
+}
+
+impl CoefficientsMut<'_> {
+    /// Returns an iterator over the powers of the nonzero terms and the mutable references to
+    /// their coefficients, in the ascending order of powers.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (u32, &mut f64)> {
+        self.into_iter()
+    }
+
+    /// Returns the mutable reference to the coefficient of the given power, or `None` if the
+    /// term is zero.
+    pub fn get_mut(&mut self, power: u32) -> Option<&mut f64> {
+        self.poly.coefficients.get_mut(&power)
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b mut CoefficientsMut<'a> {
+    type Item = (u32, &'b mut f64);
+    type IntoIter = iter::Map<btree_map::IterMut<'b, u32, f64>, fn((&'b u32, &'b mut f64)) -> (u32, &'b mut f64)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.poly.coefficients.iter_mut().map(|(power, coefficient)| (*power, coefficient))
+    }
+}
+
+impl Drop for CoefficientsMut<'_> {
+    fn drop(&mut self) {
+        self.poly.remove_zero_coefficients();
+    }
+}
+
 impl Polynomial {
+    /// Returns a [guard](CoefficientsMut) giving mutable access to the nonzero coefficients,
+    /// which removes the ones set to zero when it is dropped.
+    ///
+    /// Only the existing terms can be changed, use
+    /// [`set_coefficient_at`](Polynomial::set_coefficient_at) to add new ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let mut poly = Polynomial::from_coefficients(&vec![1.0, 2.0, 3.0, 4.0]);
+    /// let mut coefficients = poly.coefficients_mut();
+    /// for (power, coefficient) in coefficients.iter_mut() {
+    ///     if power < 2 {
+    ///         *coefficient = 0.0;
+    ///     }
+    /// }
+    /// drop(coefficients);
+    /// assert_eq!(vec![1.0, 2.0, 0.0, 0.0], poly.get_coefficients());
+    /// ```
+    pub fn coefficients_mut(&mut self) -> CoefficientsMut<'_> {
+        CoefficientsMut { poly: self }
+    }
+
+    /// Calls the closure with the power and the mutable reference to the coefficient of every
+    /// nonzero term in the ascending order of powers, and removes the terms set to zero
+    /// afterwards, as [`coefficients_mut`](Polynomial::coefficients_mut) does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let mut poly = Polynomial::from_coefficients(&vec![1.0, 2.0, 3.0]);
+    /// poly.for_each_coefficient_mut(|_, coefficient| *coefficient -= 1.0);
+    /// assert_eq!(vec![1.0, 2.0], poly.get_coefficients());
+    /// assert_eq!(Some(1), poly.degree());
+    /// ```
+    pub fn for_each_coefficient_mut(&mut self, mut f: impl FnMut(u32, &mut f64)) {
+        let mut coefficients = self.coefficients_mut();
+        for (power, coefficient) in coefficients.iter_mut() {
+            f(power, coefficient);
+        }
+    }
+
     /// Sets the coefficient in the term with the indeterminate raised to the given power.
     ///
+    /// A zero of either sign removes the term. An infinite or `NaN` coefficient is stored and
+    /// propagated by the arithmetic, use
+    /// [`try_set_coefficient_at`](Polynomial::try_set_coefficient_at) to reject it.
+    ///
     /// # Examples
     ///
     /// ```
@@ -22,6 +163,31 @@ impl Polynomial {
         self.coefficients.insert(power, coefficient);
     }
 
+    /// Sets the coefficient in the term with the indeterminate raised to the given power, or
+    /// returns an error and leaves the polynomial unchanged if the coefficient is infinite or
+    /// `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{NonFiniteCoefficientError, Polynomial};
+    ///
+    /// let mut poly = Polynomial::zero();
+    /// assert_eq!(Ok(()), poly.try_set_coefficient_at(1, 2.0));
+    /// assert_eq!(
+    ///     Err(NonFiniteCoefficientError { power: 0, coefficient: f64::INFINITY }),
+    ///     poly.try_set_coefficient_at(0, f64::INFINITY)
+    /// );
+    /// assert_eq!(vec![2.0, 0.0], poly.get_coefficients());
+    /// ```
+    pub fn try_set_coefficient_at(&mut self, power: u32, coefficient: f64) -> Result<(), NonFiniteCoefficientError> {
+        if !coefficient.is_finite() {
+            return Err(NonFiniteCoefficientError { power, coefficient });
+        }
+        self.set_coefficient_at(power, coefficient);
+        Ok(())
+    }
+
     /// Returns the coefficient from the term with the indeterminate raised to the given power.
     ///
     /// # Examples
@@ -122,6 +288,7 @@ impl Polynomial {
     /// let poly = Polynomial::from_coefficients(&coefficients);
     /// assert_eq!(coefficients, poly.get_coefficients());
     /// ```
+    #[allow(clippy::ptr_arg)]
     pub fn from_coefficients(coefficients: &Vec<f64>) -> Polynomial {
         let mut poly = Polynomial::zero();
         for (power, coefficient) in (0..coefficients.len()).rev().zip(coefficients.iter()) {
@@ -130,6 +297,60 @@ impl Polynomial {
         poly
     }
 
+    /// Creates a new instance with the coefficient of every power from `0` to `degree`
+    /// given by the function, which is called in ascending order of powers.
+    ///
+    /// The zero coefficients are not stored, so the degree of the result is less than
+    /// `degree` if the function returns zero for it.
+    ///
+    /// # Examples
+    ///
+    /// Build the Taylor polynomial of `e^x` with the coefficients `1/k!`:
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let mut factorial = 1.0;
+    /// let poly = Polynomial::from_fn(3, |k| {
+    ///     factorial *= k.max(1) as f64;
+    ///     1.0 / factorial
+    /// });
+    /// assert_eq!(vec![1.0 / 6.0, 0.5, 1.0, 1.0], poly.get_coefficients());
+    /// ```
+    pub fn from_fn<F>(degree: u32, f: F) -> Polynomial
+    where
+        F: FnMut(u32) -> f64
+    {
+        Polynomial::from_fn_over(0..=degree, f)
+    }
+
+    /// Creates a new instance with the coefficients of the given powers returned by the
+    /// function, and all the other coefficients zero.
+    ///
+    /// The function is called for the powers in the order of the iterator, and a coefficient
+    /// returned for a repeated power replaces the previous one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_fn_over([0, 10, 100], |power| power as f64 + 1.0);
+    /// assert_eq!(Some(100), poly.degree());
+    /// assert_eq!(11.0, poly.get_coefficient_at(10));
+    /// assert_eq!(0.0, poly.get_coefficient_at(50));
+    /// ```
+    pub fn from_fn_over<I, F>(powers: I, mut f: F) -> Polynomial
+    where
+        I: IntoIterator<Item = u32>,
+        F: FnMut(u32) -> f64
+    {
+        let mut poly = Polynomial::zero();
+        for power in powers {
+            poly.set_coefficient_at(power, f(power));
+        }
+        poly
+    }
+
     /// Returns a vector of coefficients.
     ///
     /// The vector starts with the first non-zero coefficient (from the highest
@@ -153,11 +374,7 @@ impl Polynomial {
             // Add skipped zero coefficients
             if let Some(last_x_power) = last_power {
                 let skipped_powers_count = last_x_power - *power - 1;
-                if skipped_powers_count > 0 {
-                    for _ in 0..skipped_powers_count {
-                        result.push(0.0);
-                    }
-                }
+                result.resize(result.len() + skipped_powers_count as usize, 0.0);
             }
             result.push(*coefficient);
             last_power = Some(*power);
@@ -165,20 +382,85 @@ impl Polynomial {
 
         // Prevent the trailing zero coefficients from being skipped
         if let Some(last_x_power) = last_power {
-            if last_x_power > 0 {
-                for _ in 0..last_x_power {
-                    result.push(0.0);
-                }
-            }
+            result.resize(result.len() + last_x_power as usize, 0.0);
         }
 
         result
     }
+
+    /// Removes the terms with the powers greater than or equal to `n` in place, i.e. reduces
+    /// the polynomial modulo `x^n`, and returns it for chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let mut poly = Polynomial::from_coefficients(&vec![4.0, 3.0, 2.0, 1.0]);
+    /// poly.truncate_in_place(2);
+    /// assert_eq!(vec![2.0, 1.0], poly.get_coefficients());
+    /// ```
+    pub fn truncate_in_place(&mut self, n: u32) -> &mut Self {
+        self.coefficients.split_off(&n);
+        self
+    }
+
+    /// Removes the terms whose coefficients are at most the tolerance in absolute value in
+    /// place, e.g. the rounding errors of cancelled terms, and returns the polynomial for
+    /// chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let mut poly = Polynomial::from_coefficients(&vec![1e-17, 1.0, -2e-16, 3.0]);
+    /// poly.trim_in_place(1e-12);
+    /// assert_eq!(vec![1.0, 0.0, 3.0], poly.get_coefficients());
+    /// ```
+    pub fn trim_in_place(&mut self, tolerance: f64) -> &mut Self {
+        self.coefficients.retain(|_, coefficient| coefficient.abs() > tolerance);
+        self
+    }
+
+    /// Writes the coefficients in the same order as [`get_coefficients`](Polynomial::get_coefficients)
+    /// into the beginning of the buffer without allocating memory, and returns the number of the
+    /// written coefficients. The rest of the buffer is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SizeMismatch`] and leaves the buffer unchanged if it is shorter than the
+    /// degree plus one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{Polynomial, SizeMismatch};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![2.0, 0.0, -1.0]);
+    /// let mut buffer = [9.0; 4];
+    /// assert_eq!(Ok(3), poly.coefficients_into(&mut buffer));
+    /// assert_eq!([2.0, 0.0, -1.0, 9.0], buffer);
+    /// assert_eq!(Err(SizeMismatch { required: 3, actual: 2 }), poly.coefficients_into(&mut buffer[..2]));
+    /// ```
+    pub fn coefficients_into(&self, buffer: &mut [f64]) -> Result<usize, SizeMismatch> {
+        let Some(degree) = self.degree() else {
+            return Ok(0);
+        };
+        let required = degree as usize + 1;
+        let actual = buffer.len();
+        let output = buffer.get_mut(..required).ok_or(SizeMismatch { required, actual })?;
+        output.fill(0.0);
+        for (power, coefficient) in self.coefficients.iter() {
+            output[degree as usize - *power as usize] = *coefficient;
+        }
+        Ok(required)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Polynomial;
+    use super::{Polynomial, SizeMismatch};
 
     #[test]
     fn from_coefficients_works_correctly() {
@@ -205,4 +487,187 @@ mod tests {
         let poly = Polynomial::from_coefficients(&coefficients);
         assert_eq!(coefficients, poly.get_coefficients());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn from_fn_builds_exp_series() {
+        let mut factorial = 1.0;
+        let poly = Polynomial::from_fn(5, |k| {
+            factorial *= k.max(1) as f64;
+            1.0 / factorial
+        });
+        let expected = [1.0 / 120.0, 1.0 / 24.0, 1.0 / 6.0, 1.0 / 2.0, 1.0, 1.0];
+        assert_eq!(expected.to_vec(), poly.get_coefficients());
+    }
+
+    #[test]
+    fn from_fn_stores_only_non_zero_coefficients() {
+        let poly = Polynomial::from_fn(6, |k| if k % 2 == 0 { k as f64 + 1.0 } else { 0.0 });
+        assert_eq!(4, poly.coefficients.len());
+        assert_eq!(vec![7.0, 0.0, 5.0, 0.0, 3.0, 0.0, 1.0], poly.get_coefficients());
+
+        let poly = Polynomial::from_fn(3, |k| if k == 3 { 0.0 } else { 1.0 });
+        assert_eq!(Some(2), poly.degree());
+    }
+
+    #[test]
+    fn from_fn_of_degree_zero() {
+        assert_eq!(vec![4.0], Polynomial::from_fn(0, |_| 4.0).get_coefficients());
+        assert!(Polynomial::from_fn(0, |_| 0.0).is_zero());
+    }
+
+    #[test]
+    fn from_fn_over_builds_sparse_polynomials() {
+        let poly = Polynomial::from_fn_over((0..=1000).step_by(500), |power| -(power as f64));
+        assert_eq!(2, poly.coefficients.len());
+        assert_eq!(-1000.0, poly.get_coefficient_at(1000));
+        assert_eq!(-500.0, poly.get_coefficient_at(500));
+        assert!(Polynomial::from_fn_over([], |_| 1.0).is_zero());
+    }
+
+    #[test]
+    fn negative_zero_is_not_stored() {
+        let mut poly = Polynomial::from_coefficients(&vec![1.0, 2.0]);
+        poly.set_coefficient_at(1, -0.0);
+        poly.set_coefficient_at(3, -0.0);
+        assert_eq!(Polynomial::from_coefficients(&vec![2.0]), poly);
+
+        poly.add_coefficient_at(0, -2.0);
+        assert!(poly.is_zero());
+        assert_eq!(None, poly.degree());
+
+        let poly = Polynomial::from_coefficients(&vec![-0.0, -0.0]);
+        assert_eq!(Polynomial::zero(), poly);
+        assert!(poly.is_zero());
+    }
+
+    #[test]
+    fn negative_zero_from_arithmetic_is_not_stored() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, -1.0]);
+        assert_eq!(Polynomial::zero(), poly.clone() * -0.0);
+        assert_eq!(Polynomial::zero(), poly.clone() * &Polynomial::from_coefficients(&vec![-0.0]));
+        assert_eq!(Polynomial::zero(), -poly.clone() + &poly);
+
+        let mut poly = poly;
+        poly.mul_coefficient_at(1, -0.0);
+        assert_eq!(Polynomial::from_coefficients(&vec![-1.0]), poly);
+    }
+
+    #[test]
+    fn non_finite_coefficients_are_stored() {
+        let mut poly = Polynomial::zero();
+        poly.set_coefficient_at(3, f64::NAN);
+        poly.set_coefficient_at(1, f64::INFINITY);
+        poly.set_coefficient_at(0, f64::NEG_INFINITY);
+        assert_eq!(Some(3), poly.degree());
+        assert!(!poly.is_zero());
+        assert!(poly.get_coefficient_at(3).is_nan());
+        assert_eq!("NaN x^3 + inf x - inf", poly.to_string());
+
+        // A polynomial with a NaN coefficient is still equal to itself
+        assert_eq!(poly, poly.clone());
+        let mut other = poly.clone();
+        other.set_coefficient_at(3, 1.0);
+        assert_ne!(poly, other);
+    }
+
+    #[test]
+    fn non_finite_coefficients_propagate() {
+        let mut poly = Polynomial::zero();
+        poly.set_coefficient_at(1, f64::INFINITY);
+        poly.add_coefficient_at(1, f64::NEG_INFINITY);
+        assert!(poly.get_coefficient_at(1).is_nan());
+
+        let infinite = Polynomial::from_coefficients(&vec![f64::INFINITY, 1.0]);
+        let sum = infinite.clone() + &-infinite.clone();
+        assert!(sum.get_coefficient_at(1).is_nan());
+        assert_eq!(0.0, sum.get_coefficient_at(0));
+
+        let product = infinite.clone() * &Polynomial::from_coefficients(&vec![1.0, 0.0]);
+        assert_eq!(f64::INFINITY, product.get_coefficient_at(2));
+        // A product with zero is zero, as the missing terms of the zero polynomial are
+        assert_eq!(Polynomial::zero(), infinite.clone() * 0.0);
+        assert_eq!(Polynomial::zero(), infinite * &Polynomial::zero());
+    }
+
+    #[test]
+    fn try_set_coefficient_at_rejects_non_finite_values() {
+        let mut poly = Polynomial::from_coefficients(&vec![1.0, 2.0]);
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let error = poly.try_set_coefficient_at(1, value).unwrap_err();
+            assert_eq!(1, error.power);
+            assert_eq!(value.to_bits(), error.coefficient.to_bits());
+        }
+        assert_eq!(Polynomial::from_coefficients(&vec![1.0, 2.0]), poly);
+
+        assert_eq!(Ok(()), poly.try_set_coefficient_at(1, -0.0));
+        assert_eq!(Polynomial::from_coefficients(&vec![2.0]), poly);
+        assert_eq!(
+            "Coefficient NaN at power 4 is not finite.",
+            poly.try_set_coefficient_at(4, f64::NAN).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn coefficients_into_buffers_of_every_size() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0, -3.0, 0.0]);
+        let mut exact = [f64::NAN; 5];
+        assert_eq!(Ok(5), poly.coefficients_into(&mut exact));
+        assert_eq!(poly.get_coefficients(), exact);
+
+        let mut oversized = [7.0; 8];
+        assert_eq!(Ok(5), poly.coefficients_into(&mut oversized));
+        assert_eq!([1.0, 0.0, 0.0, -3.0, 0.0, 7.0, 7.0, 7.0], oversized);
+
+        let mut undersized = [7.0; 4];
+        let error = poly.coefficients_into(&mut undersized).unwrap_err();
+        assert_eq!(SizeMismatch { required: 5, actual: 4 }, error);
+        assert_eq!("Buffer of length 4 is shorter than the required 5.", error.to_string());
+        assert_eq!([7.0; 4], undersized);
+    }
+
+    #[test]
+    fn coefficients_into_for_zero_polynomial() {
+        assert_eq!(Ok(0), Polynomial::zero().coefficients_into(&mut []));
+        let mut buffer = [7.0];
+        assert_eq!(Ok(1), Polynomial::one().coefficients_into(&mut buffer));
+        assert_eq!([1.0], buffer);
+    }
+
+    #[test]
+    fn zeros_set_through_the_guard_are_removed() {
+        let mut poly = Polynomial::from_coefficients(&vec![2.0, -1.0, 0.0, 5.0]);
+        {
+            let mut coefficients = poly.coefficients_mut();
+            *coefficients.get_mut(3).unwrap() = -0.0;
+            assert_eq!(None, coefficients.get_mut(1));
+            for (_, coefficient) in &mut coefficients {
+                *coefficient *= 2.0;
+            }
+        }
+        assert_eq!(Some(2), poly.degree());
+        assert_eq!(Polynomial::from_coefficients(&vec![-2.0, 0.0, 10.0]), poly);
+
+        poly.for_each_coefficient_mut(|_, coefficient| *coefficient = 0.0);
+        assert!(poly.is_zero());
+        assert_eq!(None, poly.degree());
+        assert_eq!(Polynomial::zero(), poly);
+    }
+
+    #[test]
+    fn guard_cleans_up_after_a_panic() {
+        let mut poly = Polynomial::from_coefficients(&vec![1.0, 2.0, 3.0]);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            poly.for_each_coefficient_mut(|power, coefficient| {
+                *coefficient = 0.0;
+                if power == 1 {
+                    panic!("interrupted");
+                }
+            });
+        }));
+        assert!(result.is_err());
+        // The terms of powers 0 and 1 were zeroed before the panic
+        assert_eq!(Some(2), poly.degree());
+        assert_eq!(1, poly.coefficients.len());
+        assert_eq!(Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0]), poly);
+    }
+}
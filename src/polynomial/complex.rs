@@ -0,0 +1,184 @@
+//! The complex arithmetic used internally by the transforms and the root finders, which
+//! expose the complex numbers as `(re, im)` pairs.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use super::Polynomial;
+
+#[derive(PartialEq, Debug, Clone, Copy, Default)]
+pub(super) struct Complex {
+    pub(super) re: f64,
+    pub(super) im: f64,
+}
+
+impl Complex {
+    pub(super) fn new(re: f64, im: f64) -> Complex {
+        Complex { re, im }
+    }
+
+    pub(super) fn real(re: f64) -> Complex {
+        Complex::new(re, 0.0)
+    }
+
+    /// Returns `e^(i angle)`.
+    pub(super) fn from_angle(angle: f64) -> Complex {
+        Complex::new(angle.cos(), angle.sin())
+    }
+
+    pub(super) fn conj(self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+
+    pub(super) fn scale(self, factor: f64) -> Complex {
+        Complex::new(self.re * factor, self.im * factor)
+    }
+
+    pub(super) fn abs(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// Returns the principal square root, with a nonnegative real part.
+    pub(super) fn sqrt(self) -> Complex {
+        let abs = self.abs();
+        let re = ((abs + self.re) / 2.0).sqrt();
+        let im = ((abs - self.re) / 2.0).sqrt();
+        Complex::new(re, if self.im < 0.0 { -im } else { im })
+    }
+
+    /// Returns the power with binary exponentiation.
+    fn powu(self, mut exponent: u32) -> Complex {
+        let mut result = Complex::real(1.0);
+        let mut base = self;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            exponent >>= 1;
+            base = base * base;
+        }
+        result
+    }
+
+    pub(super) fn to_pair(self) -> (f64, f64) {
+        (self.re, self.im)
+    }
+}
+
+/// Evaluates the polynomial at the complex point with Horner's method, skipping the missing
+/// powers like [`Polynomial::evaluate`].
+pub(super) fn evaluate(poly: &Polynomial, x: Complex) -> Complex {
+    let mut result = Complex::default();
+    let mut last_power: Option<u32> = None;
+    for (power, coefficient) in poly.coefficients.iter().rev() {
+        if let Some(last_power) = last_power {
+            result = result * x.powu(last_power - *power);
+        }
+        result = result + Complex::real(*coefficient);
+        last_power = Some(*power);
+    }
+    if let Some(last_power) = last_power {
+        result = result * x.powu(last_power);
+    }
+    result
+}
+
+impl Polynomial {
+    /// Evaluates the polynomial at the complex point given as an `(re, im)` pair, and returns
+    /// the value as an `(re, im)` pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // x^2 + 1 at i and 1 + i
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]);
+    /// assert_eq!((0.0, 0.0), poly.evaluate_complex((0.0, 1.0)));
+    /// assert_eq!((1.0, 2.0), poly.evaluate_complex((1.0, 1.0)));
+    /// ```
+    pub fn evaluate_complex(&self, (re, im): (f64, f64)) -> (f64, f64) {
+        evaluate(self, Complex::new(re, im)).to_pair()
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        Complex::new(self.re * other.re - self.im * other.im, self.re * other.im + self.im * other.re)
+    }
+}
+
+/// Smith's algorithm, which avoids the overflow of the squared norm of the divisor.
+impl Div for Complex {
+    type Output = Complex;
+
+    fn div(self, other: Complex) -> Complex {
+        if other.re.abs() >= other.im.abs() {
+            let ratio = other.im / other.re;
+            let denominator = other.re + other.im * ratio;
+            Complex::new((self.re + self.im * ratio) / denominator, (self.im - self.re * ratio) / denominator)
+        } else {
+            let ratio = other.re / other.im;
+            let denominator = other.re * ratio + other.im;
+            Complex::new((self.re * ratio + self.im) / denominator, (self.im * ratio - self.re) / denominator)
+        }
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Complex;
+    use crate::Polynomial;
+
+    #[test]
+    fn division_inverts_multiplication() {
+        let a = Complex::new(3.0, -4.0);
+        for b in [Complex::new(1.0, 2.0), Complex::new(-0.5, 0.25), Complex::new(1e200, -3e200)] {
+            let quotient = (a * b) / b;
+            assert!((quotient - a).abs() < 1e-12, "{quotient:?}");
+        }
+        assert_eq!(Complex::new(1.0, 0.0), Complex::new(1e300, 1e300) / Complex::new(1e300, 1e300));
+    }
+
+    #[test]
+    fn principal_square_root() {
+        assert_eq!(Complex::new(0.0, 2.0), Complex::real(-4.0).sqrt());
+        assert_eq!(Complex::new(1.0, -1.0), Complex::new(0.0, -2.0).sqrt());
+        assert_eq!(Complex::new(3.0, 4.0), Complex::new(-7.0, 24.0).sqrt());
+        assert_eq!(Complex::default(), Complex::default().sqrt());
+    }
+
+    #[test]
+    fn evaluate_sparse_polynomial() {
+        let mut poly = Polynomial::from_coefficients(&vec![2.0, 0.0, 0.0]);
+        poly.set_coefficient_at(9, 1.0);
+        // i^9 + 2i^2 = i - 2
+        assert_eq!((-2.0, 1.0), poly.evaluate_complex((0.0, 1.0)));
+        assert_eq!((poly.evaluate(-1.5), 0.0), poly.evaluate_complex((-1.5, 0.0)));
+        assert_eq!((0.0, 0.0), Polynomial::zero().evaluate_complex((3.0, 4.0)));
+    }
+}
@@ -0,0 +1,248 @@
+use super::Polynomial;
+use super::arithmetic::binary_pow;
+
+/// Returns `base` raised to the power `exponent` modulo `modulus`, using binary
+/// exponentiation with a reduction after every multiplication.
+fn pow_mod(base: &Polynomial, exponent: u32, modulus: &Polynomial) -> Polynomial {
+    let one = Polynomial::one() % modulus;
+    binary_pow(base.clone(), exponent, one, |a, b| Some((a * b) % modulus))
+        .expect("Multiplication cannot fail")
+}
+
+impl Polynomial {
+    /// Returns the composition `P(Q(x))` of this polynomial `P` with the polynomial `Q`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let p = Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]);
+    /// let q = Polynomial::from_coefficients(&vec![1.0, -1.0]);
+    /// assert_eq!("x^2 - 2x + 2", p.compose(&q).to_string());
+    /// ```
+    pub fn compose(&self, q: &Polynomial) -> Polynomial {
+        let mut result = Polynomial::zero();
+        let mut last_power: Option<u32> = None;
+
+        // Horner's method, with the powers of q multiplied in for the missing terms
+        for (power, coefficient) in self.coefficients.iter().rev() {
+            if let Some(last_power) = last_power {
+                for _ in *power..last_power {
+                    result *= q;
+                }
+            }
+            result += *coefficient;
+            last_power = Some(*power);
+        }
+
+        if let Some(last_power) = last_power {
+            for _ in 0..last_power {
+                result *= q;
+            }
+        }
+        result
+    }
+
+    /// Returns the first `k + 1` coefficients of the Taylor expansion of the polynomial about
+    /// the point `a`, i.e. `P(a)`, `P'(a)`, `P''(a) / 2!`, ..., `P^(k)(a) / k!`, so that the
+    /// `i`-th of them is the coefficient of `(x - a)^i`.
+    ///
+    /// The coefficients are the remainders of the repeated synthetic division by `x - a`,
+    /// computed in place in a single vector. The coefficients above the degree are zeros.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // x^3 = 8 + 12(x - 2) + 6(x - 2)^2 + (x - 2)^3
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0, 0.0]);
+    /// assert_eq!(vec![8.0, 12.0, 6.0], poly.taylor_coefficients_at(2.0, 2));
+    /// assert_eq!(vec![8.0, 12.0, 6.0, 1.0, 0.0], poly.taylor_coefficients_at(2.0, 4));
+    /// ```
+    pub fn taylor_coefficients_at(&self, a: f64, k: u32) -> Vec<f64> {
+        let mut coefficients = vec![0.0; k as usize + 1];
+        let Some(degree) = self.degree() else {
+            return coefficients;
+        };
+        let degree = degree as usize;
+        let mut values = vec![0.0; degree + 1];
+        for (power, coefficient) in &self.coefficients {
+            values[*power as usize] = *coefficient;
+        }
+
+        // After the j-th division the coefficient at j is the j-th remainder
+        for j in 0..degree.min(k as usize + 1) {
+            for i in (j..degree).rev() {
+                values[i] += a * values[i + 1];
+            }
+        }
+        let count = coefficients.len().min(degree + 1);
+        coefficients[..count].copy_from_slice(&values[..count]);
+        coefficients
+    }
+
+    /// Returns the remainder of the composition `P(Q(x))` divided by the modulus `M`, without
+    /// forming the full composition.
+    ///
+    /// `Q` is reduced modulo `M` first, and Horner's method reduces the intermediate results
+    /// after every multiplication, so their degrees stay below the degree of `M`. The gaps
+    /// between the powers of a sparse `P` are bridged with binary exponentiation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let mut p = Polynomial::zero();
+    /// p.set_coefficient_at(1000, 1.0);
+    /// let q = Polynomial::from_coefficients(&vec![1.0, 0.0]);
+    /// let modulus = Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]);
+    /// // x^1000 = (x^2)^500 = (-1)^500 modulo x^2 + 1
+    /// assert_eq!(Polynomial::one(), p.compose_mod(&q, &modulus));
+    /// ```
+    pub fn compose_mod(&self, q: &Polynomial, modulus: &Polynomial) -> Polynomial {
+        let q = q.clone() % modulus;
+        let mut result = Polynomial::zero();
+        let mut last_power: Option<u32> = None;
+
+        for (power, coefficient) in self.coefficients.iter().rev() {
+            if let Some(last_power) = last_power {
+                result = (result * &pow_mod(&q, last_power - power, modulus)) % modulus;
+            }
+            result = (result + *coefficient) % modulus;
+            last_power = Some(*power);
+        }
+
+        if let Some(last_power) = last_power {
+            result = (result * &pow_mod(&q, last_power, modulus)) % modulus;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use crate::Polynomial;
+    use crate::polynomial::strategy::polynomials_with;
+
+    /// Monic polynomials with small integer coefficients, so that the reduction is exact.
+    fn monic() -> impl Strategy<Value = Polynomial> {
+        prop::collection::vec((-3..=3).prop_map(f64::from), 1..5)
+            .prop_map(|lower_coefficients| {
+                let mut coefficients = vec![1.0];
+                coefficients.extend(lower_coefficients);
+                Polynomial::from_coefficients(&coefficients)
+            })
+    }
+
+    #[test]
+    fn compose_works() {
+        let p = Polynomial::from_coefficients(&vec![2.0, 0.0, -1.0, 3.0]);
+        let q = Polynomial::from_coefficients(&vec![1.0, 1.0]);
+        // 2(x + 1)^3 - (x + 1) + 3
+        assert_eq!(vec![2.0, 6.0, 5.0, 4.0], p.compose(&q).get_coefficients());
+
+        let x = Polynomial::from_coefficients(&vec![1.0, 0.0]);
+        assert_eq!(p, p.compose(&x));
+        assert_eq!(Polynomial::from_coefficients(&vec![4.0]), p.compose(&Polynomial::one()));
+        assert!(Polynomial::zero().compose(&q).is_zero());
+    }
+
+    #[test]
+    fn compose_sparse_polynomial() {
+        let mut p = Polynomial::zero();
+        p.set_coefficient_at(5, 1.0);
+        p.set_coefficient_at(2, 1.0);
+        let q = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0]);
+        let mut expected = Polynomial::zero();
+        expected.set_coefficient_at(10, 1.0);
+        expected.set_coefficient_at(4, 1.0);
+        assert_eq!(expected, p.compose(&q));
+    }
+
+    #[test]
+    fn compose_mod_stays_below_modulus_degree() {
+        let p = Polynomial::from_coefficients(&vec![1.0, -2.0, 0.0, 5.0, 1.0, -1.0]);
+        let q = Polynomial::from_coefficients(&vec![1.0, 0.0, 3.0, 1.0]);
+        let modulus = Polynomial::from_coefficients(&vec![1.0, 0.0, 2.0]);
+        let result = p.compose_mod(&q, &modulus);
+        assert!(result.degree().is_none_or(|degree| degree < 2));
+        assert_eq!(p.compose(&q) % &modulus, result);
+    }
+
+    #[test]
+    fn compose_mod_by_constant_is_zero() {
+        let p = Polynomial::from_coefficients(&vec![1.0, 2.0, 3.0]);
+        let q = Polynomial::from_coefficients(&vec![1.0, 1.0]);
+        assert!(p.compose_mod(&q, &Polynomial::from_coefficients(&vec![2.0])).is_zero());
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot divide")]
+    fn compose_mod_by_zero_polynomial() {
+        let p = Polynomial::from_coefficients(&vec![1.0, 2.0]);
+        let _ = p.compose_mod(&Polynomial::one(), &Polynomial::zero());
+    }
+
+    #[test]
+    fn taylor_coefficients_at_zero_are_the_coefficients() {
+        let poly = Polynomial::from_coefficients(&vec![2.0, 0.0, -1.0, 5.0]);
+        assert_eq!(vec![5.0, -1.0, 0.0, 2.0, 0.0, 0.0], poly.taylor_coefficients_at(0.0, 5));
+        assert_eq!(vec![5.0], poly.taylor_coefficients_at(0.0, 0));
+    }
+
+    #[test]
+    fn taylor_coefficients_of_zero_and_constant() {
+        assert_eq!(vec![0.0, 0.0, 0.0], Polynomial::zero().taylor_coefficients_at(3.0, 2));
+        let constant = Polynomial::from_coefficients(&vec![-4.0]);
+        assert_eq!(vec![-4.0, 0.0], constant.taylor_coefficients_at(3.0, 1));
+    }
+
+    #[test]
+    fn taylor_coefficients_detect_multiplicity() {
+        // (x - 1)^3 (x + 2)
+        let poly = Polynomial::from_coefficients(&vec![1.0, -1.0, -3.0, 5.0, -2.0]);
+        assert_eq!(vec![0.0, 0.0, 0.0, 3.0, 1.0], poly.taylor_coefficients_at(1.0, 4));
+    }
+
+    proptest! {
+        #[test]
+        fn taylor_coefficients_match_derivatives_and_shift(
+            p in polynomials_with((-5..=5).prop_map(f64::from), 8),
+            a in (-3..=3).prop_map(f64::from),
+            k in 0u32..12,
+        ) {
+            let coefficients = p.taylor_coefficients_at(a, k);
+            prop_assert_eq!(k as usize + 1, coefficients.len());
+            prop_assert_eq!(p.evaluate(a), coefficients[0]);
+
+            let shifted = p.compose(&Polynomial::from_coefficients(&vec![1.0, a]));
+            let mut derivative = p.clone();
+            let mut factorial = 1.0;
+            for (i, coefficient) in coefficients.into_iter().enumerate() {
+                prop_assert_eq!(shifted.get_coefficient_at(i as u32), coefficient);
+                if i > 0 {
+                    factorial *= i as f64;
+                    derivative = derivative.derivative();
+                }
+                prop_assert_eq!(derivative.evaluate(a) / factorial, coefficient);
+            }
+        }
+
+        #[test]
+        fn compose_mod_matches_remainder_of_composition(
+            p in polynomials_with((-3..=3).prop_map(f64::from), 6),
+            q in polynomials_with((-3..=3).prop_map(f64::from), 3),
+            modulus in monic(),
+        ) {
+            prop_assert_eq!(p.compose(&q) % &modulus, p.compose_mod(&q, &modulus));
+        }
+    }
+}
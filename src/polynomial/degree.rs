@@ -0,0 +1,151 @@
+//! Module containing the [`Degree`] type returned by the `Polynomial.deg` method.
+use std::ops::Add;
+
+/// Represents the degree of a polynomial.
+///
+/// The degree of the zero polynomial is defined as negative infinity, which makes the
+/// identity `deg(P * Q) = deg(P) + deg(Q)` hold for all polynomials. The variants are ordered,
+/// so `Degree::NegInfinity` is less than any finite degree.
+///
+/// # Examples
+///
+/// ```
+/// use polynomials::{Degree, Polynomial};
+///
+/// let poly1 = Polynomial::from_coefficients(&vec![1.0, 0.0, -2.0]);
+/// let poly2 = Polynomial::from_coefficients(&vec![3.0, 1.0]);
+/// assert!(poly1.deg() > poly2.deg());
+/// assert_eq!(Degree::Finite(3), poly1.deg() + poly2.deg());
+/// assert!(Polynomial::zero().deg() < poly2.deg());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
+pub enum Degree {
+    /// Degree of the zero polynomial.
+    NegInfinity,
+
+    /// Degree of a non-zero polynomial.
+    Finite(u32),
+}
+
+impl Degree {
+    /// Checks if the degree is finite, i.e. it is not the degree of the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Degree;
+    ///
+    /// assert!(Degree::Finite(0).is_finite());
+    /// assert!(!Degree::NegInfinity.is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        matches!(self, Degree::Finite(_))
+    }
+
+    /// Returns the finite degree as `Some(u32)`, or `None` for `Degree::NegInfinity`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Degree;
+    ///
+    /// assert_eq!(Some(4), Degree::Finite(4).finite());
+    /// assert_eq!(None, Degree::NegInfinity.finite());
+    /// ```
+    pub fn finite(self) -> Option<u32> {
+        match self {
+            Degree::Finite(degree) => Some(degree),
+            Degree::NegInfinity => None,
+        }
+    }
+}
+
+impl From<Option<u32>> for Degree {
+    fn from(degree: Option<u32>) -> Self {
+        match degree {
+            Some(degree) => Degree::Finite(degree),
+            None => Degree::NegInfinity,
+        }
+    }
+}
+
+impl From<Degree> for Option<u32> {
+    fn from(degree: Degree) -> Self {
+        degree.finite()
+    }
+}
+
+impl Add for Degree {
+    type Output = Degree;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Degree::Finite(degree1), Degree::Finite(degree2)) => Degree::Finite(degree1 + degree2),
+            _ => Degree::NegInfinity,
+        }
+    }
+}
+
+impl Add<u32> for Degree {
+    type Output = Degree;
+
+    fn add(self, rhs: u32) -> Self::Output {
+        self + Degree::Finite(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Degree;
+    use crate::Polynomial;
+
+    #[test]
+    fn ordering() {
+        assert!(Degree::NegInfinity < Degree::Finite(0));
+        assert!(Degree::Finite(0) < Degree::Finite(1));
+        assert!(Degree::Finite(10) >= Degree::Finite(10));
+        assert_eq!(Degree::NegInfinity, Degree::NegInfinity);
+    }
+
+    #[test]
+    fn ordering_with_zero_polynomial() {
+        let zero = Polynomial::zero();
+        let constant = Polynomial::from_coefficients(&vec![3.0]);
+        assert!(zero.deg() < constant.deg());
+        assert!(zero.deg() <= zero.deg());
+        assert_eq!(Degree::NegInfinity, zero.deg());
+        assert_eq!(Degree::Finite(0), constant.deg());
+    }
+
+    #[test]
+    fn addition() {
+        assert_eq!(Degree::Finite(5), Degree::Finite(2) + Degree::Finite(3));
+        assert_eq!(Degree::NegInfinity, Degree::NegInfinity + Degree::Finite(3));
+        assert_eq!(Degree::NegInfinity, Degree::Finite(3) + Degree::NegInfinity);
+        assert_eq!(Degree::Finite(3), Degree::Finite(1) + 2);
+    }
+
+    #[test]
+    fn degree_of_product_is_sum_of_degrees() {
+        let polys = [
+            Polynomial::zero(),
+            Polynomial::from_coefficients(&vec![-2.0]),
+            Polynomial::from_coefficients(&vec![1.0, -1.0]),
+            Polynomial::from_coefficients(&vec![2.0, 0.0, 0.0, 5.0]),
+        ];
+        for poly1 in polys.iter() {
+            for poly2 in polys.iter() {
+                let product = poly1.clone() * poly2;
+                assert_eq!(product.deg(), poly1.deg() + poly2.deg());
+            }
+        }
+    }
+
+    #[test]
+    fn conversion_from_and_to_option() {
+        assert_eq!(Degree::Finite(2), Degree::from(Some(2)));
+        assert_eq!(Degree::NegInfinity, Degree::from(None));
+        assert_eq!(Some(2), Option::<u32>::from(Degree::Finite(2)));
+        assert_eq!(None, Option::<u32>::from(Degree::NegInfinity));
+    }
+}
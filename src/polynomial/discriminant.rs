@@ -0,0 +1,165 @@
+use super::Polynomial;
+
+/// Returns the determinant of the square matrix with the fraction-free Bareiss elimination.
+///
+/// Every intermediate entry is a minor of the matrix, so the determinant of an integer matrix
+/// is exact as long as the minors are exactly representable.
+fn determinant(mut matrix: Vec<Vec<f64>>) -> f64 {
+    let n = matrix.len();
+    let mut sign = 1.0;
+    let mut previous_pivot = 1.0;
+    for k in 0..n {
+        let Some(pivot_row) = (k..n).find(|row| matrix[*row][k] != 0.0) else {
+            return 0.0;
+        };
+        if pivot_row != k {
+            matrix.swap(pivot_row, k);
+            sign = -sign;
+        }
+        let pivot = matrix[k][k];
+        for i in k + 1..n {
+            for j in k + 1..n {
+                matrix[i][j] = (matrix[i][j] * pivot - matrix[i][k] * matrix[k][j]) / previous_pivot;
+            }
+        }
+        previous_pivot = pivot;
+    }
+    if n == 0 { 1.0 } else { sign * matrix[n - 1][n - 1] }
+}
+
+/// Returns the resultant of the nonzero polynomials as the determinant of their Sylvester
+/// matrix.
+fn resultant(a: &Polynomial, b: &Polynomial) -> f64 {
+    let (m, n) = (a.degree().unwrap_or(0) as usize, b.degree().unwrap_or(0) as usize);
+    let size = m + n;
+    let mut matrix = vec![vec![0.0; size]; size];
+    for row in 0..n {
+        for (power, coefficient) in &a.coefficients {
+            matrix[row][row + m - *power as usize] = *coefficient;
+        }
+    }
+    for row in 0..m {
+        for (power, coefficient) in &b.coefficients {
+            matrix[n + row][row + n - *power as usize] = *coefficient;
+        }
+    }
+    determinant(matrix)
+}
+
+impl Polynomial {
+    /// Returns the discriminant of the polynomial, or `None` for the zero polynomial and the
+    /// constants.
+    ///
+    /// The discriminant of a polynomial of degree `n` with the leading coefficient `a` is
+    /// `(-1)^(n(n - 1) / 2) Res(P, P') / a`, where the resultant is the determinant of the
+    /// Sylvester matrix of the polynomial and its derivative. It is zero exactly when the
+    /// polynomial has a multiple root, and for the linear polynomials it is one.
+    ///
+    /// The determinant is computed with the fraction-free Bareiss elimination, whose
+    /// intermediate values are the minors of the matrix, so the discriminant of a polynomial
+    /// with small integer coefficients is exact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // 2x^2 + 3x - 5, b^2 - 4ac = 9 + 40
+    /// let poly = Polynomial::from_coefficients(&vec![2.0, 3.0, -5.0]);
+    /// assert_eq!(Some(49.0), poly.discriminant());
+    ///
+    /// // (x - 1)^2 (x + 2)
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -3.0, 2.0]);
+    /// assert_eq!(Some(0.0), poly.discriminant());
+    /// ```
+    pub fn discriminant(&self) -> Option<f64> {
+        let degree = self.degree().filter(|degree| *degree > 0)?;
+        if degree == 1 {
+            return Some(1.0);
+        }
+        let n = degree as u64;
+        let sign = if (n * (n - 1) / 2).is_multiple_of(2) { 1.0 } else { -1.0 };
+        Some(sign * resultant(self, &self.derivative()) / self.get_coefficient_at(degree))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::determinant;
+    use crate::Polynomial;
+    use crate::polynomial::strategy::polynomials_with;
+
+    fn from_roots(roots: &[f64]) -> Polynomial {
+        roots.iter()
+            .map(|root| Polynomial::from_coefficients(&vec![1.0, -root]))
+            .product()
+    }
+
+    #[test]
+    fn determinant_with_row_swaps() {
+        assert_eq!(-2.0, determinant(vec![vec![0.0, 1.0], vec![2.0, 3.0]]));
+        assert_eq!(0.0, determinant(vec![vec![1.0, 2.0, 3.0], vec![2.0, 4.0, 6.0], vec![1.0, 0.0, 1.0]]));
+        assert_eq!(-306.0, determinant(vec![vec![6.0, 1.0, 1.0], vec![4.0, -2.0, 5.0], vec![2.0, 8.0, 7.0]]));
+        assert_eq!(1.0, determinant(Vec::new()));
+    }
+
+    #[test]
+    fn cubic_discriminant() {
+        // x^3 + px + q has the discriminant -4p^3 - 27q^2
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -3.0, 1.0]);
+        assert_eq!(Some(81.0), poly.discriminant());
+        // (x - 1)(x - 2)(x + 3), the squared product of the differences of the roots
+        let poly = from_roots(&[1.0, 2.0, -3.0]);
+        assert_eq!(Some((1.0f64 * 4.0 * 5.0).powi(2)), poly.discriminant());
+        // The sign tells whether there is a pair of complex roots
+        let poly = from_roots(&[2.0]) * Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]);
+        assert!(poly.discriminant().unwrap() < 0.0);
+    }
+
+    #[test]
+    fn repeated_roots_have_zero_discriminant() {
+        assert_eq!(Some(0.0), from_roots(&[1.0, 1.0, -2.0]).discriminant());
+        assert_eq!(Some(0.0), from_roots(&[3.0, -1.0, 3.0, 0.0]).discriminant());
+        assert_eq!(Some(0.0), (from_roots(&[0.5, 0.5]) * 4.0).discriminant());
+    }
+
+    #[test]
+    fn quartic_discriminant_of_distinct_roots() {
+        // The product of the squared differences of 0, 1, 2 and 4
+        let expected: f64 = [1.0, 2.0, 4.0, 1.0, 3.0, 2.0].iter().product::<f64>().powi(2);
+        assert_eq!(Some(expected), from_roots(&[0.0, 1.0, 2.0, 4.0]).discriminant());
+    }
+
+    #[test]
+    fn discriminant_of_low_degrees() {
+        assert_eq!(None, Polynomial::zero().discriminant());
+        assert_eq!(None, Polynomial::from_coefficients(&vec![-3.0]).discriminant());
+        assert_eq!(Some(1.0), Polynomial::from_coefficients(&vec![-3.0, 2.0]).discriminant());
+    }
+
+    proptest! {
+        #[test]
+        fn quadratic_discriminant(
+            a in (-20..=20i32).prop_filter("Nonzero leading coefficient", |a| *a != 0),
+            b in -20..=20i32,
+            c in -20..=20i32,
+        ) {
+            let (a, b, c) = (f64::from(a), f64::from(b), f64::from(c));
+            let poly = Polynomial::from_coefficients(&vec![a, b, c]);
+            prop_assert_eq!(Some(b * b - 4.0 * a * c), poly.discriminant());
+        }
+
+        #[test]
+        fn square_has_zero_discriminant(
+            a in (-3..=3i32).prop_filter("Nonzero leading coefficient", |a| *a != 0),
+            q in polynomials_with((-3..=3).prop_map(f64::from), 2),
+        ) {
+            // (a x^3 + q) (x + 1)^2 has a double root at -1, and q has degree at most 2
+            let mut p = q;
+            p.set_coefficient_at(3, f64::from(a));
+            let square = Polynomial::from_coefficients(&vec![1.0, 2.0, 1.0]);
+            prop_assert_eq!(Some(0.0), (p * &square).discriminant());
+        }
+    }
+}
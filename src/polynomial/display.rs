@@ -1,8 +1,12 @@
 use std::fmt;
-use std::fmt::Display;
+use std::fmt::{Display, Write};
+use std::io;
 use super::Polynomial;
+use super::variable::is_valid_variable;
+use crate::Degree;
 
 /// Specifies the format used by the `Polynomial.format_with` method.
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum PolynomialFormat {
     /// Standard format that uses caret `^` before the powers.
     Standard,
@@ -11,51 +15,394 @@ pub enum PolynomialFormat {
     /// around them.
     Latex,
 
-    /// Concise format that omits the carets `^` before the powers.
+    /// Concise format that omits the carets `^` before single-digit powers.
+    ///
+    /// Powers greater than 9 are still preceded by a caret, so `x^23` is never written
+    /// as the ambiguous `x23`.
     Concise
 }
 
-impl Polynomial {
-    fn write_to_fmt(&self, f: &mut dyn fmt::Write, string_format: PolynomialFormat) -> fmt::Result {
-        // Handle the zero polynomial case
-        if let None = self.degree() {
-            return write!(f, "0");
-        }
+/// Specifies the order in which the terms of a polynomial are written.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum TermOrder {
+    /// Terms are written from the highest power of the indeterminate downward.
+    Descending,
 
-        for (power, coefficient) in self.coefficients.iter().rev() {
-            if *coefficient == 0.0 {
-                continue;
-            }
+    /// Terms are written from the lowest power of the indeterminate upward.
+    Ascending
+}
 
-            let sign = if *coefficient > 0.0 { "+" } else { "-" };
+/// Specifies how the coefficients are rounded before they are written.
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum Rounding {
+    Exact,
+    DecimalPlaces(usize),
+    SignificantDigits(usize),
+}
 
-            // Write the sign of the term
-            if *power == self.degree().unwrap() && sign == "-" {
-                write!(f, "{sign} ")?;
-            } else if *power != self.degree().unwrap() {
-                write!(f, " {sign} ")?;
-            }
+/// A fixed-size buffer for formatting the rounded coefficients without allocating.
+///
+/// It fits every `f64` with more decimal places or significant digits than it has, so when it
+/// overflows the rounding doesn't change the value.
+struct RoundingBuffer {
+    bytes: [u8; 1536],
+    len: usize,
+}
 
-            // Write the coefficient if it's not 1, or it's the term of degree 0
-            if coefficient.abs() != 1.0 || *power == 0 {
-                write!(f, "{}", coefficient.abs())?;
-            }
+impl RoundingBuffer {
+    /// Formats the arguments and parses the result back, or returns `None` if it doesn't fit.
+    fn reparse(args: fmt::Arguments) -> Option<f64> {
+        let mut buffer = RoundingBuffer { bytes: [0; 1536], len: 0 };
+        buffer.write_fmt(args).ok()?;
+        std::str::from_utf8(&buffer.bytes[..buffer.len]).ok()?.parse().ok()
+    }
+}
 
-            // Write the indeterminate x and the power if it's not 0
-            if *power == 0 {
-                continue;
-            }
-            if *power == 1 {
-                write!(f, "x")?;
-                continue;
+impl Write for RoundingBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl Rounding {
+    /// Returns the value the coefficient is rounded to.
+    fn round(&self, value: f64) -> f64 {
+        match *self {
+            Rounding::Exact => value,
+            Rounding::DecimalPlaces(places) => {
+                RoundingBuffer::reparse(format_args!("{value:.places$}")).unwrap_or(value)
             }
-            match string_format {
-                PolynomialFormat::Latex => write!(f, "x^{{{power}}}")?,
-                PolynomialFormat::Concise => write!(f, "x{power}")?,
-                PolynomialFormat::Standard => write!(f, "x^{power}")?,
+            Rounding::SignificantDigits(digits) => {
+                let places = digits.max(1) - 1;
+                RoundingBuffer::reparse(format_args!("{value:.places$e}")).unwrap_or(value)
             }
         }
-        Ok(())
+    }
+}
+
+/// Configures the output of the `Polynomial.format_opts` method.
+///
+/// The options are built by chaining the setter methods, starting either from
+/// [`FormatOptions::new`] or from one of the [`PolynomialFormat`] presets.
+///
+/// # Examples
+///
+/// ```
+/// use polynomials::{FormatOptions, Polynomial, PolynomialFormat, TermOrder};
+///
+/// let poly = Polynomial::from_coefficients(&vec![1.0, 3.0, 2.0]);
+/// let options = FormatOptions::new().variable("s");
+/// assert_eq!("s^2 + 3s + 2", poly.format_opts(&options));
+///
+/// let options = FormatOptions::from(PolynomialFormat::Latex)
+///     .term_order(TermOrder::Ascending)
+///     .multiplication_sign(" \\cdot ");
+/// assert_eq!("2 + 3 \\cdot x + x^{2}", poly.format_opts(&options));
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct FormatOptions {
+    format: PolynomialFormat,
+    variable: String,
+    term_order: TermOrder,
+    show_zero_terms: bool,
+    multiplication_sign: String,
+    rounding: Rounding,
+    keep_rounded_zeros: bool,
+    terms_per_line: Option<usize>,
+    line_width: Option<usize>,
+    max_terms: Option<usize>,
+}
+
+impl FormatOptions {
+    /// Returns the options of the `PolynomialFormat::Standard` preset.
+    pub fn new() -> FormatOptions {
+        FormatOptions {
+            format: PolynomialFormat::Standard,
+            variable: String::from("x"),
+            term_order: TermOrder::Descending,
+            show_zero_terms: false,
+            multiplication_sign: String::new(),
+            rounding: Rounding::Exact,
+            keep_rounded_zeros: false,
+            terms_per_line: None,
+            line_width: None,
+            max_terms: None,
+        }
+    }
+
+    /// Sets the [format](PolynomialFormat) used to write the powers of the indeterminate.
+    pub fn format(mut self, format: PolynomialFormat) -> FormatOptions {
+        self.format = format;
+        self
+    }
+
+    /// Sets the symbol used as the indeterminate. Defaults to `x`.
+    ///
+    /// In LaTeX, a name of several letters like `theta` is written in `\mathit` and a
+    /// subscript of several characters is put in braces, e.g. `x_12` becomes `x_{12}`, while a
+    /// control word like `\theta` is written as it is.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the symbol could be confused with the rest of the polynomial syntax, i.e. it
+    /// is empty, starts with a digit, is `i` or `j`, or contains whitespace, signs, operators,
+    /// parentheses, dots or commas. The same symbols are rejected by
+    /// `Polynomial::from_string_with_variable`.
+    pub fn variable(mut self, variable: &str) -> FormatOptions {
+        assert!(is_valid_variable(variable), "Invalid variable name '{variable}'.");
+        self.variable = variable.to_string();
+        self
+    }
+
+    /// Sets the [order](TermOrder) of the terms. Defaults to `TermOrder::Descending`.
+    pub fn term_order(mut self, term_order: TermOrder) -> FormatOptions {
+        self.term_order = term_order;
+        self
+    }
+
+    /// Sets whether the terms with zero coefficients are written for every power from the degree
+    /// of the polynomial down to zero. Defaults to `false`.
+    ///
+    /// The zero polynomial is always written as `0`.
+    pub fn show_zero_terms(mut self, show_zero_terms: bool) -> FormatOptions {
+        self.show_zero_terms = show_zero_terms;
+        self
+    }
+
+    /// Sets the string written between a coefficient and the indeterminate.
+    /// Defaults to an empty string.
+    ///
+    /// The sign is omitted together with the coefficient when the coefficient is equal to one.
+    pub fn multiplication_sign(mut self, multiplication_sign: &str) -> FormatOptions {
+        self.multiplication_sign = multiplication_sign.to_string();
+        self
+    }
+
+    /// Rounds every coefficient to the given number of decimal places.
+    ///
+    /// Overrides the number of [significant digits](FormatOptions::significant_digits).
+    /// The coefficient is omitted if its rounded value is equal to one, and the term is
+    /// omitted if the rounded value is zero, unless
+    /// [`keep_rounded_zeros`](FormatOptions::keep_rounded_zeros) is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{FormatOptions, Polynomial};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![0.99999, -2.0 / 3.0, 0.0001]);
+    /// let options = FormatOptions::new().precision(3);
+    /// assert_eq!("x^2 - 0.667x", poly.format_opts(&options));
+    /// ```
+    pub fn precision(mut self, decimal_places: usize) -> FormatOptions {
+        self.rounding = Rounding::DecimalPlaces(decimal_places);
+        self
+    }
+
+    /// Rounds every coefficient to the given number of significant digits.
+    ///
+    /// Overrides the number of decimal places set by the
+    /// [`precision`](FormatOptions::precision) method. The rules for the coefficients
+    /// rounded to one or zero are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{FormatOptions, Polynomial};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0000000000000002, -2.9999999999999996, 1234.5]);
+    /// let options = FormatOptions::new().significant_digits(3);
+    /// assert_eq!("x^2 - 3x + 1230", poly.format_opts(&options));
+    /// ```
+    pub fn significant_digits(mut self, digits: usize) -> FormatOptions {
+        self.rounding = Rounding::SignificantDigits(digits);
+        self
+    }
+
+    /// Sets whether the terms whose coefficients are rounded to zero are written.
+    /// Defaults to `false`.
+    pub fn keep_rounded_zeros(mut self, keep_rounded_zeros: bool) -> FormatOptions {
+        self.keep_rounded_zeros = keep_rounded_zeros;
+        self
+    }
+
+    /// Breaks the `PolynomialFormat::Latex` output into lines of at most the given number of
+    /// terms. Ignored by the other formats.
+    ///
+    /// When the lines are broken, the polynomial is written in an `aligned` environment of the
+    /// `amsmath` package, with the lines ending with `\\` and starting with `&`. The
+    /// continuation lines are indented, and they start with the sign of their first term.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of terms is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{FormatOptions, Polynomial, PolynomialFormat};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 3.0, -4.0, 5.0]);
+    /// let options = FormatOptions::from(PolynomialFormat::Latex).terms_per_line(3);
+    /// assert_eq!(
+    ///     "\\begin{aligned}\n&x^{4} - 2x^{3} + 3x^{2} \\\\\n&\\quad {} - 4x + 5\n\\end{aligned}",
+    ///     poly.format_opts(&options)
+    /// );
+    /// ```
+    pub fn terms_per_line(mut self, terms: usize) -> FormatOptions {
+        assert!(terms > 0, "Invalid number of terms per line");
+        self.terms_per_line = Some(terms);
+        self
+    }
+
+    /// Breaks the `PolynomialFormat::Latex` output into lines of at most the given number of
+    /// characters of the LaTeX source, not counting the alignment and the indentation. Ignored
+    /// by the other formats.
+    ///
+    /// A line is broken only before a term, so a single term longer than the width is written
+    /// on its own line. The lines are written as with the
+    /// [`terms_per_line`](FormatOptions::terms_per_line) option, and both can be combined.
+    pub fn line_width(mut self, width: usize) -> FormatOptions {
+        self.line_width = Some(width);
+        self
+    }
+
+    /// Elides the middle terms of the `PolynomialFormat::Latex` output when the polynomial has
+    /// more terms than the limit, writing the first half of the limit of the terms, the number
+    /// of the omitted terms under `\cdots`, and the rest of the terms. Ignored by the other
+    /// formats.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the limit is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{FormatOptions, Polynomial, PolynomialFormat};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0; 41]);
+    /// let options = FormatOptions::from(PolynomialFormat::Latex).max_terms(4);
+    /// assert_eq!(
+    ///     "x^{40} + x^{39} + \\underbrace{\\cdots}_{37\\text{ terms}} + x + 1",
+    ///     poly.format_opts(&options)
+    /// );
+    /// ```
+    pub fn max_terms(mut self, limit: usize) -> FormatOptions {
+        assert!(limit > 0, "Invalid term limit");
+        self.max_terms = Some(limit);
+        self
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions::new()
+    }
+}
+
+impl From<PolynomialFormat> for FormatOptions {
+    fn from(format: PolynomialFormat) -> Self {
+        FormatOptions::new().format(format)
+    }
+}
+
+impl Polynomial {
+    fn write_to_fmt(&self, f: &mut dyn fmt::Write, options: &FormatOptions) -> fmt::Result {
+        // Handle the zero polynomial case
+        let Degree::Finite(degree) = self.deg() else {
+            return write_coefficient(f, 0.0, options);
+        };
+
+        if !options.show_zero_terms {
+            let terms = self.coefficients.iter().map(|(power, coefficient)| (*power as i64, *coefficient));
+            return write_sparse_terms(f, terms, options);
+        }
+
+        // Every power up to the degree is written
+        let term = |power: u32| (power as i64, options.rounding.round(self.get_coefficient_at(power)));
+        match options.term_order {
+            TermOrder::Descending => write_terms(f, (0..=degree).rev().map(term), options),
+            TermOrder::Ascending => write_terms(f, (0..=degree).map(term), options),
+        }
+    }
+
+    /// Writes the polynomial formatted according to the given [options](FormatOptions) to the
+    /// writer, without allocating memory, so that it can be written into a fixed-size buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fmt::Write;
+    /// use polynomials::{FormatOptions, Polynomial, PolynomialFormat};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 0.0, 3.0]);
+    /// let mut buffer = String::with_capacity(32);
+    /// poly.format_into(&mut buffer, &FormatOptions::from(PolynomialFormat::Latex)).unwrap();
+    /// write!(buffer, " = 0").unwrap();
+    /// assert_eq!("x^{3} - 2x^{2} + 3 = 0", buffer);
+    /// ```
+    pub fn format_into(&self, buffer: &mut impl fmt::Write, options: &FormatOptions) -> fmt::Result {
+        self.write_to_fmt(buffer, options)
+    }
+
+    /// Writes the polynomial formatted according to the given [options](FormatOptions) to the
+    /// I/O writer piece by piece, without building the whole string first.
+    ///
+    /// The writer receives many small writes, so a file or a socket should be wrapped in a
+    /// [`BufWriter`](std::io::BufWriter).
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{Polynomial, PolynomialFormat};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -2.0]);
+    /// let mut output = Vec::new();
+    /// poly.write_formatted(&mut output, &PolynomialFormat::Latex.into()).unwrap();
+    /// assert_eq!(b"x^{2} - 2", output.as_slice());
+    /// ```
+    pub fn write_formatted<W: io::Write>(&self, writer: &mut W, options: &FormatOptions) -> io::Result<()> {
+        let mut adapter = IoWriter { writer, error: None };
+        match self.format_into(&mut adapter, options) {
+            Ok(()) => Ok(()),
+            Err(fmt::Error) => Err(adapter.error.unwrap_or_else(|| io::Error::other("Formatting failed"))),
+        }
+    }
+
+    /// Returns the polynomial as a [`String`] formatted according to the given
+    /// [options](FormatOptions).
+    ///
+    /// # Examples
+    ///
+    /// Write the polynomial in the indeterminate `t` in the ascending order of powers:
+    /// ```
+    /// use polynomials::{FormatOptions, Polynomial, TermOrder};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![-1.0, 0.0, 2.0]);
+    /// let options = FormatOptions::new().variable("t").term_order(TermOrder::Ascending);
+    /// assert_eq!("2 - t^2", poly.format_opts(&options));
+    /// ```
+    ///
+    /// Write all the terms up to the degree of the polynomial:
+    /// ```
+    /// use polynomials::{FormatOptions, Polynomial};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -2.0, 0.0]);
+    /// let options = FormatOptions::new().show_zero_terms(true);
+    /// assert_eq!("x^3 + 0x^2 - 2x + 0", poly.format_opts(&options));
+    /// ```
+    pub fn format_opts(&self, options: &FormatOptions) -> String {
+        let mut buffer = String::new();
+        self.format_into(&mut buffer, options).expect("Writing to a String cannot fail");
+        buffer
     }
 
     /// Returns the polynomial as a [`String`] in the specified [format](PolynomialFormat).
@@ -86,21 +433,298 @@ impl Polynomial {
     /// assert_eq!("x3 + 2x2 - x + 3", poly.format_with(PolynomialFormat::Concise));
     /// ```
     pub fn format_with(&self, format: PolynomialFormat) -> String {
-        let mut buffer = String::new();
-        let _ = self.write_to_fmt(&mut buffer, format);
-        buffer
+        self.format_opts(&FormatOptions::from(format))
+    }
+}
+
+/// Adapts an I/O writer to `fmt::Write`, keeping the I/O error which `fmt::Error` can't carry.
+struct IoWriter<'a, W: io::Write> {
+    writer: &'a mut W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> Write for IoWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.writer.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
+
+/// Writes the nonzero terms given in the ascending order of powers, in the order set by the
+/// options, skipping the terms rounded to zero. A zero is written if no term is written.
+pub(super) fn write_sparse_terms(
+    f: &mut dyn fmt::Write,
+    terms: impl DoubleEndedIterator<Item = (i64, f64)> + Clone,
+    options: &FormatOptions
+) -> fmt::Result {
+    let round = |(power, coefficient): (i64, f64)| {
+        let coefficient = options.rounding.round(coefficient);
+        (coefficient != 0.0 || options.keep_rounded_zeros).then_some((power, coefficient))
+    };
+    match options.term_order {
+        TermOrder::Descending => write_terms(f, terms.rev().filter_map(round), options),
+        TermOrder::Ascending => write_terms(f, terms.filter_map(round), options),
+    }
+}
+
+/// An item of the written sequence of terms.
+#[derive(Clone, Copy)]
+enum Item {
+    Term(i64, f64),
+    /// The given number of the elided terms.
+    Ellipsis(usize),
+}
+
+/// Counts the written characters.
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
     }
 }
 
+/// Writes the rounded terms in the given order, eliding and breaking them into lines if the
+/// options require it. A zero is written if there are no terms.
+fn write_terms(
+    f: &mut dyn fmt::Write,
+    terms: impl Iterator<Item = (i64, f64)> + Clone,
+    options: &FormatOptions
+) -> fmt::Result {
+    let is_latex = options.format == PolynomialFormat::Latex;
+    let max_terms = options.max_terms.filter(|_| is_latex);
+    let breaks_lines = is_latex && (options.terms_per_line.is_some() || options.line_width.is_some());
+
+    if terms.clone().next().is_none() {
+        return write_coefficient(f, 0.0, options);
+    }
+    let count = if max_terms.is_some() { terms.clone().count() } else { 0 };
+
+    // The first half of the limit of the terms is written before the ellipsis, the rest after
+    let (head, tail) = match max_terms {
+        Some(limit) if count > limit => (limit.div_ceil(2), limit / 2),
+        _ => (count, 0),
+    };
+    let elided = count - head - tail;
+    let items = terms.enumerate().filter_map(|(index, (power, coefficient))| {
+        if elided == 0 || index < head || index >= count - tail {
+            Some(Item::Term(power, coefficient))
+        } else {
+            (index == head).then_some(Item::Ellipsis(elided))
+        }
+    });
+
+    if !breaks_lines {
+        for (index, item) in items.enumerate() {
+            write_item(f, item, index == 0, options)?;
+        }
+        return Ok(());
+    }
+
+    f.write_str("\\begin{aligned}\n&")?;
+    let (mut line_terms, mut line_width) = (0, 0);
+    for (index, item) in items.enumerate() {
+        let is_first = index == 0;
+        let mut counter = CharCounter(0);
+        write_item(&mut counter, item, is_first, options)?;
+
+        let is_line_full = options.terms_per_line.is_some_and(|terms| line_terms >= terms)
+            || options.line_width.is_some_and(|width| line_width + counter.0 > width);
+        if line_terms > 0 && is_line_full {
+            f.write_str(" \\\\\n&\\quad {}")?;
+            (line_terms, line_width) = (0, 0);
+        }
+        write_item(f, item, is_first, options)?;
+        line_terms += 1;
+        line_width += counter.0;
+    }
+    f.write_str("\n\\end{aligned}")
+}
+
+fn write_item(f: &mut dyn fmt::Write, item: Item, is_first: bool, options: &FormatOptions) -> fmt::Result {
+    match item {
+        Item::Term(power, coefficient) => write_term(f, power, coefficient, is_first, options),
+        Item::Ellipsis(count) => {
+            if !is_first {
+                f.write_str(" + ")?;
+            }
+            write!(f, "\\underbrace{{\\cdots}}_{{{count}\\text{{ terms}}}}")
+        }
+    }
+}
+
+/// Writes a single term preceded by its sign.
+///
+/// The sign of the first written term is omitted if the coefficient is positive.
+fn write_term(
+    f: &mut dyn fmt::Write,
+    power: i64,
+    coefficient: f64,
+    is_first: bool,
+    options: &FormatOptions
+) -> fmt::Result {
+    let sign = if coefficient < 0.0 { "-" } else { "+" };
+
+    // Write the sign of the term
+    if !is_first {
+        write!(f, " {sign} ")?;
+    } else if sign == "-" {
+        write!(f, "{sign} ")?;
+    }
+
+    // Write the coefficient if it's not 1, or it's the term of degree 0
+    let is_coefficient_written = coefficient.abs() != 1.0 || power == 0;
+    if is_coefficient_written {
+        write_coefficient(f, coefficient.abs(), options)?;
+    }
+
+    // Write the indeterminate and the power if it's not 0
+    if power == 0 {
+        return Ok(());
+    }
+    if is_coefficient_written {
+        f.write_str(&options.multiplication_sign)?;
+
+        // Separate a control word like `\cdot` or `\infty` from the indeterminate, and
+        // `inf` or `NaN` in the other formats, so that it doesn't read as a single word
+        let is_separated = match (options.format, options.multiplication_sign.is_empty()) {
+            (PolynomialFormat::Latex, true) => coefficient.is_infinite(),
+            (PolynomialFormat::Latex, false) => ends_with_control_word(&options.multiplication_sign),
+            (_, true) => !coefficient.is_finite(),
+            (_, false) => false,
+        };
+        if is_separated {
+            f.write_char(' ')?;
+        }
+    }
+    let variable = &options.variable;
+    match options.format {
+        PolynomialFormat::Latex => {
+            write_latex_variable(f, variable)?;
+            if power == 1 { Ok(()) } else { write!(f, "^{{{power}}}") }
+        }
+        _ if power == 1 => write!(f, "{variable}"),
+        PolynomialFormat::Concise if (0..10).contains(&power) => write!(f, "{variable}{power}"),
+        PolynomialFormat::Concise => write!(f, "{variable}^{power}"),
+        PolynomialFormat::Standard => write!(f, "{variable}^{power}"),
+    }
+}
+
+/// Writes the indeterminate in LaTeX, so that a name of several characters reads as a single
+/// symbol.
+///
+/// A control word like `\theta` is written as it is. A name of several letters is written in
+/// `\mathit`, since LaTeX would space its letters as a product, and the subscript after an
+/// underscore is wrapped in braces if it has more than one character, e.g. `x_{12}`.
+fn write_latex_variable(f: &mut dyn fmt::Write, variable: &str) -> fmt::Result {
+    if variable.starts_with('\\') {
+        return f.write_str(variable);
+    }
+    let (name, subscript) = match variable.split_once('_') {
+        Some((name, subscript)) => (name, Some(subscript)),
+        None => (variable, None),
+    };
+    if name.chars().count() > 1 {
+        write!(f, "\\mathit{{{name}}}")?;
+    } else {
+        f.write_str(name)?;
+    }
+    match subscript {
+        Some(subscript) if subscript.chars().count() == 1 => write!(f, "_{subscript}"),
+        Some(subscript) => write!(f, "_{{{subscript}}}"),
+        None => Ok(()),
+    }
+}
+
+/// Checks if the string ends with a LaTeX control word, e.g. `\cdot`.
+fn ends_with_control_word(string: &str) -> bool {
+    let letters_start = string.trim_end_matches(|c: char| c.is_ascii_alphabetic()).len();
+    letters_start < string.len() && string[..letters_start].ends_with('\\')
+}
+
+/// Writes an already rounded coefficient.
+///
+/// When rounding to decimal places, the trailing zeros are written as well.
+fn write_coefficient(f: &mut dyn fmt::Write, value: f64, options: &FormatOptions) -> fmt::Result {
+    if options.format == PolynomialFormat::Latex {
+        if value.is_nan() {
+            return f.write_str("\\mathrm{NaN}");
+        }
+        if value.is_infinite() {
+            return f.write_str("\\infty");
+        }
+    }
+    match options.rounding {
+        Rounding::DecimalPlaces(places) => write!(f, "{value:.places$}"),
+        Rounding::Exact | Rounding::SignificantDigits(_) => write!(f, "{value}"),
+    }
+}
+
+/// Formats the polynomial using the `PolynomialFormat::Standard` format.
+///
+/// The formatter flags are respected as follows:
+/// - The precision rounds every coefficient to the given number of decimal places, as
+///   the [`FormatOptions::precision`] method does.
+/// - The alternate flag `{:#}` selects the `PolynomialFormat::Latex` format.
+/// - The width, fill and alignment apply to the whole polynomial string, which is
+///   left-aligned by default.
+///
+/// # Examples
+///
+/// ```
+/// use polynomials::Polynomial;
+///
+/// let poly = Polynomial::from_coefficients(&vec![1.0, 0.25, -1.0 / 3.0]);
+/// assert_eq!("x^2 + 0.25x - 0.33", format!("{:.2}", poly));
+/// assert_eq!("x^{2} + 0.25x - 0.33", format!("{:#.2}", poly));
+/// assert_eq!("[  x^2 + 0.2x - 0.3  ]", format!("[{:^20.1}]", poly));
+/// ```
 impl Display for Polynomial {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.write_to_fmt(f, PolynomialFormat::Standard)
+        let format = if f.alternate() {
+            PolynomialFormat::Latex
+        } else {
+            PolynomialFormat::Standard
+        };
+
+        let mut options = FormatOptions::from(format);
+        if let Some(precision) = f.precision() {
+            options = options.precision(precision);
+        }
+        let Some(width) = f.width() else {
+            return self.write_to_fmt(f, &options);
+        };
+
+        // Padding requires the length of the whole string to be known upfront
+        let mut buffer = String::new();
+        self.write_to_fmt(&mut buffer, &options)?;
+        let padding = width.saturating_sub(buffer.chars().count());
+        let (left_padding, right_padding) = match f.align() {
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Left) | None => (0, padding),
+        };
+
+        let fill = f.fill();
+        for _ in 0..left_padding {
+            f.write_char(fill)?;
+        }
+        f.write_str(&buffer)?;
+        for _ in 0..right_padding {
+            f.write_char(fill)?;
+        }
+        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::PolynomialFormat;
+    use std::fmt;
+    use crate::{FormatOptions, PolynomialFormat, TermOrder};
+    use super::Rounding;
     use super::Polynomial;
 
     #[test]
@@ -156,4 +780,388 @@ mod tests {
         let poly = Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
         assert_eq!("x2 + 2x - 3", poly.format_with(PolynomialFormat::Concise));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn display_respects_precision() {
+        let poly = Polynomial::from_coefficients(&vec![2.0 / 3.0, -1.0, 1.0 / 8.0]);
+        assert_eq!("0.667x^2 - x + 0.125", format!("{:.3}", poly));
+        assert_eq!("0.7x^2 - x + 0.1", format!("{:.1}", poly));
+        assert_eq!("0.00", format!("{:.2}", Polynomial::zero()));
+    }
+
+    #[test]
+    fn display_alternate_uses_latex_format() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
+        assert_eq!("x^{2} + 2x - 3", format!("{:#}", poly));
+    }
+
+    #[test]
+    fn display_respects_width_and_alignment() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, -3.0]);
+        assert_eq!("x - 3   ", format!("{:8}", poly));
+        assert_eq!("   x - 3", format!("{:>8}", poly));
+        assert_eq!("*x - 3**", format!("{:*^8}", poly));
+        assert_eq!("x - 3", format!("{:3}", poly));
+        assert_eq!("x - 3.00  ", format!("{:<10.2}", poly));
+    }
+
+    #[test]
+    fn format_with_concise_uses_caret_for_multi_digit_powers() {
+        let mut poly = Polynomial::zero();
+        poly.set_coefficient_at(23, 12.0);
+        poly.set_coefficient_at(10, -1.0);
+        poly.set_coefficient_at(9, 2.0);
+        assert_eq!("12x^23 - x^10 + 2x9", poly.format_with(PolynomialFormat::Concise));
+    }
+
+    #[test]
+    fn format_opts_custom_variable() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, 3.0, 2.0]);
+        let options = FormatOptions::new().variable("s");
+        assert_eq!("s^2 + 3s + 2", poly.format_opts(&options));
+
+        let options = FormatOptions::from(PolynomialFormat::Latex).variable("t");
+        assert_eq!("t^{2} + 3t + 2", poly.format_opts(&options));
+    }
+
+    #[test]
+    fn latex_variables_of_several_characters() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, 3.0, 2.0]);
+        let latex = |variable| poly.format_opts(&FormatOptions::from(PolynomialFormat::Latex).variable(variable));
+        assert_eq!("s^{2} + 3s + 2", latex("s"));
+        assert_eq!("\\theta^{2} + 3\\theta + 2", latex("\\theta"));
+        assert_eq!("x_1^{2} + 3x_1 + 2", latex("x_1"));
+        assert_eq!("x_{12}^{2} + 3x_{12} + 2", latex("x_12"));
+        assert_eq!("\\mathit{theta}^{2} + 3\\mathit{theta} + 2", latex("theta"));
+        assert_eq!("v_{\\max}^{2} + 3v_{\\max} + 2", latex("v_\\max"));
+
+        // The other formats write the name as it is
+        assert_eq!("x_12^2 + 3x_12 + 2", poly.format_opts(&FormatOptions::new().variable("x_12")));
+        assert_eq!("theta^2 + 3theta + 2", poly.format_opts(&FormatOptions::new().variable("theta")));
+        assert_eq!("x^2 + 3x + 2", poly.to_string());
+    }
+
+    #[test]
+    fn latex_control_word_variable_after_control_words() {
+        let poly = Polynomial::from_coefficients(&vec![f64::INFINITY, 2.0, 0.0]);
+        let options = FormatOptions::from(PolynomialFormat::Latex).variable("\\theta").multiplication_sign("\\cdot");
+        assert_eq!("\\infty\\cdot \\theta^{2} + 2\\cdot \\theta", poly.format_opts(&options));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid variable name '2t'.")]
+    fn format_opts_invalid_variable() {
+        let _ = FormatOptions::new().variable("2t");
+    }
+
+    #[test]
+    fn format_opts_ascending_order() {
+        let poly = Polynomial::from_coefficients(&vec![-2.0, 1.0, -3.0]);
+        let options = FormatOptions::new().term_order(TermOrder::Ascending);
+        assert_eq!("- 3 + x - 2x^2", poly.format_opts(&options));
+
+        let options = options.format(PolynomialFormat::Concise);
+        assert_eq!("- 3 + x - 2x2", poly.format_opts(&options));
+
+        let options = FormatOptions::from(PolynomialFormat::Latex).term_order(TermOrder::Ascending);
+        assert_eq!("- 3 + x - 2x^{2}", poly.format_opts(&options));
+
+        let poly = Polynomial::from_coefficients(&vec![-2.0, 0.0, 1.0, 0.0]);
+        assert_eq!("x - 2x^{3}", poly.format_opts(&options));
+    }
+
+    #[test]
+    fn format_opts_show_zero_terms() {
+        let mut poly = Polynomial::zero();
+        poly.set_coefficient_at(5, 1.0);
+        poly.set_coefficient_at(2, -3.0);
+        let options = FormatOptions::new().show_zero_terms(true);
+        assert_eq!(
+            "x^5 + 0x^4 + 0x^3 - 3x^2 + 0x + 0",
+            poly.format_opts(&options)
+        );
+
+        let options = options.term_order(TermOrder::Ascending);
+        assert_eq!(
+            "0 + 0x - 3x^2 + 0x^3 + 0x^4 + x^5",
+            poly.format_opts(&options)
+        );
+
+        assert_eq!("0", Polynomial::zero().format_opts(&options));
+    }
+
+    #[test]
+    fn format_opts_show_zero_terms_of_a_monomial() {
+        let mut poly = Polynomial::zero();
+        poly.set_coefficient_at(5, 1.0);
+        let format = |format| poly.format_opts(&FormatOptions::from(format).show_zero_terms(true));
+        assert_eq!("x^5 + 0x^4 + 0x^3 + 0x^2 + 0x + 0", format(PolynomialFormat::Standard));
+        assert_eq!("x^{5} + 0x^{4} + 0x^{3} + 0x^{2} + 0x + 0", format(PolynomialFormat::Latex));
+        assert_eq!("x5 + 0x4 + 0x3 + 0x2 + 0x + 0", format(PolynomialFormat::Concise));
+
+        let options = FormatOptions::from(PolynomialFormat::Latex).show_zero_terms(true);
+        assert_eq!("0", Polynomial::zero().format_opts(&options));
+    }
+
+    #[test]
+    fn format_opts_multiplication_sign() {
+        let poly = Polynomial::from_coefficients(&vec![2.0, -1.0, 0.0, 4.0]);
+        let options = FormatOptions::new().multiplication_sign("*");
+        assert_eq!("2*x^3 - x^2 + 4", poly.format_opts(&options));
+    }
+
+    #[test]
+    fn format_with_delegates_to_presets() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
+        for format in [PolynomialFormat::Standard, PolynomialFormat::Latex, PolynomialFormat::Concise] {
+            assert_eq!(poly.format_opts(&FormatOptions::from(format)), poly.format_with(format));
+        }
+        assert_eq!(FormatOptions::new(), FormatOptions::default());
+    }
+
+    #[test]
+    fn format_opts_precision() {
+        let poly = Polynomial::from_coefficients(&vec![1.0 / 3.0, -2.0 / 3.0, 1.0]);
+        let options = FormatOptions::new().precision(3);
+        assert_eq!("0.333x^2 - 0.667x + 1.000", poly.format_opts(&options));
+
+        let options = FormatOptions::new().significant_digits(3);
+        assert_eq!("0.333x^2 - 0.667x + 1", poly.format_opts(&options));
+    }
+
+    #[test]
+    fn format_opts_significant_digits() {
+        let poly = Polynomial::from_coefficients(&vec![1.0000000000000002, -2.9999999999999996, 0.012345]);
+        let options = FormatOptions::new().significant_digits(2);
+        assert_eq!("x^2 - 3x + 0.012", poly.format_opts(&options));
+
+        let poly = Polynomial::from_coefficients(&vec![98765.0, 0.5]);
+        let options = FormatOptions::new().significant_digits(1);
+        assert_eq!("100000x + 0.5", poly.format_opts(&options));
+    }
+
+    #[test]
+    fn format_opts_rounding_to_one_omits_coefficient() {
+        let poly = Polynomial::from_coefficients(&vec![0.99999, -1.00001, 0.99999]);
+        let options = FormatOptions::new().precision(2);
+        assert_eq!("x^2 - x + 1.00", poly.format_opts(&options));
+
+        let options = FormatOptions::new().significant_digits(3);
+        assert_eq!("x^2 - x + 1", poly.format_opts(&options));
+    }
+
+    #[test]
+    fn format_opts_rounded_zeros() {
+        let poly = Polynomial::from_coefficients(&vec![2.0, -0.0001, 0.0004]);
+        let options = FormatOptions::new().precision(2);
+        assert_eq!("2.00x^2", poly.format_opts(&options));
+
+        let options = options.keep_rounded_zeros(true);
+        assert_eq!("2.00x^2 + 0.00x + 0.00", poly.format_opts(&options));
+
+        let poly = Polynomial::from_coefficients(&vec![0.001, 0.0]);
+        let options = FormatOptions::new().precision(1);
+        assert_eq!("0.0", poly.format_opts(&options));
+    }
+
+    #[test]
+    fn latex_control_word_multiplication_sign() {
+        let poly = Polynomial::from_coefficients(&vec![-0.5, 1.0, 2.0, 0.0]);
+        let options = FormatOptions::from(PolynomialFormat::Latex).multiplication_sign("\\cdot");
+        assert_eq!("- 0.5\\cdot x^{3} + x^{2} + 2\\cdot x", poly.format_opts(&options));
+
+        let options = options.multiplication_sign(" \\cdot ");
+        assert_eq!("- 0.5 \\cdot x^{3} + x^{2} + 2 \\cdot x", poly.format_opts(&options));
+
+        let options = options.multiplication_sign("\\,");
+        assert_eq!("- 0.5\\,x^{3} + x^{2} + 2\\,x", poly.format_opts(&options));
+    }
+
+    #[test]
+    fn latex_non_finite_coefficients() {
+        let mut poly = Polynomial::zero();
+        poly.set_coefficient_at(2, f64::NEG_INFINITY);
+        poly.set_coefficient_at(0, f64::INFINITY);
+        assert_eq!("- \\infty x^{2} + \\infty", poly.format_with(PolynomialFormat::Latex));
+        assert_eq!("- inf x^2 + inf", poly.to_string());
+    }
+
+    /// A fixed-size writer, which fails instead of growing.
+    struct FixedBuffer<const N: usize> {
+        bytes: [u8; N],
+        len: usize,
+    }
+
+    impl<const N: usize> fmt::Write for FixedBuffer<N> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn format_into_fixed_buffers() {
+        let poly = Polynomial::from_coefficients(&vec![1.0 / 3.0, 0.0, -2.0, 1.0]);
+        let options = FormatOptions::new().precision(2).show_zero_terms(true);
+        let expected = "0.33x^3 + 0.00x^2 - 2.00x + 1.00";
+
+        let mut exact = FixedBuffer::<32> { bytes: [0; 32], len: 0 };
+        poly.format_into(&mut exact, &options).unwrap();
+        assert_eq!(expected, std::str::from_utf8(&exact.bytes[..exact.len]).unwrap());
+
+        let mut oversized = FixedBuffer::<64> { bytes: [0; 64], len: 0 };
+        poly.format_into(&mut oversized, &options).unwrap();
+        assert_eq!(expected, std::str::from_utf8(&oversized.bytes[..oversized.len]).unwrap());
+
+        let mut undersized = FixedBuffer::<31> { bytes: [0; 31], len: 0 };
+        assert_eq!(Err(fmt::Error), poly.format_into(&mut undersized, &options));
+    }
+
+    #[test]
+    fn rounding_beyond_the_buffer_keeps_the_value() {
+        for value in [0.1, -f64::MAX, f64::MIN_POSITIVE, 5e-324] {
+            assert_eq!(value, Rounding::DecimalPlaces(1100).round(value));
+            assert_eq!(value, Rounding::DecimalPlaces(5000).round(value));
+            assert_eq!(value, Rounding::SignificantDigits(5000).round(value));
+        }
+        assert_eq!(0.12, Rounding::DecimalPlaces(2).round(0.123));
+        assert_eq!(1.2e300, Rounding::SignificantDigits(2).round(1.234e300));
+    }
+
+    /// A writer which accepts a limited number of bytes and then fails.
+    struct FailingWriter {
+        remaining: usize,
+    }
+
+    impl std::io::Write for FailingWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            if self.remaining == 0 {
+                return Err(std::io::Error::new(std::io::ErrorKind::BrokenPipe, "closed"));
+            }
+            let written = buf.len().min(self.remaining);
+            self.remaining -= written;
+            Ok(written)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn write_formatted_streams_large_polynomial() {
+        let mut poly = Polynomial::zero();
+        for power in (0..100_000).step_by(7) {
+            poly.set_coefficient_at(power, (power % 13) as f64 - 6.5);
+        }
+        let mut output = Vec::new();
+        poly.write_formatted(&mut output, &FormatOptions::new()).unwrap();
+        assert_eq!(poly.to_string(), String::from_utf8(output).unwrap());
+
+        let mut output = Vec::new();
+        let options = FormatOptions::from(PolynomialFormat::Latex).term_order(TermOrder::Ascending);
+        poly.write_formatted(&mut output, &options).unwrap();
+        assert_eq!(poly.format_opts(&options), String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn write_formatted_propagates_io_errors() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, 2.0, 3.0, 4.0]);
+        let mut writer = FailingWriter { remaining: 5 };
+        let error = poly.write_formatted(&mut writer, &FormatOptions::new()).unwrap_err();
+        assert_eq!(std::io::ErrorKind::BrokenPipe, error.kind());
+        assert_eq!("closed", error.to_string());
+    }
+
+    /// Checks that the braces are balanced and the environments are closed.
+    fn assert_well_formed_latex(latex: &str) {
+        let mut depth = 0;
+        for c in latex.chars() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {}
+            }
+            assert!(depth >= 0, "{latex}");
+        }
+        assert_eq!(0, depth, "{latex}");
+        assert_eq!(latex.matches("\\begin{").count(), latex.matches("\\end{").count());
+    }
+
+    fn degree_40() -> Polynomial {
+        let coefficients: Vec<f64> = (0..=40).map(|i| if i % 3 == 0 { -(i as f64) - 1.0 } else { 1.0 }).collect();
+        Polynomial::from_coefficients(&coefficients)
+    }
+
+    #[test]
+    fn latex_terms_per_line() {
+        let options = FormatOptions::from(PolynomialFormat::Latex).terms_per_line(12);
+        let latex = degree_40().format_opts(&options);
+        assert_well_formed_latex(&latex);
+        assert_eq!(concat!(
+            "\\begin{aligned}\n",
+            "&- x^{40} + x^{39} + x^{38} - 4x^{37} + x^{36} + x^{35} - 7x^{34} + x^{33} + x^{32} - 10x^{31} + x^{30} + x^{29} \\\\\n",
+            "&\\quad {} - 13x^{28} + x^{27} + x^{26} - 16x^{25} + x^{24} + x^{23} - 19x^{22} + x^{21} + x^{20} - 22x^{19} + x^{18} + x^{17} \\\\\n",
+            "&\\quad {} - 25x^{16} + x^{15} + x^{14} - 28x^{13} + x^{12} + x^{11} - 31x^{10} + x^{9} + x^{8} - 34x^{7} + x^{6} + x^{5} \\\\\n",
+            "&\\quad {} - 37x^{4} + x^{3} + x^{2} - 40x + 1\n",
+            "\\end{aligned}",
+        ), latex);
+    }
+
+    #[test]
+    fn latex_line_width() {
+        let options = FormatOptions::from(PolynomialFormat::Latex).line_width(60);
+        let latex = degree_40().format_opts(&options);
+        assert_well_formed_latex(&latex);
+        for line in latex.lines().filter(|line| line.starts_with('&')) {
+            let line = line.trim_start_matches("&\\quad {}").trim_start_matches('&').trim_end_matches(" \\\\");
+            assert!(line.chars().count() <= 60, "{line}");
+        }
+        assert_eq!(
+            "\\begin{aligned}\n&x^{2} \\\\\n&\\quad {} + 1\n\\end{aligned}",
+            Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]).format_opts(&FormatOptions::from(PolynomialFormat::Latex).line_width(0))
+        );
+    }
+
+    #[test]
+    fn latex_elided_terms() {
+        let options = FormatOptions::from(PolynomialFormat::Latex).max_terms(5);
+        assert_eq!(
+            "- x^{40} + x^{39} + x^{38} + \\underbrace{\\cdots}_{36\\text{ terms}} - 40x + 1",
+            degree_40().format_opts(&options)
+        );
+
+        let options = options.term_order(TermOrder::Ascending).terms_per_line(3);
+        let latex = degree_40().format_opts(&options);
+        assert_well_formed_latex(&latex);
+        assert_eq!(concat!(
+            "\\begin{aligned}\n",
+            "&1 - 40x + x^{2} \\\\\n",
+            "&\\quad {} + \\underbrace{\\cdots}_{36\\text{ terms}} + x^{39} - x^{40}\n",
+            "\\end{aligned}",
+        ), latex);
+
+        // A polynomial within the limit is written whole
+        let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 3.0]);
+        assert_eq!("x^{2} - 2x + 3", poly.format_opts(&FormatOptions::from(PolynomialFormat::Latex).max_terms(3)));
+    }
+
+    #[test]
+    fn line_breaking_is_latex_only() {
+        let poly = degree_40();
+        let options = FormatOptions::new().terms_per_line(2).line_width(10).max_terms(2);
+        assert_eq!(poly.to_string(), poly.format_opts(&options));
+        assert!(!poly.format_with(PolynomialFormat::Latex).contains('\n'));
+
+        let options = FormatOptions::from(PolynomialFormat::Latex).terms_per_line(2).max_terms(2);
+        assert_eq!("0", Polynomial::zero().format_opts(&options));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid number of terms per line")]
+    fn zero_terms_per_line() {
+        FormatOptions::new().terms_per_line(0);
+    }
+}
@@ -0,0 +1,297 @@
+//! Module containing the compact binary encoding of polynomials.
+//!
+//! The encoding does not depend on serde and is stable between the versions of the crate. It
+//! consists of:
+//!
+//! - the format version byte, currently `1`,
+//! - the number of terms as an unsigned LEB128 varint,
+//! - the terms in ascending order of powers, each as the power in an unsigned LEB128 varint
+//!   followed by the coefficient as a little-endian `f64`.
+//!
+//! Only the non-zero coefficients are stored, so the zero polynomial is encoded as `[1, 0]`.
+use std::collections::btree_map::Entry;
+use std::error::Error;
+use std::fmt;
+use super::Polynomial;
+
+/// The version of the encoding written by [`Polynomial::to_bytes`].
+const VERSION: u8 = 1;
+
+/// Error returned when a byte slice cannot be decoded into a [`Polynomial`].
+///
+/// The offsets are the positions in the decoded slice at which the offending item starts.
+#[derive(PartialEq, Debug, Clone)]
+pub enum DecodeError {
+    /// The version byte is not a supported version of the encoding.
+    UnsupportedVersion {
+        version: u8,
+    },
+
+    /// The input ends in the middle of the encoded polynomial.
+    UnexpectedEnd,
+
+    /// A varint is longer than its type allows, or a power does not fit into `u32`.
+    VarintOverflow {
+        offset: usize,
+    },
+
+    /// A power appears in more than one term.
+    DuplicatePower {
+        power: u32,
+        offset: usize,
+    },
+
+    /// A term has a zero coefficient, which is never written by the encoder.
+    ZeroCoefficient {
+        power: u32,
+        offset: usize,
+    },
+
+    /// Bytes remain after the last term.
+    TrailingBytes {
+        offset: usize,
+    },
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::UnsupportedVersion { version } => {
+                write!(f, "Unsupported encoding version {version}.")
+            }
+            DecodeError::UnexpectedEnd => write!(f, "Unexpected end of input."),
+            DecodeError::VarintOverflow { offset } => {
+                write!(f, "Varint overflow at byte {offset}.")
+            }
+            DecodeError::DuplicatePower { power, offset } => {
+                write!(f, "Duplicate power {power} at byte {offset}.")
+            }
+            DecodeError::ZeroCoefficient { power, offset } => {
+                write!(f, "Zero coefficient of power {power} at byte {offset}.")
+            }
+            DecodeError::TrailingBytes { offset } => {
+                write!(f, "Trailing bytes at byte {offset}.")
+            }
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+fn write_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+/// Reads the encoded items and keeps track of the position in the input.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    fn read_byte(&mut self) -> Result<u8, DecodeError> {
+        let byte = *self.bytes.get(self.offset).ok_or(DecodeError::UnexpectedEnd)?;
+        self.offset += 1;
+        Ok(byte)
+    }
+
+    fn read_varint(&mut self) -> Result<u64, DecodeError> {
+        let start = self.offset;
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_byte()?;
+            let bits = u64::from(byte & 0x7f);
+            if bits << shift >> shift != bits {
+                return Err(DecodeError::VarintOverflow { offset: start });
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(DecodeError::VarintOverflow { offset: start })
+    }
+
+    fn read_f64(&mut self) -> Result<f64, DecodeError> {
+        let end = self.offset + 8;
+        let bytes = self.bytes.get(self.offset..end).ok_or(DecodeError::UnexpectedEnd)?;
+        self.offset = end;
+        Ok(f64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+}
+
+impl Polynomial {
+    /// Encodes the polynomial into the compact binary format described in the
+    /// [`encoding`](crate::encoding) module.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![2.0, 0.0, 0.0, -1.0]);
+    /// let bytes = poly.to_bytes();
+    /// assert_eq!(20, bytes.len());
+    /// assert_eq!(poly, Polynomial::from_bytes(&bytes).unwrap());
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 + self.coefficients.len() * 10);
+        bytes.push(VERSION);
+        write_varint(&mut bytes, self.coefficients.len() as u64);
+        for (power, coefficient) in self.coefficients.iter() {
+            write_varint(&mut bytes, u64::from(*power));
+            bytes.extend_from_slice(&coefficient.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decodes a polynomial encoded with the [`to_bytes`](Polynomial::to_bytes) method.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DecodeError`] if the version is not supported, the input is truncated or
+    /// followed by other bytes, or the terms are not canonical, i.e. a power is repeated or a
+    /// coefficient is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{DecodeError, Polynomial};
+    ///
+    /// let bytes = Polynomial::from_coefficients(&vec![1.0, 1.0]).to_bytes();
+    /// assert_eq!(Err(DecodeError::UnexpectedEnd), Polynomial::from_bytes(&bytes[..5]));
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Polynomial, DecodeError> {
+        let mut reader = Reader { bytes, offset: 0 };
+        let version = reader.read_byte()?;
+        if version != VERSION {
+            return Err(DecodeError::UnsupportedVersion { version });
+        }
+
+        let terms = reader.read_varint()?;
+        let mut poly = Polynomial::zero();
+        for _ in 0..terms {
+            let offset = reader.offset;
+            let power = u32::try_from(reader.read_varint()?)
+                .map_err(|_| DecodeError::VarintOverflow { offset })?;
+            let coefficient = reader.read_f64()?;
+            if coefficient == 0.0 {
+                return Err(DecodeError::ZeroCoefficient { power, offset });
+            }
+            match poly.coefficients.entry(power) {
+                Entry::Occupied(_) => return Err(DecodeError::DuplicatePower { power, offset }),
+                Entry::Vacant(entry) => {
+                    entry.insert(coefficient);
+                }
+            }
+        }
+
+        if reader.offset != bytes.len() {
+            return Err(DecodeError::TrailingBytes { offset: reader.offset });
+        }
+        Ok(poly)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DecodeError;
+    use crate::Polynomial;
+
+    #[test]
+    fn golden_bytes() {
+        let poly = Polynomial::from_coefficients(&vec![2.0, 0.0, 0.0, -1.0]);
+        let bytes = [
+            1, 2,
+            0, 0, 0, 0, 0, 0, 0, 0xf0, 0xbf,
+            3, 0, 0, 0, 0, 0, 0, 0, 0x40,
+        ];
+        assert_eq!(bytes.to_vec(), poly.to_bytes());
+        assert_eq!(poly, Polynomial::from_bytes(&bytes).unwrap());
+        assert_eq!(vec![1, 0], Polynomial::zero().to_bytes());
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut sparse = Polynomial::zero();
+        sparse.set_coefficient_at(u32::MAX, 1e-300);
+        sparse.set_coefficient_at(200, -0.5);
+        let polys = [
+            Polynomial::zero(),
+            Polynomial::from_coefficients(&vec![f64::MIN_POSITIVE]),
+            Polynomial::from_coefficients(&vec![1.5, -2.0, 0.0, 3.25, 1e10]),
+            sparse,
+        ];
+        for poly in polys {
+            assert_eq!(poly, Polynomial::from_bytes(&poly.to_bytes()).unwrap());
+        }
+    }
+
+    #[test]
+    fn truncated_input_is_rejected() {
+        let bytes = Polynomial::from_coefficients(&vec![1.0, 0.0, 2.0, 3.0]).to_bytes();
+        for len in 0..bytes.len() {
+            assert_eq!(Err(DecodeError::UnexpectedEnd), Polynomial::from_bytes(&bytes[..len]));
+        }
+    }
+
+    #[test]
+    fn corrupted_input_is_rejected() {
+        let one = 1.0f64.to_le_bytes();
+        let zero = 0.0f64.to_le_bytes();
+        let negative_zero = (-0.0f64).to_le_bytes();
+
+        assert_eq!(
+            Err(DecodeError::UnsupportedVersion { version: 2 }),
+            Polynomial::from_bytes(&[2, 0])
+        );
+        assert_eq!(
+            Err(DecodeError::TrailingBytes { offset: 2 }),
+            Polynomial::from_bytes(&[1, 0, 0])
+        );
+
+        let duplicate = [&[1, 2, 5][..], &one, &[5], &one].concat();
+        assert_eq!(
+            Err(DecodeError::DuplicatePower { power: 5, offset: 11 }),
+            Polynomial::from_bytes(&duplicate)
+        );
+
+        for zero in [zero, negative_zero] {
+            let bytes = [&[1, 1, 3][..], &zero].concat();
+            assert_eq!(
+                Err(DecodeError::ZeroCoefficient { power: 3, offset: 2 }),
+                Polynomial::from_bytes(&bytes)
+            );
+        }
+
+        let power_overflow = [&[1, 1, 0x80, 0x80, 0x80, 0x80, 0x10][..], &one].concat();
+        assert_eq!(
+            Err(DecodeError::VarintOverflow { offset: 2 }),
+            Polynomial::from_bytes(&power_overflow)
+        );
+        let count_overflow = [1, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f];
+        assert_eq!(
+            Err(DecodeError::VarintOverflow { offset: 1 }),
+            Polynomial::from_bytes(&count_overflow)
+        );
+    }
+
+    #[test]
+    fn huge_term_count_does_not_allocate() {
+        let bytes = [1, 0xff, 0xff, 0xff, 0xff, 0x0f];
+        assert_eq!(Err(DecodeError::UnexpectedEnd), Polynomial::from_bytes(&bytes));
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            "Duplicate power 5 at byte 11.",
+            DecodeError::DuplicatePower { power: 5, offset: 11 }.to_string()
+        );
+        assert_eq!("Unexpected end of input.", DecodeError::UnexpectedEnd.to_string());
+    }
+}
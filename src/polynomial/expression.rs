@@ -0,0 +1,231 @@
+//! Module containing methods for exporting a polynomial as an expression in a programming
+//! language.
+use std::fmt;
+use super::Polynomial;
+
+/// Specifies the target language of the `Polynomial.to_expression` method.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum ExpressionLang {
+    /// Rust expression using `f64::powi`, e.g. `2.0 * x.powi(3) - x + 5.0`.
+    Rust,
+
+    /// Python expression using the `**` operator, e.g. `2.0 * x**3 - x + 5.0`.
+    Python,
+
+    /// C expression using the `pow` function from `math.h`, e.g. `2.0*pow(x,3) - x + 5.0`.
+    C,
+
+    /// Mathematica expression, e.g. `2.0*x^3 - x + 5.0`.
+    Mathematica
+}
+
+impl Polynomial {
+    /// Returns the polynomial as an expression in the given [language](ExpressionLang),
+    /// using `x` as the variable name.
+    ///
+    /// The coefficients are written with the shortest representation that parses back to
+    /// the same `f64` value, so no precision is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{ExpressionLang, Polynomial};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![2.0, 0.0, -1.0, 5.0]);
+    /// assert_eq!("2.0 * x.powi(3) - x + 5.0", poly.to_expression(ExpressionLang::Rust));
+    /// assert_eq!("2.0 * x**3 - x + 5.0", poly.to_expression(ExpressionLang::Python));
+    /// assert_eq!("2.0*pow(x,3) - x + 5.0", poly.to_expression(ExpressionLang::C));
+    /// assert_eq!("2.0*x^3 - x + 5.0", poly.to_expression(ExpressionLang::Mathematica));
+    /// ```
+    pub fn to_expression(&self, lang: ExpressionLang) -> String {
+        self.to_expression_with_variable(lang, "x")
+    }
+
+    /// Returns the polynomial as an expression in the given [language](ExpressionLang),
+    /// using the given variable name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{ExpressionLang, Polynomial};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![-1.0, 0.5, 0.0]);
+    /// assert_eq!("-t**2 + 0.5 * t", poly.to_expression_with_variable(ExpressionLang::Python, "t"));
+    /// ```
+    pub fn to_expression_with_variable(&self, lang: ExpressionLang, variable: &str) -> String {
+        let mut buffer = String::new();
+        self.write_expression(&mut buffer, lang, variable).expect("Writing to a String cannot fail");
+        buffer
+    }
+
+    fn write_expression(
+        &self,
+        f: &mut dyn fmt::Write,
+        lang: ExpressionLang,
+        variable: &str
+    ) -> fmt::Result {
+        // Handle the zero polynomial case
+        if self.is_zero() {
+            return write_literal(f, 0.0, lang);
+        }
+
+        for (index, (power, coefficient)) in self.coefficients.iter().rev().enumerate() {
+            let is_negative = *coefficient < 0.0;
+
+            // Write the sign of the term
+            if index == 0 && is_negative {
+                f.write_char('-')?;
+            } else if index != 0 {
+                f.write_str(if is_negative { " - " } else { " + " })?;
+            }
+
+            // Write the coefficient if it's not 1, or it's the term of degree 0
+            if *power == 0 {
+                write_literal(f, coefficient.abs(), lang)?;
+                continue;
+            }
+            if coefficient.abs() != 1.0 {
+                write_literal(f, coefficient.abs(), lang)?;
+                match lang {
+                    ExpressionLang::Rust | ExpressionLang::Python => f.write_str(" * ")?,
+                    ExpressionLang::C | ExpressionLang::Mathematica => f.write_char('*')?,
+                }
+            }
+
+            // Write the variable raised to the power
+            if *power == 1 {
+                f.write_str(variable)?;
+                continue;
+            }
+            match lang {
+                ExpressionLang::Rust => write!(f, "{variable}.powi({power})")?,
+                ExpressionLang::Python => write!(f, "{variable}**{power}")?,
+                ExpressionLang::C => write!(f, "pow({variable},{power})")?,
+                ExpressionLang::Mathematica => write!(f, "{variable}^{power}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes a non-negative floating point literal in the given language.
+fn write_literal(f: &mut dyn fmt::Write, value: f64, lang: ExpressionLang) -> fmt::Result {
+    if value.is_nan() {
+        return f.write_str(match lang {
+            ExpressionLang::Rust => "f64::NAN",
+            ExpressionLang::Python => "float('nan')",
+            ExpressionLang::C => "NAN",
+            ExpressionLang::Mathematica => "Indeterminate",
+        });
+    }
+    if value.is_infinite() {
+        return f.write_str(match lang {
+            ExpressionLang::Rust => "f64::INFINITY",
+            ExpressionLang::Python => "float('inf')",
+            ExpressionLang::C => "INFINITY",
+            ExpressionLang::Mathematica => "Infinity",
+        });
+    }
+
+    // The Debug representation is the shortest one that round-trips, and it always contains
+    // either a decimal point or an exponent
+    let literal = format!("{value:?}");
+    match lang {
+        ExpressionLang::Mathematica => f.write_str(&literal.replace('e', "*^")),
+        _ => f.write_str(&literal),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExpressionLang, Polynomial};
+
+    const LANGS: [ExpressionLang; 4] = [
+        ExpressionLang::Rust,
+        ExpressionLang::Python,
+        ExpressionLang::C,
+        ExpressionLang::Mathematica,
+    ];
+
+    fn expressions(poly: &Polynomial) -> Vec<String> {
+        LANGS.iter().map(|lang| poly.to_expression(*lang)).collect()
+    }
+
+    #[test]
+    fn general_case() {
+        let poly = Polynomial::from_coefficients(&vec![2.0, 0.0, -1.0, 5.0]);
+        assert_eq!(
+            vec![
+                "2.0 * x.powi(3) - x + 5.0",
+                "2.0 * x**3 - x + 5.0",
+                "2.0*pow(x,3) - x + 5.0",
+                "2.0*x^3 - x + 5.0",
+            ],
+            expressions(&poly)
+        );
+    }
+
+    #[test]
+    fn negative_leading_term() {
+        let poly = Polynomial::from_coefficients(&vec![-3.5, 0.0, 0.0, -2.0]);
+        assert_eq!(
+            vec![
+                "-3.5 * x.powi(3) - 2.0",
+                "-3.5 * x**3 - 2.0",
+                "-3.5*pow(x,3) - 2.0",
+                "-3.5*x^3 - 2.0",
+            ],
+            expressions(&poly)
+        );
+    }
+
+    #[test]
+    fn coefficient_exactly_one() {
+        let poly = Polynomial::from_coefficients(&vec![-1.0, 1.0, 1.0]);
+        assert_eq!(
+            vec![
+                "-x.powi(2) + x + 1.0",
+                "-x**2 + x + 1.0",
+                "-pow(x,2) + x + 1.0",
+                "-x^2 + x + 1.0",
+            ],
+            expressions(&poly)
+        );
+    }
+
+    #[test]
+    fn constant_and_zero_polynomials() {
+        let poly = Polynomial::from_coefficients(&vec![-4.0]);
+        assert_eq!(vec!["-4.0"; 4], expressions(&poly));
+        assert_eq!(vec!["0.0"; 4], expressions(&Polynomial::zero()));
+    }
+
+    #[test]
+    fn full_precision_literals() {
+        let poly = Polynomial::from_coefficients(&vec![1.0 / 3.0, 1e-7, 1e20]);
+        assert_eq!(
+            "0.3333333333333333 * x**2 + 1e-7 * x + 1e20",
+            poly.to_expression(ExpressionLang::Python)
+        );
+        assert_eq!(
+            "0.3333333333333333*x^2 + 1*^-7*x + 1*^20",
+            poly.to_expression(ExpressionLang::Mathematica)
+        );
+        let literal = poly.to_expression(ExpressionLang::Rust);
+        let first_coefficient: f64 = literal.split(' ').next().unwrap().parse().unwrap();
+        assert_eq!(1.0 / 3.0, first_coefficient);
+    }
+
+    #[test]
+    fn custom_variable() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 0.0]);
+        assert_eq!(
+            "t.powi(2) - 2.0 * t",
+            poly.to_expression_with_variable(ExpressionLang::Rust, "t")
+        );
+        assert_eq!(
+            "pow(time,2) - 2.0*time",
+            poly.to_expression_with_variable(ExpressionLang::C, "time")
+        );
+    }
+}
@@ -0,0 +1,411 @@
+//! The discrete Fourier transform of the coefficients, i.e. the evaluation at the roots of
+//! unity.
+//!
+//! The complex numbers are `(re, im)` pairs in the public interface. The transform of a length
+//! that is a power of two is computed with the iterative radix-2 algorithm, and the other
+//! lengths are reduced to it with Bluestein's algorithm.
+
+use std::f64::consts::PI;
+use super::Polynomial;
+use super::complex::Complex;
+
+/// The direction of the transform, i.e. the sign of the exponent of `e^(±2πi jk / n)`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub(super) enum Direction {
+    /// The evaluation at the roots of unity `e^(2πi k / n)`.
+    Forward,
+    /// The transform back to the coefficients, without the normalization by `1 / n`.
+    Inverse,
+}
+
+impl Direction {
+    fn sign(self) -> f64 {
+        match self {
+            Direction::Forward => 1.0,
+            Direction::Inverse => -1.0,
+        }
+    }
+}
+
+/// Computes `X_k = sum of x_j e^(±2πi jk / n)` in place, for any length.
+pub(super) fn transform(values: &mut [Complex], direction: Direction) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+    if n.is_power_of_two() {
+        transform_power_of_two(values, direction);
+    } else {
+        transform_bluestein(values, direction);
+    }
+}
+
+fn transform_power_of_two(values: &mut [Complex], direction: Direction) {
+    let n = values.len();
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    // The roots are computed directly rather than as powers, so that the errors don't accumulate
+    let roots: Vec<Complex> = (0..n / 2)
+        .map(|k| Complex::from_angle(direction.sign() * 2.0 * PI * k as f64 / n as f64))
+        .collect();
+    let mut length = 2;
+    while length <= n {
+        let step = n / length;
+        for start in (0..n).step_by(length) {
+            for k in 0..length / 2 {
+                let even = values[start + k];
+                let odd = values[start + k + length / 2] * roots[k * step];
+                values[start + k] = even + odd;
+                values[start + k + length / 2] = even - odd;
+            }
+        }
+        length <<= 1;
+    }
+}
+
+/// Bluestein's algorithm, which writes `jk` as `(j^2 + k^2 - (k - j)^2) / 2` to express the
+/// transform as a convolution, computed with the transforms of a power of two length.
+fn transform_bluestein(values: &mut [Complex], direction: Direction) {
+    let n = values.len();
+    // e^(±πi j^2 / n), with j^2 reduced modulo 2n to keep the angle small
+    let chirp: Vec<Complex> = (0..n)
+        .map(|j| {
+            let square = (j as u128 * j as u128 % (2 * n as u128)) as f64;
+            Complex::from_angle(direction.sign() * PI * square / n as f64)
+        })
+        .collect();
+
+    let size = (2 * n - 1).next_power_of_two();
+    let mut a = vec![Complex::default(); size];
+    for (j, value) in values.iter().enumerate() {
+        a[j] = *value * chirp[j];
+    }
+    let mut b = vec![Complex::default(); size];
+    b[0] = chirp[0].conj();
+    for m in 1..n {
+        b[m] = chirp[m].conj();
+        b[size - m] = chirp[m].conj();
+    }
+
+    transform_power_of_two(&mut a, Direction::Forward);
+    transform_power_of_two(&mut b, Direction::Forward);
+    for (a, b) in a.iter_mut().zip(&b) {
+        *a = *a * *b;
+    }
+    transform_power_of_two(&mut a, Direction::Inverse);
+    for (k, value) in values.iter_mut().enumerate() {
+        *value = a[k].scale(1.0 / size as f64) * chirp[k];
+    }
+}
+
+impl Polynomial {
+    /// Returns the values `P(ω^0), ..., P(ω^(n - 1))` of the polynomial at the `n`-th roots of
+    /// unity, where `ω = e^(2πi / n)`, as `(re, im)` pairs, computed with the fast Fourier
+    /// transform.
+    ///
+    /// The terms of degree at least `n` are folded onto the lower ones, since `ω^n = 1`. Any
+    /// `n` is supported, the lengths other than powers of two with Bluestein's algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // x^2 + 1 at 1, i, -1 and -i
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]);
+    /// let values = poly.evaluate_at_roots_of_unity(4);
+    /// assert_eq!(vec![(2.0, 0.0), (0.0, 0.0), (2.0, 0.0), (0.0, 0.0)], values);
+    /// ```
+    pub fn evaluate_at_roots_of_unity(&self, n: usize) -> Vec<(f64, f64)> {
+        if n == 0 {
+            return Vec::new();
+        }
+        let mut values = vec![Complex::default(); n];
+        for (power, coefficient) in &self.coefficients {
+            values[*power as usize % n].re += coefficient;
+        }
+        transform(&mut values, Direction::Forward);
+        values.into_iter().map(|value| (value.re, value.im)).collect()
+    }
+
+    /// Returns the complex coefficients `a_0, ..., a_(n - 1)` in the ascending order of powers
+    /// of the polynomial of degree less than `n` with the given values at the `n`-th roots of
+    /// unity `ω^0, ..., ω^(n - 1)`, where `ω = e^(2πi / n)`, computed with the inverse fast
+    /// Fourier transform.
+    ///
+    /// This is the inverse of [`evaluate_at_roots_of_unity`](Polynomial::evaluate_at_roots_of_unity)
+    /// up to the rounding errors. Use
+    /// [`from_evaluations_at_roots_of_unity`](Polynomial::from_evaluations_at_roots_of_unity)
+    /// for the values of a polynomial with real coefficients.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // The values of i x at 1 and -1
+    /// let coefficients = Polynomial::coefficients_from_evaluations_at_roots_of_unity(&[(0.0, 1.0), (0.0, -1.0)]);
+    /// assert_eq!(vec![(0.0, 0.0), (0.0, 1.0)], coefficients);
+    /// ```
+    pub fn coefficients_from_evaluations_at_roots_of_unity(values: &[(f64, f64)]) -> Vec<(f64, f64)> {
+        let mut coefficients: Vec<Complex> = values.iter().map(|(re, im)| Complex::new(*re, *im)).collect();
+        transform(&mut coefficients, Direction::Inverse);
+        let factor = 1.0 / values.len() as f64;
+        coefficients.into_iter().map(|value| (value.re * factor, value.im * factor)).collect()
+    }
+
+    /// Returns the polynomial of degree less than `n` with the given values at the `n`-th roots
+    /// of unity, or `None` if its coefficients are not real.
+    ///
+    /// The coefficients are computed with
+    /// [`coefficients_from_evaluations_at_roots_of_unity`](Polynomial::coefficients_from_evaluations_at_roots_of_unity),
+    /// and their imaginary parts are dropped if none of them exceeds the tolerance relative to
+    /// the largest magnitude of a coefficient. The real parts keep their rounding errors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tolerance is negative or `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![3.0, -2.0, 1.0]);
+    /// let values = poly.evaluate_at_roots_of_unity(3);
+    /// let restored = Polynomial::from_evaluations_at_roots_of_unity(&values, 1e-12).unwrap();
+    /// assert!((restored - &poly).get_coefficients().iter().all(|c| c.abs() < 1e-12));
+    ///
+    /// // The values of i x
+    /// assert_eq!(None, Polynomial::from_evaluations_at_roots_of_unity(&[(0.0, 1.0), (0.0, -1.0)], 1e-12));
+    /// ```
+    pub fn from_evaluations_at_roots_of_unity(values: &[(f64, f64)], tolerance: f64) -> Option<Polynomial> {
+        assert!(tolerance >= 0.0, "Invalid tolerance");
+        let coefficients = Polynomial::coefficients_from_evaluations_at_roots_of_unity(values);
+        let max_magnitude = coefficients.iter().map(|(re, im)| re.hypot(*im)).fold(0.0, f64::max);
+        if coefficients.iter().any(|(_, im)| im.abs() > tolerance * max_magnitude) {
+            return None;
+        }
+        let mut poly = Polynomial::zero();
+        for (power, (re, _)) in coefficients.into_iter().enumerate() {
+            poly.set_coefficient_at(power as u32, re);
+        }
+        Some(poly)
+    }
+
+    /// Multiplies the polynomials with the fast Fourier transform, which takes `O(n log n)`
+    /// time instead of the `O(n^2)` of the multiplication operator for dense polynomials of
+    /// degree `n`.
+    ///
+    /// The coefficients of the product have rounding errors relative to the largest ones, and
+    /// the coefficients which are negligible are treated as zero, as by
+    /// [`mul_fft_with_tolerance`](Polynomial::mul_fft_with_tolerance) with the tolerance
+    /// `1e-13`. The transforms are as long as the degree of the product, so the sparse
+    /// polynomials of high degree should be multiplied with the operator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let p = Polynomial::from_coefficients(&vec![1.0, 2.0, 3.0]);
+    /// let q = Polynomial::from_coefficients(&vec![1.0, -2.0, 3.0]);
+    /// let product = p.mul_fft(&q);
+    /// let expected = p.clone() * &q;
+    /// assert_eq!(expected.degree(), product.degree());
+    /// assert!((product - &expected).get_coefficients().iter().all(|c| c.abs() < 1e-12));
+    /// ```
+    pub fn mul_fft(&self, other: &Polynomial) -> Polynomial {
+        self.mul_fft_with_tolerance(other, 1e-13)
+    }
+
+    /// Multiplies the polynomials with the fast Fourier transform, treating the coefficients of
+    /// the product which are at most the tolerance relative to the product of the Euclidean
+    /// norms of the coefficients of the factors as zero.
+    ///
+    /// The product of the norms bounds every coefficient of the product, and the rounding
+    /// errors of the transforms are proportional to it, so the coefficients which should be
+    /// zero are removed rather than left as noise. A true coefficient below the threshold is
+    /// removed as well.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tolerance is negative or `NaN`.
+    pub fn mul_fft_with_tolerance(&self, other: &Polynomial, tolerance: f64) -> Polynomial {
+        assert!(tolerance >= 0.0, "Invalid tolerance");
+        let (Some(degree), Some(other_degree)) = (self.degree(), other.degree()) else {
+            return Polynomial::zero();
+        };
+        let size = (degree as usize + other_degree as usize + 1).next_power_of_two();
+        let dense = |poly: &Polynomial| {
+            let mut values = vec![Complex::default(); size];
+            for (power, coefficient) in &poly.coefficients {
+                values[*power as usize].re = *coefficient;
+            }
+            transform(&mut values, Direction::Forward);
+            values
+        };
+        let mut product = dense(self);
+        for (value, other_value) in product.iter_mut().zip(dense(other)) {
+            *value = *value * other_value;
+        }
+        transform(&mut product, Direction::Inverse);
+
+        let norm = |poly: &Polynomial| poly.coefficients.values().map(|c| c * c).sum::<f64>().sqrt();
+        let threshold = tolerance * norm(self) * norm(other);
+        let mut result = Polynomial::zero();
+        for (power, value) in product.into_iter().enumerate().take(degree as usize + other_degree as usize + 1) {
+            let coefficient = value.re / size as f64;
+            if coefficient.abs() > threshold {
+                result.set_coefficient_at(power as u32, coefficient);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+    use proptest::prelude::*;
+    use super::{transform, Complex, Direction};
+    use crate::{convolve_slices, Polynomial};
+    use crate::polynomial::strategy::polynomials_with;
+
+    /// Evaluates the polynomial at `e^(i angle)` with Horner's method in complex arithmetic.
+    fn evaluate_at_angle(poly: &Polynomial, angle: f64) -> Complex {
+        let x = Complex::new(angle.cos(), angle.sin());
+        (0..=poly.degree().unwrap_or(0))
+            .rev()
+            .fold(Complex::default(), |value, power| value * x + Complex::new(poly.get_coefficient_at(power), 0.0))
+    }
+
+    fn assert_close((re, im): (f64, f64), expected: Complex, tolerance: f64) {
+        assert!((re - expected.re).abs() < tolerance && (im - expected.im).abs() < tolerance, "({re}, {im}) != {expected:?}");
+    }
+
+    #[test]
+    fn forward_transform_matches_evaluation() {
+        let poly = Polynomial::from_coefficients(&vec![0.5, -3.0, 2.0, 0.0, 1.0, -7.0]);
+        for n in [6, 8, 7, 12, 1] {
+            let values = poly.evaluate_at_roots_of_unity(n);
+            assert_eq!(n, values.len());
+            for (k, value) in values.into_iter().enumerate() {
+                let expected = evaluate_at_angle(&poly, 2.0 * PI * k as f64 / n as f64);
+                assert_close(value, expected, 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn high_powers_are_folded() {
+        // x^5 = x^2 at the cube roots of unity
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        let folded = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0]);
+        assert_eq!(folded.evaluate_at_roots_of_unity(3), poly.evaluate_at_roots_of_unity(3));
+        assert!(poly.evaluate_at_roots_of_unity(0).is_empty());
+    }
+
+    #[test]
+    fn bluestein_matches_direct_transform() {
+        for n in [3, 5, 6, 7, 9, 15, 100] {
+            let values: Vec<Complex> = (0..n).map(|j| Complex::new((j as f64).sin(), (j as f64 * 0.3).cos())).collect();
+            let mut transformed = values.clone();
+            transform(&mut transformed, Direction::Inverse);
+            for (k, value) in transformed.into_iter().enumerate() {
+                let expected = values.iter().enumerate().fold(Complex::default(), |sum, (j, x)| {
+                    let angle = -2.0 * PI * ((j * k) % n) as f64 / n as f64;
+                    sum + *x * Complex::new(angle.cos(), angle.sin())
+                });
+                assert_close((value.re, value.im), expected, 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn complex_coefficients_round_trip() {
+        let coefficients = vec![(1.0, 2.0), (-0.5, 0.0), (0.0, -3.0), (4.0, 1.0), (0.25, 0.25)];
+        let mut values: Vec<Complex> = coefficients.iter().map(|(re, im)| Complex::new(*re, *im)).collect();
+        transform(&mut values, Direction::Forward);
+        let values: Vec<(f64, f64)> = values.into_iter().map(|value| (value.re, value.im)).collect();
+        let restored = Polynomial::coefficients_from_evaluations_at_roots_of_unity(&values);
+        for (actual, (re, im)) in restored.into_iter().zip(coefficients) {
+            assert_close(actual, Complex::new(re, im), 1e-12);
+        }
+        assert!(Polynomial::coefficients_from_evaluations_at_roots_of_unity(&[]).is_empty());
+        assert_eq!(Some(Polynomial::zero()), Polynomial::from_evaluations_at_roots_of_unity(&[], 0.0));
+    }
+
+    /// Returns a dense polynomial of the degree with pseudorandom coefficients in `[-1, 1)`.
+    fn pseudorandom(degree: u32, seed: u64) -> Polynomial {
+        let mut state = seed;
+        Polynomial::from_fn(degree, |_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+        })
+    }
+
+    fn assert_products_close(expected: &Polynomial, actual: &Polynomial, tolerance: f64) {
+        assert_eq!(expected.degree(), actual.degree());
+        for power in 0..=expected.degree().unwrap_or(0) {
+            let (expected, actual) = (expected.get_coefficient_at(power), actual.get_coefficient_at(power));
+            assert!((expected - actual).abs() < tolerance, "x^{power}: {expected} != {actual}");
+        }
+    }
+
+    #[test]
+    fn mul_fft_matches_schoolbook_up_to_degree_4096() {
+        for (degree, other_degree) in [(0, 0), (1, 5), (17, 100), (1000, 999), (4096, 4096)] {
+            let (p, q) = (pseudorandom(degree, degree as u64), pseudorandom(other_degree, 7));
+            // The dense schoolbook convolution, much faster than the sparse operator
+            let expected = Polynomial::from_coefficients(&convolve_slices(&p.get_coefficients(), &q.get_coefficients()));
+            assert_products_close(&expected, &p.mul_fft(&q), 1e-10);
+        }
+    }
+
+    #[test]
+    fn mul_fft_removes_the_noise() {
+        // (x - 1)(x^1000 + ... + 1) = x^1001 - 1, all the other coefficients cancel out
+        let ones = Polynomial::from_fn(1000, |_| 1.0);
+        let product = Polynomial::from_coefficients(&vec![1.0, -1.0]).mul_fft(&ones);
+        assert_eq!(2, product.coefficients.len());
+        assert_products_close(&Polynomial::from_fn(1001, |k| if k == 0 { -1.0 } else if k == 1001 { 1.0 } else { 0.0 }), &product, 1e-12);
+
+        let sparse = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(vec![1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0], sparse.mul_fft(&sparse).get_coefficients());
+        assert_eq!(Polynomial::zero(), sparse.mul_fft(&Polynomial::zero()));
+    }
+
+    proptest! {
+        #[test]
+        fn mul_fft_of_integer_polynomials(
+            p in polynomials_with((-9..=9).prop_map(f64::from), 64),
+            q in polynomials_with((-9..=9).prop_map(f64::from), 64),
+        ) {
+            let product = p.mul_fft(&q);
+            let expected = p * &q;
+            prop_assert_eq!(expected.degree(), product.degree());
+            for power in 0..=expected.degree().unwrap_or(0) {
+                prop_assert!((expected.get_coefficient_at(power) - product.get_coefficient_at(power)).abs() < 1e-9);
+            }
+        }
+
+        #[test]
+        fn real_coefficients_round_trip(
+            poly in polynomials_with((-5..=5).prop_map(f64::from), 20),
+            extra in 0usize..20,
+        ) {
+            let n = poly.degree().unwrap_or(0) as usize + 1 + extra;
+            let values = poly.evaluate_at_roots_of_unity(n);
+            let restored = Polynomial::from_evaluations_at_roots_of_unity(&values, 1e-9).unwrap();
+            let difference = restored - &poly;
+            prop_assert!(difference.get_coefficients().iter().all(|c| c.abs() < 1e-9), "{}", difference);
+        }
+    }
+}
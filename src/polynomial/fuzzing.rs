@@ -0,0 +1,76 @@
+//! Module containing the [`arbitrary::Arbitrary`] implementation for fuzzing, enabled with the
+//! `arbitrary` feature.
+use arbitrary::{Arbitrary, Result, Unstructured};
+use super::Polynomial;
+
+/// The maximum degree of the generated polynomials.
+const MAX_DEGREE: u32 = 64;
+
+/// Generates well-formed polynomials, i.e. with finite coefficients and degree at most 64.
+///
+/// The first byte selects the shape of the polynomial: the zero polynomial, a constant, a
+/// sparse polynomial of possibly high degree, a polynomial with negative coefficients only,
+/// or a dense polynomial.
+impl<'a> Arbitrary<'a> for Polynomial {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // Non-finite coefficients are replaced with zeros
+        let coefficient = |u: &mut Unstructured<'a>| -> Result<f64> {
+            let coefficient = f64::arbitrary(u)?;
+            Ok(if coefficient.is_finite() { coefficient } else { 0.0 })
+        };
+
+        let mut poly = Polynomial::zero();
+        match u.int_in_range(0..=9u8)? {
+            0 => {}
+            1 | 2 => poly.set_coefficient_at(0, coefficient(u)?),
+            3 | 4 => {
+                for _ in 0..u.int_in_range(1..=4)? {
+                    let power = u.int_in_range(0..=MAX_DEGREE)?;
+                    poly.set_coefficient_at(power, coefficient(u)?);
+                }
+            }
+            5 | 6 => {
+                for power in 0..=u.int_in_range(0..=16)? {
+                    poly.set_coefficient_at(power, -coefficient(u)?.abs());
+                }
+            }
+            _ => {
+                for power in 0..=u.int_in_range(0..=16)? {
+                    poly.set_coefficient_at(power, coefficient(u)?);
+                }
+            }
+        }
+        Ok(poly)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+    use crate::Polynomial;
+
+    #[test]
+    fn generated_polynomials_are_well_formed() {
+        let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+        let bytes: Vec<u8> = (0..1 << 16)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect();
+
+        let mut u = Unstructured::new(&bytes);
+        let mut shapes = [false; 3];
+        while !u.is_empty() {
+            let poly = Polynomial::arbitrary(&mut u).unwrap();
+            assert!(poly.degree().is_none_or(|degree| degree <= super::MAX_DEGREE));
+            assert!(poly.coefficients.values().all(|c| c.is_finite() && *c != 0.0));
+            shapes[0] |= poly.is_zero();
+            shapes[1] |= poly.degree() == Some(0);
+            shapes[2] |= poly.degree().is_some_and(|degree| degree > 16);
+        }
+        assert_eq!([true; 3], shapes);
+    }
+}
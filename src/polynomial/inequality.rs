@@ -0,0 +1,317 @@
+use std::ops::Bound;
+use super::Polynomial;
+use super::sturm;
+
+/// The sign of the values of a polynomial on an interval.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Sign {
+    Negative,
+    Zero,
+    Positive,
+}
+
+/// An interval of real numbers given by its lower and upper bound.
+pub type Interval = (Bound<f64>, Bound<f64>);
+
+/// Returns a bound of the rounding error of the evaluation at `x`.
+pub(super) fn evaluation_error(poly: &Polynomial, x: f64) -> f64 {
+    let terms = poly.coefficients.len() as f64;
+    let absolute: f64 = poly.coefficients
+        .iter()
+        .map(|(power, coefficient)| coefficient.abs() * x.abs().powi(*power as i32))
+        .sum();
+    16.0 * (terms + 1.0) * f64::EPSILON * absolute
+}
+
+/// Finds the root of the polynomial between the points at which its values have opposite
+/// signs with bisection.
+pub(super) fn bisect(poly: &Polynomial, mut low: f64, mut high: f64) -> f64 {
+    let low_is_negative = poly.evaluate(low) < 0.0;
+    loop {
+        let middle = low + (high - low) / 2.0;
+        if middle <= low || middle >= high {
+            return middle;
+        }
+        let value = poly.evaluate(middle);
+        if value == 0.0 {
+            return middle;
+        }
+        if (value < 0.0) == low_is_negative {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+}
+
+/// Returns the distinct real roots of a non-zero polynomial in ascending order.
+///
+/// The roots are found for the [balanced](Polynomial::balanced) polynomial and scaled back,
+/// which is exact, so that the polynomials with badly scaled coefficients are handled.
+pub(super) fn distinct_real_roots(poly: &Polynomial) -> Vec<f64> {
+    let (scale, balanced) = poly.balanced();
+    let mut roots = distinct_real_roots_unscaled(&balanced, &bisect);
+    for root in &mut roots {
+        *root *= scale;
+    }
+    roots
+}
+
+/// Returns the distinct real roots of a non-zero polynomial in ascending order.
+///
+/// The roots of the derivative split the real line into the intervals on which the
+/// polynomial is monotonic, and every interval with a sign change contains one simple root,
+/// found with the `refine` function from the ends of the interval. The roots of the
+/// derivative at which the polynomial is zero up to the rounding errors are the multiple
+/// roots.
+pub(super) fn distinct_real_roots_unscaled<F>(poly: &Polynomial, refine: &F) -> Vec<f64>
+where
+    F: Fn(&Polynomial, f64, f64) -> f64,
+{
+    let Some(degree) = poly.degree().filter(|degree| *degree > 0) else {
+        return Vec::new();
+    };
+    // Cauchy's bound, all the roots lie strictly inside (-radius, radius)
+    let leading_coefficient = poly.get_coefficient_at(degree).abs();
+    let radius = 1.0 + poly.coefficients
+        .range(..degree)
+        .map(|(_, coefficient)| coefficient.abs() / leading_coefficient)
+        .fold(0.0, f64::max);
+
+    let mut points = vec![-radius];
+    points.extend(
+        distinct_real_roots_unscaled(&poly.derivative(), refine)
+            .into_iter()
+            .filter(|x| -radius < *x && *x < radius)
+    );
+    points.push(radius);
+
+    let is_root = |x: f64| poly.evaluate(x).abs() <= evaluation_error(poly, x);
+    let mut roots = Vec::new();
+    for window in points.windows(2) {
+        let (low, high) = (window[0], window[1]);
+        if is_root(low) {
+            if roots.last() != Some(&low) {
+                roots.push(low);
+            }
+            continue;
+        }
+        if is_root(high) {
+            continue;
+        }
+        if (poly.evaluate(low) < 0.0) != (poly.evaluate(high) < 0.0) {
+            roots.push(refine(poly, low, high));
+        }
+    }
+    roots
+}
+
+/// Returns the union of the intervals with the accepted signs, merging the adjacent ones.
+fn solve(intervals: Vec<(Bound<f64>, Bound<f64>, Sign)>, accept: impl Fn(Sign) -> bool) -> Vec<Interval> {
+    let mut solution: Vec<Interval> = Vec::new();
+    let mut previous_accepted = false;
+    for (start, end, sign) in intervals {
+        if !accept(sign) {
+            previous_accepted = false;
+            continue;
+        }
+        match solution.last_mut() {
+            Some((_, last_end)) if previous_accepted => *last_end = end,
+            _ => solution.push((start, end)),
+        }
+        previous_accepted = true;
+    }
+    solution
+}
+
+impl Polynomial {
+    /// Partitions the real line into the maximal intervals on which the polynomial is
+    /// positive, negative or zero, in ascending order.
+    ///
+    /// The real roots are returned as degenerate intervals `[r, r]` with the sign
+    /// [`Sign::Zero`], and the intervals between them are open. The sign does not change
+    /// across a root of even multiplicity, so the intervals on both of its sides have the same
+    /// sign. The zero polynomial is zero on the whole real line.
+    ///
+    /// The roots are found numerically, and a multiple root is detected when the polynomial
+    /// vanishes up to the rounding errors at a root of its derivative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ops::Bound::{Excluded, Included, Unbounded};
+    /// use polynomials::{Polynomial, Sign};
+    ///
+    /// // (x - 1)^2
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 1.0]);
+    /// assert_eq!(vec![
+    ///     (Unbounded, Excluded(1.0), Sign::Positive),
+    ///     (Included(1.0), Included(1.0), Sign::Zero),
+    ///     (Excluded(1.0), Unbounded, Sign::Positive),
+    /// ], poly.sign_intervals());
+    /// ```
+    pub fn sign_intervals(&self) -> Vec<(Bound<f64>, Bound<f64>, Sign)> {
+        if self.is_zero() {
+            return vec![(Bound::Unbounded, Bound::Unbounded, Sign::Zero)];
+        }
+        let roots = distinct_real_roots(self);
+        let Some((first, last)) = roots.first().zip(roots.last()) else {
+            return vec![(Bound::Unbounded, Bound::Unbounded, self.sign_at(0.0))];
+        };
+
+        let mut intervals = vec![(Bound::Unbounded, Bound::Excluded(*first), sturm::sign_at(self, f64::NEG_INFINITY))];
+        for window in roots.windows(2) {
+            let (low, high) = (window[0], window[1]);
+            intervals.push((Bound::Included(low), Bound::Included(low), Sign::Zero));
+            intervals.push((Bound::Excluded(low), Bound::Excluded(high), self.sign_at(low + (high - low) / 2.0)));
+        }
+        intervals.push((Bound::Included(*last), Bound::Included(*last), Sign::Zero));
+        intervals.push((Bound::Excluded(*last), Bound::Unbounded, sturm::sign_at(self, f64::INFINITY)));
+        intervals
+    }
+
+    /// Returns the set on which the polynomial is greater than or equal to zero, as a sorted
+    /// list of disjoint intervals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ops::Bound::{Included, Unbounded};
+    /// use polynomials::Polynomial;
+    ///
+    /// // x^2 - 1
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -1.0]);
+    /// assert_eq!(
+    ///     vec![(Unbounded, Included(-1.0)), (Included(1.0), Unbounded)],
+    ///     poly.solve_ge_zero()
+    /// );
+    /// ```
+    pub fn solve_ge_zero(&self) -> Vec<Interval> {
+        solve(self.sign_intervals(), |sign| sign != Sign::Negative)
+    }
+
+    /// Returns the set on which the polynomial is greater than zero, as a sorted list of
+    /// disjoint intervals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ops::Bound::{Excluded, Unbounded};
+    /// use polynomials::Polynomial;
+    ///
+    /// // x^2 - 1
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -1.0]);
+    /// assert_eq!(
+    ///     vec![(Unbounded, Excluded(-1.0)), (Excluded(1.0), Unbounded)],
+    ///     poly.solve_gt_zero()
+    /// );
+    /// ```
+    pub fn solve_gt_zero(&self) -> Vec<Interval> {
+        solve(self.sign_intervals(), |sign| sign == Sign::Positive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Bound::{self, Excluded, Included, Unbounded};
+    use super::{bisect, distinct_real_roots, distinct_real_roots_unscaled, Sign};
+    use crate::Polynomial;
+
+    /// Rounds the finite bounds to 9 decimal places, so that the numerically found roots can
+    /// be compared exactly.
+    fn rounded(bound: Bound<f64>) -> Bound<f64> {
+        bound.map(|x| (x * 1e9).round() / 1e9 + 0.0)
+    }
+
+    #[test]
+    fn distinct_real_roots_works() {
+        // (x + 3)(x - 0.5)^3 (x^2 + 1)
+        let poly = Polynomial::from_coefficients(&vec![1.0, 3.0])
+            * &Polynomial::from_coefficients(&vec![1.0, -1.5, 0.75, -0.125])
+            * &Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]);
+        let roots: Vec<f64> = distinct_real_roots(&poly).into_iter()
+            .map(|root| (root * 1e4).round() / 1e4)
+            .collect();
+        assert_eq!(vec![-3.0, 0.5], roots);
+        assert!(distinct_real_roots(&Polynomial::from_coefficients(&vec![1.0, 0.0, 4.0])).is_empty());
+    }
+
+    #[test]
+    fn distinct_real_roots_of_badly_scaled_polynomial() {
+        // (x - 1e70)(x - 2e70)(x - 4e70)(x + 8e70), the Cauchy bound of the roots is so large
+        // that the values of the unscaled polynomial near it overflow
+        let poly = [1e70, 2e70, 4e70, -8e70].into_iter()
+            .map(|root| Polynomial::from_coefficients(&vec![1.0, -root]))
+            .fold(Polynomial::one(), |product, factor| product * &factor);
+        let roots: Vec<f64> = distinct_real_roots(&poly).into_iter()
+            .map(|root| (root / 1e61).round() / 1e9)
+            .collect();
+        assert_eq!(vec![-8.0, 1.0, 2.0, 4.0], roots);
+        assert!(distinct_real_roots_unscaled(&poly, &bisect).len() < 4);
+    }
+
+    #[test]
+    fn sign_chart() {
+        // x (x - 1)^2 (x + 2)
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -3.0, 2.0, 0.0]);
+        let intervals: Vec<_> = poly.sign_intervals()
+            .into_iter()
+            .map(|(start, end, sign)| (rounded(start), rounded(end), sign))
+            .collect();
+        assert_eq!(vec![
+            (Unbounded, Excluded(-2.0), Sign::Positive),
+            (Included(-2.0), Included(-2.0), Sign::Zero),
+            (Excluded(-2.0), Excluded(0.0), Sign::Negative),
+            (Included(0.0), Included(0.0), Sign::Zero),
+            (Excluded(0.0), Excluded(1.0), Sign::Positive),
+            (Included(1.0), Included(1.0), Sign::Zero),
+            (Excluded(1.0), Unbounded, Sign::Positive),
+        ], intervals);
+
+        let round = |intervals: Vec<(Bound<f64>, Bound<f64>)>| -> Vec<_> {
+            intervals.into_iter().map(|(start, end)| (rounded(start), rounded(end))).collect()
+        };
+        assert_eq!(
+            vec![(Unbounded, Included(-2.0)), (Included(0.0), Unbounded)],
+            round(poly.solve_ge_zero())
+        );
+        assert_eq!(
+            vec![(Unbounded, Excluded(-2.0)), (Excluded(0.0), Excluded(1.0)), (Excluded(1.0), Unbounded)],
+            round(poly.solve_gt_zero())
+        );
+        assert_eq!(
+            vec![(Excluded(-2.0), Excluded(0.0))],
+            round((-poly).solve_gt_zero())
+        );
+    }
+
+    #[test]
+    fn sign_chart_with_large_roots() {
+        // (x - 1e20)(x - 3e20), where adding one to a root does not move away from it
+        let poly = Polynomial::from_coefficients(&vec![1.0, -4e20, 3e40]);
+        let signs: Vec<Sign> = poly.sign_intervals().into_iter().map(|(_, _, sign)| sign).collect();
+        assert_eq!(vec![Sign::Positive, Sign::Zero, Sign::Negative, Sign::Zero, Sign::Positive], signs);
+
+        let intervals = poly.solve_gt_zero();
+        assert_eq!(2, intervals.len());
+        assert_eq!((Unbounded, Excluded(1.0)), (intervals[0].0, intervals[0].1.map(|x| (x / 1e20).round())));
+        assert_eq!(Unbounded, intervals[1].1);
+        assert_eq!(1, (-poly).solve_ge_zero().len());
+    }
+
+    #[test]
+    fn positive_polynomial_has_single_interval() {
+        let poly = Polynomial::from_coefficients(&vec![2.0, -1.0, 3.0]);
+        assert_eq!(vec![(Unbounded, Unbounded, Sign::Positive)], poly.sign_intervals());
+        assert_eq!(vec![(Unbounded, Unbounded)], poly.solve_gt_zero());
+        assert!((-poly).solve_ge_zero().is_empty());
+    }
+
+    #[test]
+    fn zero_polynomial_is_zero_everywhere() {
+        let poly = Polynomial::zero();
+        assert_eq!(vec![(Unbounded, Unbounded, Sign::Zero)], poly.sign_intervals());
+        assert_eq!(vec![(Unbounded, Unbounded)], poly.solve_ge_zero());
+        assert!(poly.solve_gt_zero().is_empty());
+    }
+}
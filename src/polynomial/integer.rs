@@ -0,0 +1,348 @@
+//! Integer polynomials.
+//!
+//! The coefficients are `f64`, and every finite one is a binary fraction `m / 2^k`, so the
+//! least common multiple of the denominators of a polynomial is a power of two. Multiplying by
+//! a power of two and dividing integers by their common divisor is exact, so the conversions
+//! here introduce no rounding errors, unless a coefficient overflows.
+
+use super::Polynomial;
+use super::scaling::{frexp, mul_power_of_two};
+
+/// Returns the exponent of the lowest set bit of a finite nonzero value, i.e. the largest `e`
+/// such that the value is an integer multiple of `2^e`.
+fn lowest_bit_exponent(value: f64) -> i32 {
+    let (mantissa, exponent) = frexp(value);
+    let integer_mantissa = mul_power_of_two(mantissa.abs(), 53) as u64;
+    exponent - 53 + integer_mantissa.trailing_zeros() as i32
+}
+
+/// Returns the greatest common divisor of the integer values. The remainder of `f64` division
+/// is exact, so the Euclidean algorithm is exact as well.
+pub(super) fn gcd(mut a: f64, mut b: f64) -> f64 {
+    while b != 0.0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+impl Polynomial {
+    /// Returns the content of the polynomial, i.e. the positive greatest common divisor of the
+    /// coefficients, or zero for the zero polynomial.
+    ///
+    /// For the integer coefficients it is the usual content, and in general it is the largest
+    /// binary fraction of which all the coefficients are integer multiples. The Euclidean
+    /// algorithm on `f64` values is exact, so the content is exact as well. The content of a
+    /// polynomial with a coefficient that is not finite is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![-12.0, 0.0, 18.0, 30.0]);
+    /// assert_eq!(6.0, poly.content());
+    /// assert_eq!(0.25, Polynomial::from_coefficients(&vec![0.75, 0.5]).content());
+    /// assert_eq!(0.0, Polynomial::zero().content());
+    /// ```
+    pub fn content(&self) -> f64 {
+        if self.coefficients.values().any(|coefficient| !coefficient.is_finite()) {
+            return f64::NAN;
+        }
+        self.coefficients.values().fold(0.0, |content, coefficient| gcd(content, *coefficient))
+    }
+
+    /// Returns the primitive part of the polynomial, i.e. the polynomial divided by its
+    /// [content](Polynomial::content), whose coefficients are integers without a common
+    /// divisor. The primitive part of the zero polynomial is zero.
+    ///
+    /// The content is positive, so the signs of the coefficients, including the leading one,
+    /// are preserved. The division is exact unless a quotient overflows, which may happen for a
+    /// polynomial with coefficients of very different magnitudes, see
+    /// [`scale_to_primitive`](Polynomial::scale_to_primitive) for the checked variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![-12.0, 0.0, 18.0, 30.0]);
+    /// assert_eq!(vec![-2.0, 0.0, 3.0, 5.0], poly.primitive_part().get_coefficients());
+    /// assert_eq!(poly, poly.primitive_part() * poly.content());
+    /// ```
+    pub fn primitive_part(&self) -> Polynomial {
+        let content = self.content();
+        if content == 0.0 {
+            return Polynomial::zero();
+        }
+        self.clone() / content
+    }
+
+    /// Returns the least common multiple `s` of the denominators of the coefficients and the
+    /// polynomial with the integer coefficients `s * P`, or `None` if a coefficient is not
+    /// finite or the scale or a scaled coefficient overflows, which happens for subnormal
+    /// coefficients.
+    ///
+    /// The scale is the smallest power of two that makes all the coefficients integers, and
+    /// it is one for a polynomial with integer coefficients, including the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![0.75, -2.0, 0.5]);
+    /// let (scale, integer) = poly.clear_denominators().unwrap();
+    /// assert_eq!(4.0, scale);
+    /// assert_eq!(vec![3.0, -8.0, 2.0], integer.get_coefficients());
+    /// ```
+    pub fn clear_denominators(&self) -> Option<(f64, Polynomial)> {
+        let mut exponent = 0;
+        for coefficient in self.coefficients.values() {
+            if !coefficient.is_finite() {
+                return None;
+            }
+            exponent = exponent.max(-lowest_bit_exponent(*coefficient));
+        }
+
+        let mut result = Polynomial::zero();
+        for (power, coefficient) in &self.coefficients {
+            let scaled = mul_power_of_two(*coefficient, exponent);
+            if !scaled.is_finite() {
+                return None;
+            }
+            result.coefficients.insert(*power, scaled);
+        }
+        let scale = mul_power_of_two(1.0, exponent);
+        scale.is_finite().then_some((scale, result))
+    }
+
+    /// Returns the positive factor `c` and the primitive polynomial `Q` with integer
+    /// coefficients without a common divisor, such that `P = c * Q`, or `None` if a coefficient
+    /// is not finite or the integer polynomial overflows.
+    ///
+    /// The factor is the [content](Polynomial::content) of the polynomial, and the division by
+    /// it is exact. The signs of the coefficients are preserved, so the factor is always
+    /// positive. The zero polynomial has the factor zero and is its own primitive polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![-1.5, 0.0, 4.5]);
+    /// let (factor, primitive) = poly.scale_to_primitive().unwrap();
+    /// assert_eq!(1.5, factor);
+    /// assert_eq!(vec![-1.0, 0.0, 3.0], primitive.get_coefficients());
+    /// assert_eq!(poly, primitive * factor);
+    /// ```
+    pub fn scale_to_primitive(&self) -> Option<(f64, Polynomial)> {
+        let content = self.content();
+        if content.is_nan() {
+            return None;
+        }
+        if content == 0.0 {
+            return Some((0.0, Polynomial::zero()));
+        }
+        let mut primitive = self.clone();
+        for coefficient in primitive.coefficients.values_mut() {
+            *coefficient /= content;
+            if !coefficient.is_finite() {
+                return None;
+            }
+        }
+        Some((content, primitive))
+    }
+
+    /// Evaluates the polynomial at the fraction `p / q`, returning the numerator
+    /// `sum of a_i p^i q^(n - i)` and the denominator `q^n`, where `n` is the degree.
+    ///
+    /// The numerator is computed with the homogeneous Horner's method, which multiplies and
+    /// adds without dividing, so the result is exact for integer coefficients and arguments as
+    /// long as the intermediate values stay below `2^53`. The fraction is not reduced, and the
+    /// zero polynomial gives `(0, 1)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // 3x^2 - x + 2 at 1/3 is 2 = 18/9
+    /// let poly = Polynomial::from_coefficients(&vec![3.0, -1.0, 2.0]);
+    /// assert_eq!((18.0, 9.0), poly.evaluate_rational(1.0, 3.0));
+    /// assert_eq!(2.0, poly.evaluate(1.0 / 3.0));
+    /// ```
+    pub fn evaluate_rational(&self, p: f64, q: f64) -> (f64, f64) {
+        let Some(degree) = self.degree() else {
+            return (0.0, 1.0);
+        };
+        let mut numerator = self.get_coefficient_at(degree);
+        let mut q_power = 1.0;
+        for power in (0..degree).rev() {
+            q_power *= q;
+            numerator = numerator * p + self.get_coefficient_at(power) * q_power;
+        }
+        (numerator, q_power)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+    use proptest::prelude::*;
+    use super::{gcd, lowest_bit_exponent};
+    use crate::Polynomial;
+    use crate::polynomial::strategy::polynomials_with;
+
+    /// Binary fractions with up to 40 bits after the point.
+    fn binary_fractions() -> impl Strategy<Value = f64> + Clone {
+        (-1_000_000i64..=1_000_000, 0..=40).prop_map(|(numerator, exponent)| numerator as f64 / 2f64.powi(exponent))
+    }
+
+    fn exact(value: f64) -> BigRational {
+        BigRational::from_float(value).unwrap()
+    }
+
+    #[test]
+    fn lowest_bit_exponent_works() {
+        assert_eq!(0, lowest_bit_exponent(1.0));
+        assert_eq!(2, lowest_bit_exponent(-12.0));
+        assert_eq!(-2, lowest_bit_exponent(0.75));
+        assert_eq!(-1074, lowest_bit_exponent(f64::from_bits(1)));
+        assert_eq!(971, lowest_bit_exponent(f64::MAX));
+    }
+
+    #[test]
+    fn gcd_of_large_integers() {
+        assert_eq!(6.0, gcd(-12.0, 18.0));
+        assert_eq!(5.0, gcd(0.0, -5.0));
+        // 3 * 2^60 and 5 * 2^58 exceed the range of the exactly representable consecutive integers
+        assert_eq!(2f64.powi(58), gcd(3.0 * 2f64.powi(60), 5.0 * 2f64.powi(58)));
+    }
+
+    #[test]
+    fn clearing_denominators_of_integer_polynomials() {
+        let poly = Polynomial::from_coefficients(&vec![3.0, -8.0, 2.0]);
+        assert_eq!(Some((1.0, poly.clone())), poly.clear_denominators());
+        assert_eq!(Some((1.0, Polynomial::zero())), Polynomial::zero().clear_denominators());
+        assert_eq!(Some((0.0, Polynomial::zero())), Polynomial::zero().scale_to_primitive());
+    }
+
+    #[test]
+    fn clearing_denominators_of_non_finite_or_overflowing_polynomials() {
+        assert_eq!(None, Polynomial::from_coefficients(&vec![f64::NAN, 1.0]).clear_denominators());
+        assert_eq!(None, Polynomial::from_coefficients(&vec![f64::INFINITY]).scale_to_primitive());
+        let poly = Polynomial::from_coefficients(&vec![f64::MAX, 0.5]);
+        assert_eq!(None, poly.clear_denominators());
+        assert_eq!(None, poly.scale_to_primitive());
+
+        // 3t + tx with a subnormal t, the scale 2^1030 overflows although the coefficients of
+        // the primitive polynomial are small
+        let t = f64::from_bits(1 << 44);
+        let poly = Polynomial::from_coefficients(&vec![t, 3.0 * t]);
+        assert_eq!(None, poly.clear_denominators());
+        let (factor, primitive) = poly.scale_to_primitive().unwrap();
+        assert_eq!(t, factor);
+        assert_eq!(vec![1.0, 3.0], primitive.get_coefficients());
+        assert_eq!(poly, primitive * factor);
+    }
+
+    #[test]
+    fn primitive_polynomial_of_tiny_coefficients() {
+        let poly = Polynomial::from_coefficients(&vec![3.0 * 2f64.powi(-100), 6.0 * 2f64.powi(-100)]);
+        let (factor, primitive) = poly.scale_to_primitive().unwrap();
+        assert_eq!(3.0 * 2f64.powi(-100), factor);
+        assert_eq!(vec![1.0, 2.0], primitive.get_coefficients());
+    }
+
+    #[test]
+    fn content_with_large_common_factor() {
+        // 3^20 * 2^200 times 4x^3 - 6x^2 + 10, which has the content 2
+        let factor = BigInt::from(3).pow(20) << 200;
+        let common = 3f64.powi(20) * 2f64.powi(200);
+        let poly = Polynomial::from_coefficients(&vec![4.0 * common, -6.0 * common, 0.0, 10.0 * common]);
+        assert_eq!(BigRational::from_integer(factor * 2), exact(poly.content()));
+        assert_eq!(vec![2.0, -3.0, 0.0, 5.0], poly.primitive_part().get_coefficients());
+        assert_eq!(poly, poly.primitive_part() * poly.content());
+    }
+
+    #[test]
+    fn content_sign_and_degenerate_cases() {
+        let poly = Polynomial::from_coefficients(&vec![-4.0, -6.0]);
+        assert_eq!(2.0, poly.content());
+        assert_eq!(vec![-2.0, -3.0], poly.primitive_part().get_coefficients());
+        assert_eq!(7.0, Polynomial::from_coefficients(&vec![-7.0]).content());
+        assert_eq!(Polynomial::zero(), Polynomial::zero().primitive_part());
+        assert!(Polynomial::from_coefficients(&vec![f64::INFINITY, 2.0]).content().is_nan());
+        assert!(Polynomial::from_coefficients(&vec![f64::NAN]).content().is_nan());
+    }
+
+    #[test]
+    fn evaluate_rational_at_integers_and_negative_denominators() {
+        let poly = Polynomial::from_coefficients(&vec![2.0, 0.0, -3.0, 1.0]);
+        assert_eq!((poly.evaluate(5.0), 1.0), poly.evaluate_rational(5.0, 1.0));
+        // 2 (1/-2)^3 - 3 (1/-2) + 1 = -1/4 + 3/2 + 1, over (-2)^3
+        assert_eq!((-18.0, -8.0), poly.evaluate_rational(1.0, -2.0));
+        assert_eq!((7.0, 1.0), Polynomial::from_coefficients(&vec![7.0]).evaluate_rational(3.0, 5.0));
+        assert_eq!((0.0, 1.0), Polynomial::zero().evaluate_rational(3.0, 5.0));
+    }
+
+    proptest! {
+        #[test]
+        fn evaluate_rational_matches_exact_evaluation(
+            poly in polynomials_with((-100..=100).prop_map(f64::from), 6),
+            p in -50i32..=50,
+            q in (-50i32..=50).prop_filter("Nonzero denominator", |q| *q != 0),
+        ) {
+            let x = BigRational::new(BigInt::from(p), BigInt::from(q));
+            let expected = (0..=poly.degree().unwrap_or(0))
+                .map(|power| exact(poly.get_coefficient_at(power)) * x.pow(power as i32))
+                .fold(BigRational::from_integer(BigInt::from(0)), |sum, term| sum + term);
+            let (numerator, denominator) = poly.evaluate_rational(p.into(), q.into());
+            prop_assert_eq!(expected, exact(numerator) / exact(denominator));
+            prop_assert_eq!(exact(f64::from(q)).pow(poly.degree().unwrap_or(0) as i32), exact(denominator));
+        }
+
+        #[test]
+        fn clearing_denominators_is_exact(p in polynomials_with(binary_fractions(), 8)) {
+            let (scale, integer) = p.clear_denominators().unwrap();
+            prop_assert_eq!(p.coefficients.len(), integer.coefficients.len());
+            for (power, coefficient) in &integer.coefficients {
+                prop_assert_eq!(0.0, coefficient.fract());
+                prop_assert_eq!(exact(p.get_coefficient_at(*power)) * exact(scale), exact(*coefficient));
+            }
+            // The scale is the smallest one, unless it is one, some coefficient is odd
+            prop_assert!(scale == 1.0 || integer.coefficients.values().any(|coefficient| coefficient % 2.0 != 0.0));
+        }
+
+        #[test]
+        fn primitive_polynomial_is_exact(p in polynomials_with(binary_fractions(), 8)) {
+            let (factor, primitive) = p.scale_to_primitive().unwrap();
+            prop_assert!(factor >= 0.0);
+            let content = primitive.coefficients.values().fold(0.0, |content, coefficient| gcd(content, *coefficient));
+            prop_assert!(p.is_zero() || content == 1.0);
+            for power in 0..=p.degree().unwrap_or(0) {
+                prop_assert_eq!(
+                    exact(p.get_coefficient_at(power)),
+                    exact(primitive.get_coefficient_at(power)) * exact(factor)
+                );
+            }
+            prop_assert_eq!(p, primitive * factor);
+        }
+
+        #[test]
+        fn primitive_part_matches_scale_to_primitive(p in polynomials_with(binary_fractions(), 8)) {
+            let (factor, primitive) = p.scale_to_primitive().unwrap();
+            prop_assert_eq!(factor, p.content());
+            prop_assert_eq!(primitive, p.primitive_part());
+        }
+
+        #[test]
+        fn integer_polynomials_have_scale_one(p in polynomials_with((-1000..=1000).prop_map(f64::from), 8)) {
+            prop_assert_eq!(Some((1.0, p.clone())), p.clear_denominators());
+            let (factor, primitive) = p.scale_to_primitive().unwrap();
+            prop_assert_eq!(BigRational::from_integer(BigInt::from(factor as i64)), exact(factor));
+            prop_assert_eq!(p, primitive * factor);
+        }
+    }
+}
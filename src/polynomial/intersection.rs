@@ -0,0 +1,153 @@
+use super::Polynomial;
+use super::inequality::distinct_real_roots;
+use super::orthogonal::definite_integral;
+
+impl Polynomial {
+    /// Returns the points `(x, y)` at which the graphs of the polynomials intersect, in
+    /// ascending order of `x`, or `None` if the polynomials are equal and intersect everywhere.
+    ///
+    /// The `x` coordinates are the distinct real roots of the difference of the polynomials,
+    /// so a point of tangency is reported once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let parabola = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0]);
+    /// let line = Polynomial::from_coefficients(&vec![1.0, 2.0]);
+    /// let points = parabola.intersections_with(&line).unwrap();
+    /// assert_eq!(vec![(-1.0, 1.0), (2.0, 4.0)], points);
+    ///
+    /// assert_eq!(None, line.intersections_with(&line));
+    /// ```
+    pub fn intersections_with(&self, other: &Polynomial) -> Option<Vec<(f64, f64)>> {
+        let difference = self.clone() - other;
+        if difference.is_zero() {
+            return None;
+        }
+        let points = distinct_real_roots(&difference)
+            .into_iter()
+            .map(|x| (x, self.evaluate(x)))
+            .collect();
+        Some(points)
+    }
+
+    /// Returns the area between the graphs of the polynomials on the interval between `a` and
+    /// `b`, i.e. the integral of `|P(x) - Q(x)|`.
+    ///
+    /// The interval is split at the real roots of the difference of the polynomials, and the
+    /// absolute values of the exact integrals of the difference on the pieces are summed, so
+    /// the areas on both sides of a crossing do not cancel out. The area is nonnegative for
+    /// both orders of the endpoints.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // The integral of x - x^3 on [-1, 1] is zero, but the area is not
+    /// let line = Polynomial::from_coefficients(&vec![1.0, 0.0]);
+    /// let cubic = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0, 0.0]);
+    /// assert_eq!(0.5, cubic.area_between(&line, -1.0, 1.0));
+    /// ```
+    pub fn area_between(&self, other: &Polynomial, a: f64, b: f64) -> f64 {
+        let (a, b) = if a <= b { (a, b) } else { (b, a) };
+        let difference = self.clone() - other;
+        if difference.is_zero() {
+            return 0.0;
+        }
+        let mut points = vec![a];
+        points.extend(distinct_real_roots(&difference).into_iter().filter(|x| a < *x && *x < b));
+        points.push(b);
+        points.windows(2)
+            .map(|window| definite_integral(&difference, window[0], window[1]).abs())
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Polynomial;
+    use crate::polynomial::orthogonal::definite_integral;
+
+    fn rounded(points: Vec<(f64, f64)>) -> Vec<(f64, f64)> {
+        let round = |value: f64| (value * 1e9).round() / 1e9 + 0.0;
+        points.into_iter().map(|(x, y)| (round(x), round(y))).collect()
+    }
+
+    #[test]
+    fn line_crosses_parabola_twice() {
+        let parabola = Polynomial::from_coefficients(&vec![1.0, -3.0, 1.0]);
+        let line = Polynomial::from_coefficients(&vec![-1.0, 4.0]);
+        // x^2 - 3x + 1 = -x + 4 at x = -1 and x = 3
+        let points = parabola.intersections_with(&line).unwrap();
+        assert_eq!(vec![(-1.0, 5.0), (3.0, 1.0)], rounded(points.clone()));
+        assert_eq!(points, line.intersections_with(&parabola).unwrap());
+    }
+
+    #[test]
+    fn tangent_line_touches_once() {
+        let parabola = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0]);
+        let tangent = Polynomial::from_coefficients(&vec![2.0, -1.0]);
+        assert_eq!(vec![(1.0, 1.0)], rounded(parabola.intersections_with(&tangent).unwrap()));
+    }
+
+    #[test]
+    fn parallel_graphs_do_not_intersect() {
+        let line = Polynomial::from_coefficients(&vec![2.0, 1.0]);
+        let parallel = Polynomial::from_coefficients(&vec![2.0, -3.0]);
+        assert_eq!(Some(vec![]), line.intersections_with(&parallel));
+
+        let parabola = Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]);
+        let shifted = Polynomial::from_coefficients(&vec![1.0, 0.0, -1.0]);
+        assert_eq!(Some(vec![]), parabola.intersections_with(&shifted));
+        assert_eq!(Some(vec![]), parabola.intersections_with(&Polynomial::zero()));
+    }
+
+    fn assert_approx_eq(expected: f64, actual: f64) {
+        assert!((expected - actual).abs() < 1e-12, "{expected} != {actual}");
+    }
+
+    #[test]
+    fn area_between_curves_crossing_once() {
+        let parabola = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0]);
+        let line = Polynomial::from_coefficients(&vec![1.0, 0.0]);
+        // ∫ (x - x^2) dx on [0, 1] = 1/6, ∫ (x^2 - x) dx on [1, 2] = 5/6, and the integral
+        // of the difference on [0, 2] is only 5/6 - 1/6
+        assert_approx_eq(1.0, parabola.area_between(&line, 0.0, 2.0));
+        assert_approx_eq(1.0, line.area_between(&parabola, 2.0, 0.0));
+        assert_approx_eq(2.0 / 3.0, definite_integral(&(parabola.clone() - &line), 0.0, 2.0));
+    }
+
+    #[test]
+    fn area_between_curves_crossing_twice() {
+        // x^3 - x crosses zero at -1, 0 and 1, the interval contains the last two
+        let cubic = Polynomial::from_coefficients(&vec![1.0, 0.0, -1.0, 0.0]);
+        // 1/4 on [-0.5, 0] minus the part on [-1, -0.5], 1/4 on [0, 1] and 9/4 on [1, 2]
+        let expected = (0.25 - 9.0 / 64.0) + 0.25 + 2.25;
+        assert_approx_eq(expected, cubic.area_between(&Polynomial::zero(), -0.5, 2.0));
+    }
+
+    #[test]
+    fn area_between_identical_curves_is_zero() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 3.0]);
+        assert_eq!(0.0, poly.area_between(&poly.clone(), -5.0, 5.0));
+        assert_eq!(0.0, poly.area_between(&Polynomial::zero(), 1.0, 1.0));
+    }
+
+    #[test]
+    fn area_between_tangent_curves() {
+        let parabola = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0]);
+        let tangent = Polynomial::from_coefficients(&vec![2.0, -1.0]);
+        // ∫ (x - 1)^2 dx on [0, 3] = 1/3 + 8/3
+        assert_approx_eq(3.0, parabola.area_between(&tangent, 0.0, 3.0));
+    }
+
+    #[test]
+    fn equal_polynomials_intersect_everywhere() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 3.0]);
+        assert_eq!(None, poly.intersections_with(&poly.clone()));
+        assert_eq!(None, Polynomial::zero().intersections_with(&Polynomial::zero()));
+    }
+}
@@ -0,0 +1,450 @@
+//! Module containing the Laurent polynomials, which allow the negative powers of the
+//! indeterminate.
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use super::Polynomial;
+use super::display::{write_sparse_terms, FormatOptions, PolynomialFormat};
+
+/// Represents a Laurent polynomial with real coefficients, i.e. a finite sum of terms with
+/// integer, possibly negative, powers of the indeterminate.
+///
+/// As in [`Polynomial`], the zero coefficients are never stored.
+///
+/// # Examples
+///
+/// ```
+/// use polynomials::LaurentPolynomial;
+///
+/// // x + 2x^-1
+/// let poly = LaurentPolynomial::from_terms([(1, 1.0), (-1, 2.0)]);
+/// assert_eq!("x + 2x^-1", poly.to_string());
+/// assert_eq!("x + 2x^{-1}", format!("{poly:#}"));
+/// assert_eq!(Some(3.0), poly.evaluate(2.0));
+/// assert_eq!(None, poly.evaluate(0.0));
+/// ```
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct LaurentPolynomial {
+    coefficients: BTreeMap<i64, f64>,
+}
+
+impl LaurentPolynomial {
+    /// Returns a new Laurent polynomial with all coefficients set to zero.
+    pub fn zero() -> LaurentPolynomial {
+        LaurentPolynomial {
+            coefficients: BTreeMap::new(),
+        }
+    }
+
+    /// Returns a new Laurent polynomial with the coefficients of the terms summed up for every
+    /// power.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::LaurentPolynomial;
+    ///
+    /// let poly = LaurentPolynomial::from_terms([(-2, 1.0), (0, 3.0), (-2, 1.0)]);
+    /// assert_eq!(2.0, poly.get_coefficient_at(-2));
+    /// assert_eq!(3.0, poly.get_coefficient_at(0));
+    /// ```
+    pub fn from_terms(terms: impl IntoIterator<Item = (i64, f64)>) -> LaurentPolynomial {
+        let mut poly = LaurentPolynomial::zero();
+        for (power, coefficient) in terms {
+            poly.add_coefficient_at(power, coefficient);
+        }
+        poly
+    }
+
+    /// Checks if the Laurent polynomial is a zero polynomial.
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// Sets the coefficient in the term with the indeterminate raised to the given power.
+    pub fn set_coefficient_at(&mut self, power: i64, coefficient: f64) {
+        if coefficient == 0.0 {
+            self.coefficients.remove(&power);
+            return;
+        }
+        self.coefficients.insert(power, coefficient);
+    }
+
+    /// Returns the coefficient from the term with the indeterminate raised to the given power.
+    pub fn get_coefficient_at(&self, power: i64) -> f64 {
+        self.coefficients.get(&power).copied().unwrap_or(0.0)
+    }
+
+    /// Adds the specified value to the coefficient of the term with the indeterminate raised
+    /// to the given power.
+    pub fn add_coefficient_at(&mut self, power: i64, coefficient: f64) {
+        self.set_coefficient_at(power, self.get_coefficient_at(power) + coefficient);
+    }
+
+    /// Returns the highest power with a nonzero coefficient, or `None` for the zero polynomial.
+    pub fn highest_power(&self) -> Option<i64> {
+        self.coefficients.last_key_value().map(|(power, _)| *power)
+    }
+
+    /// Returns the lowest power with a nonzero coefficient, or `None` for the zero polynomial.
+    pub fn lowest_power(&self) -> Option<i64> {
+        self.coefficients.first_key_value().map(|(power, _)| *power)
+    }
+
+    /// Evaluates the Laurent polynomial at `x`, or returns `None` if `x` is zero and there is a
+    /// term with a negative power.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::LaurentPolynomial;
+    ///
+    /// let poly = LaurentPolynomial::from_terms([(2, 1.0), (-2, -4.0)]);
+    /// assert_eq!(Some(3.0), poly.evaluate(2.0));
+    /// assert_eq!(None, poly.evaluate(0.0));
+    ///
+    /// let poly = LaurentPolynomial::from_terms([(2, 1.0), (0, -4.0)]);
+    /// assert_eq!(Some(-4.0), poly.evaluate(0.0));
+    /// ```
+    pub fn evaluate(&self, x: f64) -> Option<f64> {
+        let (principal, regular) = self.split();
+        if principal.is_zero() {
+            return Some(regular.evaluate(x));
+        }
+        if x == 0.0 {
+            return None;
+        }
+        Some(principal.evaluate(1.0 / x) + regular.evaluate(x))
+    }
+
+    /// Returns the derivative of the Laurent polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::LaurentPolynomial;
+    ///
+    /// let poly = LaurentPolynomial::from_terms([(2, 1.0), (0, 5.0), (-1, 3.0)]);
+    /// assert_eq!(LaurentPolynomial::from_terms([(1, 2.0), (-2, -3.0)]), poly.derivative());
+    /// ```
+    pub fn derivative(&self) -> LaurentPolynomial {
+        let mut result = LaurentPolynomial::zero();
+        for (power, coefficient) in self.coefficients.iter() {
+            if *power != 0 {
+                result.set_coefficient_at(*power - 1, *coefficient * (*power as f64));
+            }
+        }
+        result
+    }
+
+    /// Splits the Laurent polynomial into the principal part, with the terms of negative
+    /// powers, and the regular part, with the others.
+    ///
+    /// The principal part is returned as a polynomial in `1 / x`, i.e. the coefficient of
+    /// `x^-k` is at the power `k`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a power doesn't fit in `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::LaurentPolynomial;
+    ///
+    /// let poly = LaurentPolynomial::from_terms([(1, 1.0), (0, 2.0), (-2, 3.0)]);
+    /// let (principal, regular) = poly.split();
+    /// assert_eq!(vec![3.0, 0.0, 0.0], principal.get_coefficients());
+    /// assert_eq!(vec![1.0, 2.0], regular.get_coefficients());
+    /// ```
+    pub fn split(&self) -> (Polynomial, Polynomial) {
+        let (mut principal, mut regular) = (Polynomial::zero(), Polynomial::zero());
+        for (power, coefficient) in self.coefficients.iter() {
+            let poly = if *power < 0 { &mut principal } else { &mut regular };
+            let power = u32::try_from(power.unsigned_abs()).expect("Power out of the range of u32");
+            poly.coefficients.insert(power, *coefficient);
+        }
+        (principal, regular)
+    }
+
+    /// Converts the Laurent polynomial into a polynomial, or returns `None` if it has a term
+    /// with a negative power or a power which doesn't fit in `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{LaurentPolynomial, Polynomial};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -3.0]);
+    /// let laurent = LaurentPolynomial::from(&poly);
+    /// assert_eq!(Some(poly), laurent.to_polynomial());
+    ///
+    /// let laurent = LaurentPolynomial::from_terms([(-1, 1.0)]);
+    /// assert_eq!(None, laurent.to_polynomial());
+    /// ```
+    pub fn to_polynomial(&self) -> Option<Polynomial> {
+        let mut poly = Polynomial::zero();
+        for (power, coefficient) in self.coefficients.iter() {
+            poly.coefficients.insert(u32::try_from(*power).ok()?, *coefficient);
+        }
+        Some(poly)
+    }
+
+    /// Returns the Laurent polynomial as a [`String`] formatted according to the given
+    /// [options](FormatOptions).
+    ///
+    /// The negative powers are written like the others, e.g. `x^-2`, or `x^{-2}` in the
+    /// `PolynomialFormat::Latex` format. The zero terms are never written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{FormatOptions, LaurentPolynomial, TermOrder};
+    ///
+    /// let poly = LaurentPolynomial::from_terms([(1, 1.0), (0, 2.0), (-2, 3.0)]);
+    /// let options = FormatOptions::new().variable("z").term_order(TermOrder::Ascending);
+    /// assert_eq!("3z^-2 + 2 + z", poly.format_opts(&options));
+    /// ```
+    pub fn format_opts(&self, options: &FormatOptions) -> String {
+        let mut buffer = String::new();
+        let terms = self.coefficients.iter().map(|(power, coefficient)| (*power, *coefficient));
+        write_sparse_terms(&mut buffer, terms, options).expect("Writing to a String cannot fail");
+        buffer
+    }
+}
+
+impl From<&Polynomial> for LaurentPolynomial {
+    fn from(poly: &Polynomial) -> Self {
+        let coefficients = poly.coefficients
+            .iter()
+            .map(|(power, coefficient)| (*power as i64, *coefficient))
+            .collect();
+        LaurentPolynomial { coefficients }
+    }
+}
+
+impl From<Polynomial> for LaurentPolynomial {
+    fn from(poly: Polynomial) -> Self {
+        LaurentPolynomial::from(&poly)
+    }
+}
+
+fn add_in_place(poly1: &mut LaurentPolynomial, poly2: &LaurentPolynomial) {
+    for (power, coefficient) in poly2.coefficients.iter() {
+        poly1.add_coefficient_at(*power, *coefficient);
+    }
+}
+
+fn sub_in_place(poly1: &mut LaurentPolynomial, poly2: &LaurentPolynomial) {
+    for (power, coefficient) in poly2.coefficients.iter() {
+        poly1.add_coefficient_at(*power, -*coefficient);
+    }
+}
+
+fn multiply(poly1: &LaurentPolynomial, poly2: &LaurentPolynomial) -> LaurentPolynomial {
+    let mut result = LaurentPolynomial::zero();
+    for (power1, coefficient1) in poly1.coefficients.iter() {
+        for (power2, coefficient2) in poly2.coefficients.iter() {
+            result.add_coefficient_at(power1 + power2, coefficient1 * coefficient2);
+        }
+    }
+    result
+}
+
+impl Add<&Self> for LaurentPolynomial {
+    type Output = LaurentPolynomial;
+
+    fn add(mut self, rhs: &Self) -> Self::Output {
+        add_in_place(&mut self, rhs);
+        self
+    }
+}
+
+impl Add for LaurentPolynomial {
+    type Output = LaurentPolynomial;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self + &rhs
+    }
+}
+
+impl AddAssign<&Self> for LaurentPolynomial {
+    fn add_assign(&mut self, rhs: &Self) {
+        add_in_place(self, rhs);
+    }
+}
+
+impl Sub<&Self> for LaurentPolynomial {
+    type Output = LaurentPolynomial;
+
+    fn sub(mut self, rhs: &Self) -> Self::Output {
+        sub_in_place(&mut self, rhs);
+        self
+    }
+}
+
+impl Sub for LaurentPolynomial {
+    type Output = LaurentPolynomial;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self - &rhs
+    }
+}
+
+impl SubAssign<&Self> for LaurentPolynomial {
+    fn sub_assign(&mut self, rhs: &Self) {
+        sub_in_place(self, rhs);
+    }
+}
+
+impl Mul<&Self> for LaurentPolynomial {
+    type Output = LaurentPolynomial;
+
+    fn mul(self, rhs: &Self) -> Self::Output {
+        multiply(&self, rhs)
+    }
+}
+
+impl Mul for LaurentPolynomial {
+    type Output = LaurentPolynomial;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        multiply(&self, &rhs)
+    }
+}
+
+impl Mul<f64> for LaurentPolynomial {
+    type Output = LaurentPolynomial;
+
+    fn mul(mut self, rhs: f64) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl MulAssign<&Self> for LaurentPolynomial {
+    fn mul_assign(&mut self, rhs: &Self) {
+        *self = multiply(self, rhs);
+    }
+}
+
+impl MulAssign<f64> for LaurentPolynomial {
+    fn mul_assign(&mut self, rhs: f64) {
+        // Prevent zeros from being present in the map
+        if rhs == 0.0 {
+            *self = LaurentPolynomial::zero();
+            return;
+        }
+        for (_, coefficient) in self.coefficients.iter_mut() {
+            *coefficient *= rhs;
+        }
+        self.coefficients.retain(|_, coefficient| *coefficient != 0.0);
+    }
+}
+
+impl Neg for LaurentPolynomial {
+    type Output = LaurentPolynomial;
+
+    fn neg(mut self) -> Self::Output {
+        for (_, coefficient) in self.coefficients.iter_mut() {
+            *coefficient = -*coefficient;
+        }
+        self
+    }
+}
+
+/// Formats the Laurent polynomial using the `PolynomialFormat::Standard` format, or the
+/// `PolynomialFormat::Latex` format with the alternate flag `{:#}`. The precision rounds every
+/// coefficient to the given number of decimal places.
+impl Display for LaurentPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = if f.alternate() {
+            PolynomialFormat::Latex
+        } else {
+            PolynomialFormat::Standard
+        };
+        let mut options = FormatOptions::from(format);
+        if let Some(precision) = f.precision() {
+            options = options.precision(precision);
+        }
+        let terms = self.coefficients.iter().map(|(power, coefficient)| (*power, *coefficient));
+        write_sparse_terms(f, terms, &options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LaurentPolynomial;
+    use crate::{FormatOptions, Polynomial, PolynomialFormat};
+
+    #[test]
+    fn multiplication_across_zero_power() {
+        // (x + x^-1)(x - x^-1) = x^2 - x^-2
+        let p = LaurentPolynomial::from_terms([(1, 1.0), (-1, 1.0)]);
+        let q = LaurentPolynomial::from_terms([(1, 1.0), (-1, -1.0)]);
+        assert_eq!(LaurentPolynomial::from_terms([(2, 1.0), (-2, -1.0)]), p.clone() * &q);
+
+        // x^-3 * x^3 = 1
+        let product = LaurentPolynomial::from_terms([(-3, 2.0)]) * LaurentPolynomial::from_terms([(3, 0.5)]);
+        assert_eq!(LaurentPolynomial::from(Polynomial::one()), product);
+        assert_eq!(LaurentPolynomial::zero(), p * &LaurentPolynomial::zero());
+    }
+
+    #[test]
+    fn addition_and_subtraction() {
+        let p = LaurentPolynomial::from_terms([(1, 1.0), (-1, 2.0)]);
+        let q = LaurentPolynomial::from_terms([(0, 3.0), (-1, -2.0)]);
+        assert_eq!(LaurentPolynomial::from_terms([(1, 1.0), (0, 3.0)]), p.clone() + &q);
+        assert_eq!(LaurentPolynomial::from_terms([(1, 1.0), (0, -3.0), (-1, 4.0)]), p.clone() - &q);
+        assert!((p.clone() - p).is_zero());
+        assert_eq!(-q.clone(), q * -1.0);
+    }
+
+    #[test]
+    fn evaluation_at_nonzero_points() {
+        // 2x^2 - 1 + 3x^-1 - x^-3
+        let poly = LaurentPolynomial::from_terms([(2, 2.0), (0, -1.0), (-1, 3.0), (-3, -1.0)]);
+        assert_eq!(Some(8.0 - 1.0 + 1.5 - 0.125), poly.evaluate(2.0));
+        assert_eq!(Some(2.0 - 1.0 - 3.0 + 1.0), poly.evaluate(-1.0));
+        assert_eq!(Some(0.5 - 1.0 + 6.0 - 8.0), poly.evaluate(0.5));
+        assert_eq!(None, poly.evaluate(0.0));
+        assert_eq!(Some(0.0), LaurentPolynomial::zero().evaluate(0.0));
+    }
+
+    #[test]
+    fn derivative_of_negative_powers() {
+        let poly = LaurentPolynomial::from_terms([(-1, 1.0), (-3, 2.0)]);
+        assert_eq!(LaurentPolynomial::from_terms([(-2, -1.0), (-4, -6.0)]), poly.derivative());
+    }
+
+    #[test]
+    fn polynomial_round_trip() {
+        let poly = Polynomial::from_coefficients(&vec![4.0, 0.0, -1.0, 0.5, 0.0]);
+        let laurent = LaurentPolynomial::from(&poly);
+        assert_eq!(Some(poly.clone()), laurent.to_polynomial());
+        assert_eq!((Polynomial::zero(), poly), laurent.split());
+
+        let shifted = laurent * &LaurentPolynomial::from_terms([(-2, 1.0)]);
+        assert_eq!(None, shifted.to_polynomial());
+        assert_eq!(Some(-1), shifted.lowest_power());
+        assert_eq!(Some(2), shifted.highest_power());
+        let (principal, regular) = shifted.split();
+        assert_eq!(vec![0.5, 0.0], principal.get_coefficients());
+        assert_eq!(vec![4.0, 0.0, -1.0], regular.get_coefficients());
+        assert_eq!(Some(Polynomial::zero()), LaurentPolynomial::zero().to_polynomial());
+    }
+
+    #[test]
+    fn display_negative_powers() {
+        let poly = LaurentPolynomial::from_terms([(2, -1.0), (0, 1.5), (-1, -1.0), (-12, 2.0)]);
+        assert_eq!("- x^2 + 1.5 - x^-1 + 2x^-12", poly.to_string());
+        assert_eq!("- x^{2} + 1.5 - x^{-1} + 2x^{-12}", format!("{poly:#}"));
+        assert_eq!(
+            "- x2 + 1.5 - x^-1 + 2x^-12",
+            poly.format_opts(&FormatOptions::from(PolynomialFormat::Concise))
+        );
+        assert_eq!("0", LaurentPolynomial::zero().to_string());
+        assert_eq!("0.33x^-1", format!("{:.2}", LaurentPolynomial::from_terms([(-1, 1.0 / 3.0)])));
+    }
+}
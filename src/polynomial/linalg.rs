@@ -0,0 +1,168 @@
+//! Module containing the conversions between polynomials and [`nalgebra`] vectors and the
+//! matrices of linear maps on polynomials, enabled with the `nalgebra` feature.
+//!
+//! The vectors hold the coefficients in ascending order, i.e. the element at the index `i` is
+//! the coefficient of `x^i`. This is the reverse of the order used by the
+//! `Polynomial.get_coefficients` method, but it keeps the index equal to the power.
+use nalgebra::{DMatrix, DVector};
+use super::Polynomial;
+
+impl Polynomial {
+    /// Constructs a new instance from a vector of coefficients in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nalgebra::DVector;
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_dvector(&DVector::from_vec(vec![-1.0, 0.0, 2.0]));
+    /// assert_eq!(vec![2.0, 0.0, -1.0], poly.get_coefficients());
+    /// ```
+    pub fn from_dvector(coefficients: &DVector<f64>) -> Polynomial {
+        let mut poly = Polynomial::zero();
+        for (power, coefficient) in coefficients.iter().enumerate() {
+            poly.set_coefficient_at(power as u32, *coefficient);
+        }
+        poly
+    }
+
+    /// Returns a vector of the given length with the coefficients in ascending order.
+    ///
+    /// The vector is padded with zeros if the polynomial has fewer coefficients, or truncated
+    /// to the terms of degree less than `len` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nalgebra::DVector;
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![3.0, 2.0, 1.0]);
+    /// assert_eq!(DVector::from_vec(vec![1.0, 2.0, 3.0, 0.0]), poly.to_dvector(4));
+    /// assert_eq!(DVector::from_vec(vec![1.0, 2.0]), poly.to_dvector(2));
+    /// ```
+    pub fn to_dvector(&self, len: usize) -> DVector<f64> {
+        DVector::from_fn(len, |power, _| self.get_coefficient_at(power as u32))
+    }
+
+    /// Returns the matrix of the linear map multiplying the polynomials of degree less than
+    /// `n` by this polynomial, in the coefficient vectors described in the
+    /// [`to_dvector`](Polynomial::to_dvector) method.
+    ///
+    /// The matrix has `deg + n` rows and `n` columns, where `deg` is the degree of the
+    /// polynomial, or zero for the zero polynomial. The column `j` holds the coefficients of
+    /// the product with `x^j`, so the matrix is a Toeplitz matrix of the convolution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let p = Polynomial::from_coefficients(&vec![1.0, -2.0]);
+    /// let q = Polynomial::from_coefficients(&vec![3.0, 0.0, 1.0]);
+    /// let product = p.multiplication_matrix(3) * q.to_dvector(3);
+    /// assert_eq!((p * &q).to_dvector(4), product);
+    /// ```
+    pub fn multiplication_matrix(&self, n: usize) -> DMatrix<f64> {
+        let degree = self.degree().unwrap_or(0) as usize;
+        let mut matrix = DMatrix::zeros(degree + n, n);
+        for column in 0..n {
+            for (power, coefficient) in self.coefficients.iter() {
+                matrix[(*power as usize + column, column)] = *coefficient;
+            }
+        }
+        matrix
+    }
+
+    /// Returns the `n` by `n` matrix of the differentiation of the polynomials of degree less
+    /// than `n`, in the coefficient vectors described in the
+    /// [`to_dvector`](Polynomial::to_dvector) method.
+    ///
+    /// The only non-zero entries are `i + 1` in the row `i` and the column `i + 1`, so the last
+    /// row is zero and the powers of the matrix give the higher derivatives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -2.0, 5.0]);
+    /// let derivative = Polynomial::differentiation_matrix(4) * poly.to_dvector(4);
+    /// assert_eq!(poly.derivative().to_dvector(4), derivative);
+    /// ```
+    pub fn differentiation_matrix(n: usize) -> DMatrix<f64> {
+        let mut matrix = DMatrix::zeros(n, n);
+        for row in 0..n.saturating_sub(1) {
+            matrix[(row, row + 1)] = (row + 1) as f64;
+        }
+        matrix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{DMatrix, DVector};
+    use crate::Polynomial;
+
+    fn sample_polynomials() -> Vec<Polynomial> {
+        vec![
+            Polynomial::zero(),
+            Polynomial::from_coefficients(&vec![4.0]),
+            Polynomial::from_coefficients(&vec![1.0, -1.0]),
+            Polynomial::from_coefficients(&vec![2.0, 0.0, 0.0, -3.0, 0.5]),
+        ]
+    }
+
+    #[test]
+    fn dvector_round_trip() {
+        for poly in sample_polynomials() {
+            let len = poly.degree().map_or(0, |degree| degree as usize + 1);
+            assert_eq!(poly, Polynomial::from_dvector(&poly.to_dvector(len)));
+            assert_eq!(poly, Polynomial::from_dvector(&poly.to_dvector(len + 3)));
+        }
+    }
+
+    #[test]
+    fn to_dvector_pads_and_truncates() {
+        let poly = Polynomial::from_coefficients(&vec![5.0, 0.0, 1.0]);
+        assert_eq!(DVector::from_vec(vec![1.0, 0.0, 5.0, 0.0, 0.0]), poly.to_dvector(5));
+        assert_eq!(DVector::from_vec(vec![1.0]), poly.to_dvector(1));
+        assert_eq!(0, poly.to_dvector(0).len());
+    }
+
+    #[test]
+    fn multiplication_matrix_shape() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, 2.0, 3.0]);
+        let expected = DMatrix::from_row_slice(4, 2, &[
+            3.0, 0.0,
+            2.0, 3.0,
+            1.0, 2.0,
+            0.0, 1.0,
+        ]);
+        assert_eq!(expected, poly.multiplication_matrix(2));
+        assert_eq!((3, 3), Polynomial::zero().multiplication_matrix(3).shape());
+    }
+
+    #[test]
+    fn multiplication_matrix_multiplies() {
+        for p in sample_polynomials() {
+            for q in sample_polynomials() {
+                let n = q.degree().map_or(0, |degree| degree as usize + 1) + 1;
+                let rows = p.degree().unwrap_or(0) as usize + n;
+                let product = p.multiplication_matrix(n) * q.to_dvector(n);
+                assert_eq!((p.clone() * &q).to_dvector(rows), product);
+            }
+        }
+    }
+
+    #[test]
+    fn differentiation_matrix_differentiates() {
+        for poly in sample_polynomials() {
+            let derivative = Polynomial::differentiation_matrix(6) * poly.to_dvector(6);
+            assert_eq!(poly.derivative().to_dvector(6), derivative);
+        }
+        assert_eq!((0, 0), Polynomial::differentiation_matrix(0).shape());
+        assert_eq!(DMatrix::<f64>::zeros(1, 1), Polynomial::differentiation_matrix(1));
+    }
+}
@@ -0,0 +1,570 @@
+use std::error::Error;
+use std::fmt;
+use super::Polynomial;
+
+/// Relative size below which the coefficients of a remainder in the Euclidean algorithm are
+/// treated as rounding errors of a cancelled term.
+pub(super) const TOLERANCE: f64 = 1e-10;
+
+/// Error returned by [`Polynomial::crt`] when a modulus is zero or the moduli are not pairwise
+/// coprime.
+#[derive(PartialEq, Debug, Clone)]
+pub enum CrtError {
+    /// The modulus at the index is the zero polynomial.
+    ZeroModulus {
+        index: usize,
+    },
+
+    /// The moduli at the indices have a common factor, their monic greatest common divisor.
+    NotCoprime {
+        first: usize,
+        second: usize,
+        gcd: Polynomial,
+    },
+}
+
+impl fmt::Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrtError::ZeroModulus { index } => write!(f, "Modulus {index} is zero."),
+            CrtError::NotCoprime { first, second, gcd } => {
+                write!(f, "Moduli {first} and {second} have a common factor {gcd}.")
+            }
+        }
+    }
+}
+
+impl Error for CrtError {}
+
+pub(super) fn max_abs_coefficient(poly: &Polynomial) -> f64 {
+    poly.coefficients.values().fold(0.0, |max, coefficient| max.max(coefficient.abs()))
+}
+
+/// Returns the monic greatest common divisor `g` of `a` and `b`, and the Bézout coefficients
+/// `s` and `t` such that `s * a + t * b = g`.
+///
+/// The coefficients of every remainder smaller than [`TOLERANCE`] relative to the largest
+/// coefficient of the inputs are dropped, so that terms cancelled up to rounding errors do not
+/// prolong the algorithm. If both inputs are zero, all three results are zero.
+pub(super) fn extended_gcd(a: &Polynomial, b: &Polynomial) -> (Polynomial, Polynomial, Polynomial) {
+    let threshold = TOLERANCE * max_abs_coefficient(a).max(max_abs_coefficient(b));
+    let (mut r0, mut r1) = (a.clone(), b.clone());
+    let (mut s0, mut s1) = (Polynomial::one(), Polynomial::zero());
+    let (mut t0, mut t1) = (Polynomial::zero(), Polynomial::one());
+
+    while !r1.is_zero() {
+        let result = r0 / &r1;
+        let mut remainder = result.remainder;
+        remainder.coefficients.retain(|_, coefficient| coefficient.abs() > threshold);
+
+        r0 = std::mem::replace(&mut r1, remainder);
+        let s2 = s0 - &(result.quotient.clone() * &s1);
+        s0 = std::mem::replace(&mut s1, s2);
+        let t2 = t0 - &(result.quotient * &t1);
+        t0 = std::mem::replace(&mut t1, t2);
+    }
+
+    match r0.degree() {
+        Some(degree) => {
+            let leading_coefficient = r0.get_coefficient_at(degree);
+            (r0 / leading_coefficient, s0 / leading_coefficient, t0 / leading_coefficient)
+        }
+        None => (Polynomial::zero(), Polynomial::zero(), Polynomial::zero()),
+    }
+}
+
+/// Folds the terms into the powers below `n`, using `x^n = wrap_sign`, i.e. reducing modulo
+/// `x^n - wrap_sign`.
+///
+/// The sums are accumulated in a dense buffer if it is not much longer than the number of the
+/// terms, given with the largest power, and otherwise in the sparse polynomial, so that a
+/// sparse input does not allocate a buffer of length `n`.
+fn fold_terms(
+    terms: impl Iterator<Item = (u64, f64)>,
+    term_count: usize,
+    max_power: u64,
+    n: u32,
+    wrap_sign: f64
+) -> Polynomial {
+    assert!(n > 0, "Invalid degree of the modulus");
+    let fold = |power: u64| {
+        let (quotient, remainder) = (power / n as u64, power % n as u64);
+        (remainder as u32, if quotient % 2 == 1 { wrap_sign } else { 1.0 })
+    };
+    let mut result = Polynomial::zero();
+    let length = (max_power + 1).min(n as u64);
+    if length > 2 * term_count as u64 {
+        for (power, coefficient) in terms {
+            let (power, sign) = fold(power);
+            result.add_coefficient_at(power, sign * coefficient);
+        }
+        return result;
+    }
+    let mut folded = vec![0.0; length as usize];
+    for (power, coefficient) in terms {
+        let (power, sign) = fold(power);
+        folded[power as usize] += sign * coefficient;
+    }
+    for (power, coefficient) in folded.into_iter().enumerate() {
+        result.set_coefficient_at(power as u32, coefficient);
+    }
+    result
+}
+
+/// Returns the iterator over the terms of the polynomial with the powers widened to `u64`,
+/// the number of the terms and the degree, as taken by `fold_terms`.
+fn terms_of(poly: &Polynomial) -> (impl Iterator<Item = (u64, f64)> + '_, usize, u64) {
+    let terms = poly.coefficients.iter().map(|(power, coefficient)| (*power as u64, *coefficient));
+    (terms, poly.coefficients.len(), poly.degree().unwrap_or(0) as u64)
+}
+
+/// Multiplies the polynomials term by term and folds the products as `fold_terms` does.
+fn mul_folded(a: &Polynomial, b: &Polynomial, n: u32, wrap_sign: f64) -> Polynomial {
+    let products = a.coefficients.iter().flat_map(|(power, coefficient)| {
+        b.coefficients.iter().map(move |(other_power, other_coefficient)| {
+            (*power as u64 + *other_power as u64, coefficient * other_coefficient)
+        })
+    });
+    let product_count = a.coefficients.len() * b.coefficients.len();
+    let max_power = a.degree().unwrap_or(0) as u64 + b.degree().unwrap_or(0) as u64;
+    fold_terms(products, product_count, max_power, n, wrap_sign)
+}
+
+impl Polynomial {
+    /// Returns the remainder of the polynomial divided by `x^n + 1`, i.e. its image in the
+    /// negacyclic ring `R[x] / (x^n + 1)`.
+    ///
+    /// Since `x^n = -1`, every term `a x^k` is folded into `(-1)^(k / n) a x^(k mod n)`, which
+    /// is faster than the general division.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // x^5 + 2x^3 + 3 = -x - 2x^3 + 3 modulo x^4 + 1
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 2.0, 0.0, 0.0, 3.0]);
+    /// assert_eq!(vec![2.0, 0.0, -1.0, 3.0], poly.reduce_negacyclic(4).get_coefficients());
+    /// ```
+    pub fn reduce_negacyclic(&self, n: u32) -> Polynomial {
+        let (terms, term_count, degree) = terms_of(self);
+        fold_terms(terms, term_count, degree, n, -1.0)
+    }
+
+    /// Returns the remainder of the polynomial divided by `x^n - 1`, i.e. its image in the
+    /// cyclic ring `R[x] / (x^n - 1)`, where every term `a x^k` is folded into `a x^(k mod n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 2.0, 0.0, 0.0, 3.0]);
+    /// assert_eq!(vec![2.0, 0.0, 1.0, 3.0], poly.reduce_cyclic(4).get_coefficients());
+    /// ```
+    pub fn reduce_cyclic(&self, n: u32) -> Polynomial {
+        let (terms, term_count, degree) = terms_of(self);
+        fold_terms(terms, term_count, degree, n, 1.0)
+    }
+
+    /// Returns the product of the polynomials modulo `x^n + 1`.
+    ///
+    /// The products of the terms are folded as by
+    /// [`reduce_negacyclic`](Polynomial::reduce_negacyclic) as they are computed, so the full
+    /// product is never built.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // (x^3 + 1)(x^2 + x) = x^5 + x^4 + x^2 + x = -x - 1 + x^2 + x modulo x^4 + 1
+    /// let p = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0, 1.0]);
+    /// let q = Polynomial::from_coefficients(&vec![1.0, 1.0, 0.0]);
+    /// assert_eq!(vec![1.0, 0.0, -1.0], p.mul_negacyclic(&q, 4).get_coefficients());
+    /// ```
+    pub fn mul_negacyclic(&self, other: &Polynomial, n: u32) -> Polynomial {
+        mul_folded(self, other, n, -1.0)
+    }
+
+    /// Returns the product of the polynomials modulo `x^n - 1`, i.e. the cyclic convolution of
+    /// their coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let p = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0, 1.0]);
+    /// let q = Polynomial::from_coefficients(&vec![1.0, 1.0, 0.0]);
+    /// assert_eq!(vec![1.0, 2.0, 1.0], p.mul_cyclic(&q, 4).get_coefficients());
+    /// ```
+    pub fn mul_cyclic(&self, other: &Polynomial, n: u32) -> Polynomial {
+        mul_folded(self, other, n, 1.0)
+    }
+
+    /// Returns the remainder of the product of the polynomials divided by the modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let p = Polynomial::from_coefficients(&vec![1.0, 1.0]);
+    /// let modulus = Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]);
+    /// // (x + 1)^2 = 2x modulo x^2 + 1
+    /// assert_eq!(vec![2.0, 0.0], p.mul_mod(&p, &modulus).get_coefficients());
+    /// ```
+    pub fn mul_mod(&self, other: &Polynomial, modulus: &Polynomial) -> Polynomial {
+        (self.clone() * other) % modulus
+    }
+
+    /// Returns the greatest common divisor `g` of the polynomials and the Bézout coefficients
+    /// `s` and `t` such that `s * self + t * other = g`, as `(g, s, t)`.
+    ///
+    /// The coefficients are found with the extended Euclidean algorithm, which treats the
+    /// coefficients of the remainders negligible relative to the inputs as zero. The greatest
+    /// common divisor is monic, with `s` and `t` divided by the same leading coefficient. If
+    /// one of the polynomials is zero, the other one is the greatest common divisor, with the
+    /// coefficient of the zero polynomial zero, and if both are zero, all three results are
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // (x - 1)(x + 1) and (x - 1)(x + 3)
+    /// let a = Polynomial::from_coefficients(&vec![1.0, 0.0, -1.0]);
+    /// let b = Polynomial::from_coefficients(&vec![1.0, 2.0, -3.0]);
+    /// let (gcd, s, t) = a.extended_gcd(&b);
+    /// assert_eq!(vec![1.0, -1.0], gcd.get_coefficients());
+    /// assert_eq!(gcd, s * &a + &(t * &b));
+    /// ```
+    pub fn extended_gcd(&self, other: &Polynomial) -> (Polynomial, Polynomial, Polynomial) {
+        extended_gcd(self, other)
+    }
+
+    /// Returns the inverse of the polynomial modulo the modulus, i.e. the polynomial `Q` of
+    /// degree less than the modulus such that `P * Q = 1` modulo `M`, or `None` if the
+    /// polynomial and the modulus are not coprime.
+    ///
+    /// The inverse is found with the extended Euclidean algorithm, which treats the
+    /// coefficients negligible relative to the inputs as zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let modulus = Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]);
+    /// let p = Polynomial::from_coefficients(&vec![1.0, 0.0]);
+    /// // x * (-x) = -x^2 = 1 modulo x^2 + 1
+    /// assert_eq!(vec![-1.0, 0.0], p.inverse_mod(&modulus).unwrap().get_coefficients());
+    ///
+    /// let factor = Polynomial::from_coefficients(&vec![1.0, 1.0]);
+    /// let modulus = factor.clone() * &Polynomial::from_coefficients(&vec![1.0, 2.0]);
+    /// assert_eq!(None, factor.inverse_mod(&modulus));
+    /// ```
+    pub fn inverse_mod(&self, modulus: &Polynomial) -> Option<Polynomial> {
+        let reduced = self.clone() % modulus;
+        if modulus.degree() == Some(0) {
+            return Some(Polynomial::zero());
+        }
+        let (gcd, inverse, _) = extended_gcd(&reduced, modulus);
+        (gcd.degree() == Some(0)).then(|| inverse % modulus)
+    }
+
+    /// Returns the unique polynomial of degree less than the sum of the degrees of the moduli
+    /// which is congruent to every residue modulo its modulus, given as (modulus, residue)
+    /// pairs.
+    ///
+    /// The solution is built incrementally: after the first `i` pairs are solved by `P` with
+    /// the product of their moduli `M`, the next pair `(m, r)` is solved by
+    /// `P + M * ((r - P) * M⁻¹ mod m)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CrtError`] if a modulus is zero or two moduli have a common factor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // P(1) = 2 and P(x) = x modulo x^2 + 1
+    /// let pairs = [
+    ///     (Polynomial::from_coefficients(&vec![1.0, -1.0]), Polynomial::from_coefficients(&vec![2.0])),
+    ///     (Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]), Polynomial::from_coefficients(&vec![1.0, 0.0])),
+    /// ];
+    /// let poly = Polynomial::crt(&pairs).unwrap();
+    /// assert_eq!(vec![0.5, 1.0, 0.5], poly.get_coefficients());
+    /// ```
+    pub fn crt(pairs: &[(Polynomial, Polynomial)]) -> Result<Polynomial, CrtError> {
+        for (index, (modulus, _)) in pairs.iter().enumerate() {
+            if modulus.is_zero() {
+                return Err(CrtError::ZeroModulus { index });
+            }
+        }
+        for (first, (first_modulus, _)) in pairs.iter().enumerate() {
+            for (second, (second_modulus, _)) in pairs.iter().enumerate().skip(first + 1) {
+                let (gcd, _, _) = extended_gcd(first_modulus, second_modulus);
+                if gcd.degree() != Some(0) {
+                    return Err(CrtError::NotCoprime { first, second, gcd });
+                }
+            }
+        }
+
+        let mut result = Polynomial::zero();
+        let mut product = Polynomial::one();
+        for (modulus, residue) in pairs {
+            let inverse = product.inverse_mod(modulus).expect("the moduli are coprime");
+            let difference = residue.clone() - &result;
+            result += &(product.clone() * &difference.mul_mod(&inverse, modulus));
+            product *= modulus;
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::{extended_gcd, max_abs_coefficient, CrtError};
+    use crate::Polynomial;
+    use crate::polynomial::strategy::polynomials_with;
+
+    fn assert_approx_eq(expected: &Polynomial, actual: &Polynomial) {
+        let degree = expected.degree().max(actual.degree()).unwrap_or(0);
+        for power in 0..=degree {
+            let (expected, actual) = (expected.get_coefficient_at(power), actual.get_coefficient_at(power));
+            assert!((expected - actual).abs() < 1e-9, "x^{power}: {expected} != {actual}");
+        }
+    }
+
+    #[test]
+    fn extended_gcd_works() {
+        let common = Polynomial::from_coefficients(&vec![2.0, -1.0]);
+        let a = common.clone() * &Polynomial::from_coefficients(&vec![1.0, 3.0]);
+        let b = common * &Polynomial::from_coefficients(&vec![1.0, 0.0, 5.0]);
+        let (gcd, s, t) = extended_gcd(&a, &b);
+        assert_approx_eq(&Polynomial::from_coefficients(&vec![1.0, -0.5]), &gcd);
+        assert_approx_eq(&gcd, &(s * &a + &(t * &b)));
+    }
+
+    #[test]
+    fn extended_gcd_of_zero() {
+        let a = Polynomial::from_coefficients(&vec![2.0, 4.0]);
+        let (gcd, s, t) = extended_gcd(&a, &Polynomial::zero());
+        assert_eq!(vec![1.0, 2.0], gcd.get_coefficients());
+        assert_eq!(vec![0.5], s.get_coefficients());
+        assert!(t.is_zero());
+    }
+
+    #[test]
+    fn extended_gcd_of_zeros() {
+        let b = Polynomial::from_coefficients(&vec![-4.0, 2.0, 8.0]);
+        let (gcd, s, t) = Polynomial::zero().extended_gcd(&b);
+        assert_eq!(vec![1.0, -0.5, -2.0], gcd.get_coefficients());
+        assert!(s.is_zero());
+        assert_eq!(vec![-0.25], t.get_coefficients());
+
+        let zero = Polynomial::zero();
+        assert_eq!((zero.clone(), zero.clone(), zero.clone()), zero.extended_gcd(&zero));
+    }
+
+    #[test]
+    fn extended_gcd_of_coprime_polynomials() {
+        let a = Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]);
+        let b = Polynomial::from_coefficients(&vec![1.0, -1.0]);
+        let (gcd, s, t) = a.extended_gcd(&b);
+        assert_eq!(Polynomial::one(), gcd);
+        assert_approx_eq(&gcd, &(s * &a + &(t * &b)));
+        // A nonzero constant divides everything
+        let (gcd, s, t) = a.extended_gcd(&Polynomial::from_coefficients(&vec![4.0]));
+        assert_eq!((Polynomial::one(), Polynomial::zero(), Polynomial::from_coefficients(&vec![0.25])), (gcd, s, t));
+    }
+
+    #[test]
+    fn inverse_mod_works() {
+        let modulus = Polynomial::from_coefficients(&vec![1.0, 0.0, -3.0, 1.0]);
+        let p = Polynomial::from_coefficients(&vec![1.0, 2.0, 0.5]);
+        let inverse = p.inverse_mod(&modulus).unwrap();
+        assert!(inverse.degree().unwrap() < 3);
+        assert_approx_eq(&Polynomial::one(), &p.mul_mod(&inverse, &modulus));
+    }
+
+    #[test]
+    fn crt_with_quadratic_moduli() {
+        let pairs = [
+            (Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]), Polynomial::from_coefficients(&vec![1.0, -2.0])),
+            (Polynomial::from_coefficients(&vec![1.0, -0.5, 0.25]), Polynomial::from_coefficients(&vec![0.5])),
+            (Polynomial::from_coefficients(&vec![1.0, 3.0, -1.5]), Polynomial::from_coefficients(&vec![-2.0, 0.75])),
+        ];
+        let poly = Polynomial::crt(&pairs).unwrap();
+        assert!(poly.degree().unwrap() < 6);
+        for (modulus, residue) in &pairs {
+            assert_approx_eq(residue, &(poly.clone() % modulus));
+        }
+    }
+
+    #[test]
+    fn crt_with_linear_moduli_is_lagrange_interpolation() {
+        let points = [(-1.0, 2.0), (0.0, 1.0), (2.0, -3.0), (3.0, 0.5)];
+        let pairs: Vec<(Polynomial, Polynomial)> = points.iter()
+            .map(|(x, y)| (Polynomial::from_coefficients(&vec![1.0, -x]), Polynomial::from_coefficients(&vec![*y])))
+            .collect();
+
+        let mut lagrange = Polynomial::zero();
+        for (i, (xi, yi)) in points.iter().enumerate() {
+            let mut basis = Polynomial::from_coefficients(&vec![*yi]);
+            for (j, (xj, _)) in points.iter().enumerate() {
+                if i != j {
+                    basis = basis * &Polynomial::from_coefficients(&vec![1.0, -xj]) / (xi - xj);
+                }
+            }
+            lagrange += &basis;
+        }
+
+        assert_approx_eq(&lagrange, &Polynomial::crt(&pairs).unwrap());
+    }
+
+    #[test]
+    fn crt_rejects_moduli_with_common_factor() {
+        let common = Polynomial::from_coefficients(&vec![1.0, -2.0]);
+        let pairs = [
+            (Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]), Polynomial::one()),
+            (common.clone() * &Polynomial::from_coefficients(&vec![2.0, 1.0]), Polynomial::one()),
+            (common.clone() * &Polynomial::from_coefficients(&vec![1.0, 5.0]), Polynomial::zero()),
+        ];
+        let Err(CrtError::NotCoprime { first, second, gcd }) = Polynomial::crt(&pairs) else {
+            panic!("expected a NotCoprime error");
+        };
+        assert_eq!((1, 2), (first, second));
+        assert_approx_eq(&common, &gcd);
+    }
+
+    #[test]
+    fn crt_rejects_zero_modulus() {
+        let pairs = [(Polynomial::zero(), Polynomial::one())];
+        assert_eq!(Err(CrtError::ZeroModulus { index: 0 }), Polynomial::crt(&pairs));
+        assert_eq!(Ok(Polynomial::zero()), Polynomial::crt(&[]));
+    }
+
+    /// Returns `x^n + sign`.
+    fn binomial_modulus(n: u32, sign: f64) -> Polynomial {
+        let mut modulus = Polynomial::from_coefficients(&vec![sign]);
+        modulus.set_coefficient_at(n, 1.0);
+        modulus
+    }
+
+    #[test]
+    fn reduction_of_high_powers() {
+        let mut poly = Polynomial::zero();
+        poly.set_coefficient_at(u32::MAX, 2.0);
+        poly.set_coefficient_at(7, 1.0);
+        // 2^32 - 1 = 4 (2^30 - 1) + 3 and 7 = 4 + 3 have odd quotients
+        assert_eq!(vec![-3.0, 0.0, 0.0, 0.0], poly.reduce_negacyclic(4).get_coefficients());
+        assert_eq!(vec![3.0, 0.0, 0.0, 0.0], poly.reduce_cyclic(4).get_coefficients());
+        assert_eq!(Polynomial::zero(), Polynomial::zero().reduce_negacyclic(8));
+    }
+
+    #[test]
+    fn sparse_reduction_modulo_huge_binomials() {
+        let x = Polynomial::from_coefficients(&vec![1.0, 0.0]);
+        assert_eq!(x, x.reduce_cyclic(u32::MAX));
+        assert_eq!(x, x.reduce_negacyclic(u32::MAX));
+
+        let mut poly = Polynomial::zero();
+        poly.set_coefficient_at(u32::MAX - 1, 3.0);
+        poly.set_coefficient_at(5, -1.0);
+        assert_eq!(poly, poly.reduce_negacyclic(u32::MAX));
+
+        // (3x^(n - 1) - x^5)^2 = 9x^(2n - 2) - 6x^(n + 4) + x^10 = -9x^(n - 2) + 6x^4 + x^10
+        // modulo x^n + 1
+        let mut expected = Polynomial::zero();
+        expected.set_coefficient_at(u32::MAX - 2, -9.0);
+        expected.set_coefficient_at(4, 6.0);
+        expected.set_coefficient_at(10, 1.0);
+        assert_eq!(expected, poly.mul_negacyclic(&poly, u32::MAX));
+    }
+
+    #[test]
+    fn terms_cancel_after_folding() {
+        assert_eq!(Polynomial::zero(), binomial_modulus(4, 1.0).reduce_negacyclic(4));
+        assert_eq!(Polynomial::zero(), binomial_modulus(4, -1.0).reduce_cyclic(4));
+
+        let square = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0]);
+        assert_eq!(Polynomial::from_coefficients(&vec![-1.0]), square.mul_negacyclic(&square, 4));
+        assert_eq!(Polynomial::one(), square.mul_cyclic(&square, 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid degree of the modulus")]
+    fn reduction_modulo_constant() {
+        Polynomial::one().reduce_negacyclic(0);
+    }
+
+    proptest! {
+        #[test]
+        fn negacyclic_matches_remainder(
+            p in polynomials_with((-9..=9).prop_map(f64::from), 24),
+            q in polynomials_with((-9..=9).prop_map(f64::from), 24),
+            n in 1u32..12,
+        ) {
+            let modulus = binomial_modulus(n, 1.0);
+            prop_assert_eq!(p.clone() % &modulus, p.reduce_negacyclic(n));
+            prop_assert_eq!(p.mul_mod(&q, &modulus), p.mul_negacyclic(&q, n));
+        }
+
+        #[test]
+        fn cyclic_matches_remainder(
+            p in polynomials_with((-9..=9).prop_map(f64::from), 24),
+            q in polynomials_with((-9..=9).prop_map(f64::from), 24),
+            n in 1u32..12,
+        ) {
+            let modulus = binomial_modulus(n, -1.0);
+            prop_assert_eq!(p.clone() % &modulus, p.reduce_cyclic(n));
+            prop_assert_eq!(p.mul_mod(&q, &modulus), p.mul_cyclic(&q, n));
+        }
+
+        #[test]
+        fn bezout_identity(
+            p in polynomials_with((-9..=9).prop_map(f64::from), 8),
+            q in polynomials_with((-9..=9).prop_map(f64::from), 8),
+            common in polynomials_with((-3..=3).prop_map(f64::from), 2),
+        ) {
+            let (a, b) = (p * &common, q * &common);
+            let (gcd, s, t) = a.extended_gcd(&b);
+            prop_assert!(gcd.is_zero() || gcd.get_coefficient_at(gcd.degree().unwrap()) == 1.0);
+            // The rounding errors grow with the magnitude of the Bézout coefficients
+            let scale = max_abs_coefficient(&s) * max_abs_coefficient(&a) + max_abs_coefficient(&t) * max_abs_coefficient(&b);
+            let residual = s * &a + &(t * &b) - &gcd;
+            prop_assert!(max_abs_coefficient(&residual) <= 1e-9 * scale.max(1.0), "{residual}");
+        }
+    }
+}
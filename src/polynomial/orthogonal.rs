@@ -0,0 +1,432 @@
+use super::Polynomial;
+
+/// Relative squared norm below which an orthogonalized polynomial is considered linearly
+/// dependent on the previous ones.
+const DEPENDENCE_TOLERANCE: f64 = 1e-20;
+
+/// Returns the definite integral of the polynomial from `a` to `b`.
+pub(super) fn definite_integral(poly: &Polynomial, a: f64, b: f64) -> f64 {
+    poly.coefficients
+        .iter()
+        .map(|(power, coefficient)| {
+            // Computed in f64, as the power increased by one may not fit into i32
+            let exponent = f64::from(*power) + 1.0;
+            coefficient * (b.powf(exponent) - a.powf(exponent)) / exponent
+        })
+        .sum()
+}
+
+/// Solves the linear least squares problem `min |A x - b|` with the Householder QR
+/// decomposition, where `A` is given by its rows and has full column rank.
+fn solve_least_squares(mut rows: Vec<Vec<f64>>, mut b: Vec<f64>) -> Vec<f64> {
+    let columns = rows.first().map_or(0, Vec::len);
+    for k in 0..columns {
+        // The reflection maps the k-th column below the diagonal onto the diagonal
+        let norm = rows[k..].iter().map(|row| row[k] * row[k]).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+        let alpha = if rows[k][k] > 0.0 { -norm } else { norm };
+        let mut v: Vec<f64> = rows[k..].iter().map(|row| row[k]).collect();
+        v[0] -= alpha;
+        let v_norm_squared: f64 = v.iter().map(|value| value * value).sum();
+        if v_norm_squared == 0.0 {
+            continue;
+        }
+        for j in k..columns {
+            let dot: f64 = v.iter().zip(&rows[k..]).map(|(value, row)| value * row[j]).sum();
+            let factor = 2.0 * dot / v_norm_squared;
+            for (value, row) in v.iter().zip(&mut rows[k..]) {
+                row[j] -= factor * value;
+            }
+        }
+        let dot: f64 = v.iter().zip(&b[k..]).map(|(value, b)| value * b).sum();
+        let factor = 2.0 * dot / v_norm_squared;
+        for (value, b) in v.iter().zip(&mut b[k..]) {
+            *b -= factor * value;
+        }
+    }
+
+    // Back substitution with the upper triangular R
+    let mut x = vec![0.0; columns];
+    for k in (0..columns).rev() {
+        let sum: f64 = (k + 1..columns).map(|j| rows[k][j] * x[j]).sum();
+        x[k] = (b[k] - sum) / rows[k][k];
+    }
+    x
+}
+
+impl Polynomial {
+    /// Returns the coefficients `c_0, ..., c_n` of the least squares fit of the points by the sum
+    /// of `c_k T_k(t)`, where `T_k` are the Chebyshev polynomials of the first kind and `t` is
+    /// the variable `x` mapped linearly from the interval onto `[-1, 1]`.
+    ///
+    /// The design matrix of the Chebyshev polynomials is well-conditioned for the points spread
+    /// over the interval, so the fit stays accurate for high degrees, unlike in the monomial
+    /// basis. The problem is solved with the QR decomposition.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval is empty, or if there are fewer points than `degree + 1`. The
+    /// points must have at least `degree + 1` distinct `x` coordinates for the fit to be unique.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // 2x^2 - 1 = T_2(x)
+    /// let points = [(-1.0, 1.0), (-0.5, -0.5), (0.0, -1.0), (0.5, -0.5), (1.0, 1.0)];
+    /// let coefficients = Polynomial::chebyshev_fit_coefficients(&points, 2, (-1.0, 1.0));
+    /// assert!((coefficients[2] - 1.0).abs() < 1e-12);
+    /// assert!(coefficients[0].abs() < 1e-12 && coefficients[1].abs() < 1e-12);
+    /// ```
+    pub fn chebyshev_fit_coefficients(points: &[(f64, f64)], degree: u32, interval: (f64, f64)) -> Vec<f64> {
+        let (a, b) = interval;
+        assert!(a < b, "Invalid interval");
+        let columns = degree as usize + 1;
+        assert!(points.len() >= columns, "Not enough points for the degree");
+
+        let rows = points
+            .iter()
+            .map(|(x, _)| {
+                let t = (2.0 * x - a - b) / (b - a);
+                let mut row = Vec::with_capacity(columns);
+                row.push(1.0);
+                if columns > 1 {
+                    row.push(t);
+                }
+                for k in 2..columns {
+                    row.push(2.0 * t * row[k - 1] - row[k - 2]);
+                }
+                row
+            })
+            .collect();
+        let values = points.iter().map(|(_, y)| *y).collect();
+        solve_least_squares(rows, values)
+    }
+
+    /// Returns the least squares fit of the points by a polynomial of the given degree, found in
+    /// the Chebyshev basis on the interval and converted to the monomial basis.
+    ///
+    /// See [`chebyshev_fit_coefficients`](Polynomial::chebyshev_fit_coefficients) for the fit.
+    /// The conversion to the monomial basis is done with Clenshaw's recurrence. It is exact in
+    /// exact arithmetic, but the monomial coefficients of a high degree polynomial on an
+    /// interval far from `[-1, 1]` are sensitive to the rounding errors, so the Chebyshev
+    /// coefficients should be kept if that matters.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as `chebyshev_fit_coefficients`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let points: Vec<(f64, f64)> = (0..=20).map(|i| {
+    ///     let x = i as f64 / 10.0;
+    ///     (x, x * x - 3.0 * x + 1.0)
+    /// }).collect();
+    /// let poly = Polynomial::fit_chebyshev(&points, 2, (0.0, 2.0));
+    /// assert!((poly.get_coefficient_at(1) + 3.0).abs() < 1e-12);
+    /// ```
+    pub fn fit_chebyshev(points: &[(f64, f64)], degree: u32, interval: (f64, f64)) -> Polynomial {
+        let coefficients = Polynomial::chebyshev_fit_coefficients(points, degree, interval);
+        let (a, b) = interval;
+        let t = Polynomial::from_coefficients(&vec![2.0 / (b - a), -(a + b) / (b - a)]);
+
+        // Clenshaw's recurrence b_k = c_k + 2t b_(k+1) - b_(k+2), the sum is c_0 + t b_1 - b_2
+        let (mut next, mut after_next) = (Polynomial::zero(), Polynomial::zero());
+        for coefficient in coefficients[1..].iter().rev() {
+            let current = t.clone() * &next * 2.0 - &after_next + *coefficient;
+            after_next = std::mem::replace(&mut next, current);
+        }
+        t * &next - &after_next + coefficients[0]
+    }
+
+    /// Returns the inner product `∫ P(x) Q(x) dx` on the interval from `a` to `b`.
+    ///
+    /// The integral is computed exactly from the coefficients of the product, up to the
+    /// rounding errors of the floating point arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let x = Polynomial::from_coefficients(&vec![1.0, 0.0]);
+    /// assert_eq!(2.0 / 3.0, x.inner_product(&x, -1.0, 1.0));
+    /// assert_eq!(0.0, x.inner_product(&Polynomial::one(), -1.0, 1.0));
+    /// ```
+    pub fn inner_product(&self, other: &Polynomial, a: f64, b: f64) -> f64 {
+        definite_integral(&(self.clone() * other), a, b)
+    }
+
+    /// Returns the weighted inner product `∫ P(x) Q(x) W(x) dx` on the interval from `a` to
+    /// `b`, with the polynomial weight `W`.
+    ///
+    /// The weight should be nonnegative on the interval for the result to be an inner product.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let weight = Polynomial::from_coefficients(&vec![1.0, 0.0]);
+    /// let one = Polynomial::one();
+    /// assert_eq!(0.5, one.weighted_inner_product(&one, &weight, 0.0, 1.0));
+    /// ```
+    pub fn weighted_inner_product(&self, other: &Polynomial, weight: &Polynomial, a: f64, b: f64) -> f64 {
+        definite_integral(&(self.clone() * other * weight), a, b)
+    }
+
+    /// Orthogonalizes the polynomials with respect to the inner product on the interval from
+    /// `a` to `b` with the modified Gram–Schmidt process.
+    ///
+    /// The `i`-th returned polynomial is the `i`-th polynomial of the basis minus its
+    /// projections on the previous ones, so the results are orthogonal but not normalized.
+    /// A polynomial which is linearly dependent on the previous ones, up to a relative
+    /// tolerance of its squared norm, is dropped, so fewer polynomials than given may be
+    /// returned.
+    ///
+    /// The inner products are computed in the monomial basis, whose conditioning gets worse
+    /// with the degree, so the orthogonality of the results degrades for high degrees.
+    ///
+    /// # Examples
+    ///
+    /// The monomials on `[-1, 1]` are orthogonalized into the monic Legendre polynomials:
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let monomials = [
+    ///     Polynomial::from_coefficients(&vec![1.0]),
+    ///     Polynomial::from_coefficients(&vec![1.0, 0.0]),
+    ///     Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0]),
+    /// ];
+    /// let legendre = Polynomial::gram_schmidt(&monomials, -1.0, 1.0);
+    /// assert_eq!(vec![1.0, 0.0, -1.0 / 3.0], legendre[2].get_coefficients());
+    /// ```
+    pub fn gram_schmidt(basis: &[Polynomial], a: f64, b: f64) -> Vec<Polynomial> {
+        let mut orthogonal: Vec<(Polynomial, f64)> = Vec::with_capacity(basis.len());
+        for poly in basis {
+            let norm_squared = poly.inner_product(poly, a, b);
+            let mut result = poly.clone();
+            for (previous, previous_norm_squared) in &orthogonal {
+                let projection = result.inner_product(previous, a, b) / previous_norm_squared;
+                result -= &(previous.clone() * projection);
+            }
+            let result_norm_squared = result.inner_product(&result, a, b);
+            if result_norm_squared > DEPENDENCE_TOLERANCE * norm_squared {
+                orthogonal.push((result, result_norm_squared));
+            }
+        }
+        orthogonal.into_iter().map(|(poly, _)| poly).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Polynomial;
+
+    fn monomial(power: u32) -> Polynomial {
+        let mut poly = Polynomial::zero();
+        poly.set_coefficient_at(power, 1.0);
+        poly
+    }
+
+    fn assert_approx_eq(expected: &[f64], actual: &Polynomial) {
+        let coefficients = actual.get_coefficients();
+        assert_eq!(expected.len(), coefficients.len(), "{actual}");
+        for (expected, actual) in expected.iter().zip(coefficients) {
+            assert!((expected - actual).abs() < 1e-12, "{expected} != {actual}");
+        }
+    }
+
+    #[test]
+    fn inner_product_works() {
+        let p = Polynomial::from_coefficients(&vec![1.0, 1.0]);
+        let q = Polynomial::from_coefficients(&vec![2.0, 0.0, -1.0]);
+        // ∫ (2x^3 + 2x^2 - x - 1) dx from 0 to 2 = 8 + 16/3 - 2 - 2
+        assert!((p.inner_product(&q, 0.0, 2.0) - 28.0 / 3.0).abs() < 1e-12);
+        assert_eq!(p.inner_product(&q, 0.0, 2.0), q.inner_product(&p, 0.0, 2.0));
+        assert_eq!(0.0, p.inner_product(&Polynomial::zero(), 0.0, 2.0));
+        assert_eq!(-p.inner_product(&q, 0.0, 2.0), p.inner_product(&q, 2.0, 0.0));
+    }
+
+    #[test]
+    fn inner_product_of_powers_beyond_i32() {
+        // ∫ x^(2^31) dx from -1 to 1 = 2 / (2^31 + 1)
+        let x = monomial(1 << 31);
+        let expected = 2.0 / (2f64.powi(31) + 1.0);
+        assert_eq!(expected, x.inner_product(&Polynomial::one(), -1.0, 1.0));
+        assert_eq!(expected, monomial(1 << 30).inner_product(&monomial(1 << 30), -1.0, 1.0));
+        assert_eq!(2f64.powi(-32), monomial(u32::MAX).inner_product(&Polynomial::one(), 0.0, 1.0));
+    }
+
+    #[test]
+    fn weighted_inner_product_works() {
+        let x = monomial(1);
+        let weight = Polynomial::from_coefficients(&vec![-1.0, 0.0, 1.0]);
+        // ∫ x^2 (1 - x^2) dx from -1 to 1 = 2/3 - 2/5
+        assert!((x.weighted_inner_product(&x, &weight, -1.0, 1.0) - 4.0 / 15.0).abs() < 1e-12);
+        assert_eq!(
+            x.inner_product(&x, -1.0, 1.0),
+            x.weighted_inner_product(&x, &Polynomial::one(), -1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn gram_schmidt_produces_legendre_polynomials() {
+        let monomials: Vec<Polynomial> = (0..5).map(monomial).collect();
+        let legendre = Polynomial::gram_schmidt(&monomials, -1.0, 1.0);
+        assert_eq!(5, legendre.len());
+        assert_approx_eq(&[1.0], &legendre[0]);
+        assert_approx_eq(&[1.0, 0.0], &legendre[1]);
+        assert_approx_eq(&[1.0, 0.0, -1.0 / 3.0], &legendre[2]);
+        assert_approx_eq(&[1.0, 0.0, -3.0 / 5.0, 0.0], &legendre[3]);
+        assert_approx_eq(&[1.0, 0.0, -6.0 / 7.0, 0.0, 3.0 / 35.0], &legendre[4]);
+    }
+
+    #[test]
+    fn gram_schmidt_results_are_orthogonal() {
+        // The monomials are badly conditioned, the relative error grows to about 1e-9 here
+        let basis: Vec<Polynomial> = (0..8).map(monomial).collect();
+        let orthogonal = Polynomial::gram_schmidt(&basis, 0.0, 3.0);
+        for (i, p) in orthogonal.iter().enumerate() {
+            for q in &orthogonal[..i] {
+                let scale = p.inner_product(p, 0.0, 3.0).sqrt() * q.inner_product(q, 0.0, 3.0).sqrt();
+                assert!(p.inner_product(q, 0.0, 3.0).abs() < 1e-7 * scale);
+            }
+        }
+    }
+
+    #[test]
+    fn gram_schmidt_drops_dependent_polynomials() {
+        let basis = [
+            monomial(0),
+            Polynomial::from_coefficients(&vec![1.0, 2.0]),
+            Polynomial::from_coefficients(&vec![3.0, -1.0]),
+            Polynomial::zero(),
+            monomial(2),
+        ];
+        let orthogonal = Polynomial::gram_schmidt(&basis, -1.0, 1.0);
+        assert_eq!(3, orthogonal.len());
+        assert_approx_eq(&[1.0, 0.0, -1.0 / 3.0], &orthogonal[2]);
+    }
+
+    /// Chebyshev points of the first kind on the interval.
+    fn chebyshev_nodes(n: usize, (a, b): (f64, f64)) -> Vec<f64> {
+        (0..n)
+            .map(|i| {
+                let t = (std::f64::consts::PI * (2 * i + 1) as f64 / (2 * n) as f64).cos();
+                (a + b) / 2.0 + (b - a) / 2.0 * t
+            })
+            .collect()
+    }
+
+    /// The least squares fit in the monomial basis with the normal equations, solved with
+    /// Gaussian elimination, for comparison.
+    fn fit_monomial(points: &[(f64, f64)], degree: usize) -> Polynomial {
+        let n = degree + 1;
+        let mut matrix = vec![vec![0.0; n + 1]; n];
+        for (x, y) in points {
+            let powers: Vec<f64> = (0..n).map(|k| x.powi(k as i32)).collect();
+            for i in 0..n {
+                for j in 0..n {
+                    matrix[i][j] += powers[i] * powers[j];
+                }
+                matrix[i][n] += powers[i] * y;
+            }
+        }
+        for k in 0..n {
+            let pivot = (k..n).max_by(|i, j| matrix[*i][k].abs().total_cmp(&matrix[*j][k].abs())).unwrap();
+            matrix.swap(k, pivot);
+            for i in k + 1..n {
+                let factor = matrix[i][k] / matrix[k][k];
+                let pivot_row = matrix[k].clone();
+                for (value, pivot_value) in matrix[i][k..].iter_mut().zip(&pivot_row[k..]) {
+                    *value -= factor * pivot_value;
+                }
+            }
+        }
+        let mut coefficients = vec![0.0; n];
+        for k in (0..n).rev() {
+            let sum: f64 = (k + 1..n).map(|j| matrix[k][j] * coefficients[j]).sum();
+            coefficients[k] = (matrix[k][n] - sum) / matrix[k][k];
+        }
+        coefficients.reverse();
+        Polynomial::from_coefficients(&coefficients)
+    }
+
+    fn max_error(poly: &Polynomial, expected: &Polynomial, (a, b): (f64, f64)) -> f64 {
+        (0..=200)
+            .map(|i| a + (b - a) * i as f64 / 200.0)
+            .map(|x| (poly.evaluate(x) - expected.evaluate(x)).abs())
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn fit_degree_15_from_noisy_samples() {
+        let interval = (0.0, 10.0);
+        // A product of factors with roots spread over the interval, scaled to values near one
+        let expected = (0..15)
+            .map(|i| Polynomial::from_coefficients(&vec![1.0 / 5.0, -(i as f64 * 0.7 + 0.2) / 5.0]))
+            .fold(Polynomial::one(), |product, factor| product * &factor);
+        let points: Vec<(f64, f64)> = (0..200)
+            .map(|i| {
+                let x = 10.0 * i as f64 / 199.0;
+                (x, expected.evaluate(x) + 1e-9 * (i as f64 * 12.9898).sin())
+            })
+            .collect();
+        let scale = max_error(&expected, &Polynomial::zero(), interval);
+
+        let chebyshev = Polynomial::fit_chebyshev(&points, 15, interval);
+        assert!(max_error(&chebyshev, &expected, interval) < 1e-6 * scale);
+
+        let monomial = fit_monomial(&points, 15);
+        assert!(max_error(&monomial, &expected, interval) > 1e-3 * scale);
+    }
+
+    #[test]
+    fn exact_recovery_from_chebyshev_nodes() {
+        let interval = (-3.0, 5.0);
+        let coefficients = [0.5, -1.0, 0.25, 2.0, 0.0, -0.75, 1.0];
+        let t = |x: f64| (2.0 * x - 2.0) / 8.0;
+        let chebyshev_sum = |x: f64| {
+            let (mut previous, mut current) = (1.0, t(x));
+            let mut sum = coefficients[0] + coefficients[1] * current;
+            for coefficient in &coefficients[2..] {
+                (previous, current) = (current, 2.0 * t(x) * current - previous);
+                sum += coefficient * current;
+            }
+            sum
+        };
+        let points: Vec<(f64, f64)> = chebyshev_nodes(7, interval).into_iter().map(|x| (x, chebyshev_sum(x))).collect();
+
+        let fitted = Polynomial::chebyshev_fit_coefficients(&points, 6, interval);
+        for (expected, actual) in coefficients.iter().zip(&fitted) {
+            assert!((expected - actual).abs() < 1e-12, "{expected} != {actual}");
+        }
+        let poly = Polynomial::fit_chebyshev(&points, 6, interval);
+        assert_eq!(Some(6), poly.degree());
+        for (x, y) in &points {
+            assert!((poly.evaluate(*x) - y).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn fit_of_low_degree() {
+        let points = [(1.0, 2.0), (2.0, 2.0), (3.0, 5.0)];
+        let constant = Polynomial::fit_chebyshev(&points, 0, (1.0, 3.0));
+        assert!((constant.get_coefficient_at(0) - 3.0).abs() < 1e-12);
+        assert_eq!(Some(0), constant.degree());
+    }
+
+    #[test]
+    #[should_panic(expected = "Not enough points for the degree")]
+    fn fit_with_too_few_points() {
+        Polynomial::fit_chebyshev(&[(0.0, 1.0), (1.0, 2.0)], 2, (0.0, 1.0));
+    }
+}
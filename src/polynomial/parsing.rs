@@ -1,26 +1,319 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+use std::sync::OnceLock;
 use regex::Regex;
 use super::Polynomial;
+use super::variable::is_valid_variable;
+
+mod equation;
+mod expression;
+
+use equation::parse_equation;
+
+/// Error returned when a string cannot be parsed into a [`Polynomial`].
+///
+/// Every variant except `InvalidVariable` carries the span of the offending fragment as a
+/// range of byte offsets into the parsed string.
+///
+/// # Examples
+///
+/// ```
+/// use polynomials::{Polynomial, PolynomialParsingError};
+///
+/// let err = Polynomial::from_string("2x^2 + 3y").unwrap_err();
+/// assert_eq!(PolynomialParsingError::UnexpectedInput { span: 8..9 }, err);
+/// assert_eq!(8..9, err.span());
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub enum PolynomialParsingError {
+    /// A coefficient is not a valid number.
+    InvalidCoefficient {
+        text: String,
+        span: Range<usize>,
+    },
+
+    /// A power does not fit into `u32`, or exceeds the maximum degree set in the
+    /// [`ParseOptions`].
+    ExponentOverflow {
+        span: Range<usize>,
+    },
+
+    /// A fragment of the string is not a part of any valid term.
+    UnexpectedInput {
+        span: Range<usize>,
+    },
+
+    /// A digit separator is not placed between two digits.
+    MisplacedDigitSeparator {
+        span: Range<usize>,
+    },
+
+    /// The divisor in an expression is not a constant.
+    DivisionByNonConstant {
+        span: Range<usize>,
+    },
+
+    /// The divisor in an expression is zero.
+    DivisionByZero {
+        span: Range<usize>,
+    },
+
+    /// A sub-expression would expand to a polynomial of a degree above the limit of
+    /// [`Polynomial::from_expression`].
+    DegreeTooHigh {
+        span: Range<usize>,
+    },
+
+    /// Parentheses or unary signs in an expression are nested deeper than the limit of
+    /// [`Polynomial::from_expression`].
+    NestingTooDeep {
+        span: Range<usize>,
+    },
+
+    /// The variable declared in an equation prefix such as `P(t) =` is not the indeterminate.
+    VariableMismatch {
+        span: Range<usize>,
+    },
+
+    /// The requested name of the indeterminate could be confused with the rest of the syntax.
+    InvalidVariable {
+        variable: String,
+    },
+}
+
+impl PolynomialParsingError {
+    /// Returns the range of byte offsets of the fragment which caused the error.
+    ///
+    /// The `InvalidVariable` error is not caused by the parsed string, so an empty range at
+    /// its start is returned.
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            PolynomialParsingError::InvalidCoefficient { span, .. } => span.clone(),
+            PolynomialParsingError::ExponentOverflow { span } => span.clone(),
+            PolynomialParsingError::UnexpectedInput { span } => span.clone(),
+            PolynomialParsingError::MisplacedDigitSeparator { span } => span.clone(),
+            PolynomialParsingError::DivisionByNonConstant { span } => span.clone(),
+            PolynomialParsingError::DivisionByZero { span } => span.clone(),
+            PolynomialParsingError::DegreeTooHigh { span } => span.clone(),
+            PolynomialParsingError::NestingTooDeep { span } => span.clone(),
+            PolynomialParsingError::VariableMismatch { span } => span.clone(),
+            PolynomialParsingError::InvalidVariable { .. } => 0..0,
+        }
+    }
+
+    /// Moves the span by the given number of bytes, used when a substring was parsed.
+    fn shifted(mut self, offset: usize) -> PolynomialParsingError {
+        let span = match &mut self {
+            PolynomialParsingError::InvalidCoefficient { span, .. } => span,
+            PolynomialParsingError::ExponentOverflow { span } => span,
+            PolynomialParsingError::UnexpectedInput { span } => span,
+            PolynomialParsingError::MisplacedDigitSeparator { span } => span,
+            PolynomialParsingError::DivisionByNonConstant { span } => span,
+            PolynomialParsingError::DivisionByZero { span } => span,
+            PolynomialParsingError::DegreeTooHigh { span } => span,
+            PolynomialParsingError::NestingTooDeep { span } => span,
+            PolynomialParsingError::VariableMismatch { span } => span,
+            PolynomialParsingError::InvalidVariable { .. } => return self,
+        };
+        *span = span.start + offset..span.end + offset;
+        self
+    }
+}
+
+impl fmt::Display for PolynomialParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Range { start, end } = self.span();
+        match self {
+            PolynomialParsingError::MisplacedDigitSeparator { .. } => {
+                write!(f, "Misplaced digit separator at bytes {start}..{end}.")
+            }
+            PolynomialParsingError::DivisionByNonConstant { .. } => {
+                write!(f, "Division by a non-constant at bytes {start}..{end}.")
+            }
+            PolynomialParsingError::DivisionByZero { .. } => {
+                write!(f, "Division by zero at bytes {start}..{end}.")
+            }
+            PolynomialParsingError::DegreeTooHigh { .. } => {
+                write!(f, "Degree too high at bytes {start}..{end}.")
+            }
+            PolynomialParsingError::NestingTooDeep { .. } => {
+                write!(f, "Nesting too deep at bytes {start}..{end}.")
+            }
+            PolynomialParsingError::VariableMismatch { .. } => {
+                write!(f, "Declared variable does not match the indeterminate at bytes {start}..{end}.")
+            }
+            PolynomialParsingError::InvalidVariable { variable } => {
+                write!(f, "Invalid variable name '{variable}'.")
+            }
+            PolynomialParsingError::InvalidCoefficient { text, .. } => {
+                write!(f, "Invalid coefficient '{text}' at bytes {start}..{end}.")
+            }
+            PolynomialParsingError::ExponentOverflow { .. } => {
+                write!(f, "Power too large at bytes {start}..{end}.")
+            }
+            PolynomialParsingError::UnexpectedInput { .. } => {
+                write!(f, "Unexpected input at bytes {start}..{end}.")
+            }
+        }
+    }
+}
+
+impl Error for PolynomialParsingError {}
+
+/// Returns the span of the fragment with the surrounding whitespace excluded, or `None` if the
+/// fragment consists of whitespace only.
+fn non_whitespace_span(string: &str, span: Range<usize>) -> Option<Range<usize>> {
+    let fragment = &string[span.clone()];
+    let trimmed_start = fragment.trim_start();
+    if trimmed_start.is_empty() {
+        return None;
+    }
+    let start = span.start + (fragment.len() - trimmed_start.len());
+    let end = start + trimmed_start.trim_end().len();
+    Some(start..end)
+}
+
+/// Returns the span of the first non-whitespace character after the given position, extended
+/// over the following alphanumeric characters, or `None` if there is no such character.
+fn next_token_span(string: &str, position: usize) -> Option<Range<usize>> {
+    let (offset, first) = string[position..].char_indices().find(|(_, c)| !c.is_whitespace())?;
+    let start = position + offset;
+    let mut end = start + first.len_utf8();
+    if first.is_alphanumeric() {
+        end += string[end..].find(|c: char| !c.is_alphanumeric()).unwrap_or(string.len() - end);
+    }
+    Some(start..end)
+}
+
+/// Options of the [`Polynomial::parse_with`] method.
+///
+/// The options are built by chaining the setters on the [`ParseOptions::strict`] or
+/// [`ParseOptions::lenient`] presets. The defaults, used by [`FromStr`] and
+/// `Polynomial::from_string`, are the strict parsing of the `x` indeterminate with a decimal
+/// point and no limit on the degree.
+///
+/// # Examples
+///
+/// ```
+/// use polynomials::{ParseOptions, Polynomial};
+///
+/// let options = ParseOptions::strict().variable("t").decimal_comma(true).max_degree(1000);
+/// let poly = Polynomial::parse_with("0,5t^2 - 1,25", &options).unwrap();
+/// assert_eq!(vec![0.5, 0.0, -1.25], poly.get_coefficients());
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct ParseOptions {
+    strict: bool,
+    variable: String,
+    decimal_comma: bool,
+    max_degree: Option<u32>,
+    equation: bool,
+}
+
+impl ParseOptions {
+    /// Returns the default options, which are the same as [`ParseOptions::strict`].
+    pub fn new() -> ParseOptions {
+        ParseOptions {
+            strict: true,
+            variable: String::from("x"),
+            decimal_comma: false,
+            max_degree: None,
+            equation: false,
+        }
+    }
+
+    /// Returns the options rejecting any unrecognized input, as described in the
+    /// `Polynomial.from_string` method.
+    pub fn strict() -> ParseOptions {
+        ParseOptions::new()
+    }
+
+    /// Returns the options skipping the fragments which are not valid terms, as described in
+    /// the `Polynomial.from_string_lenient` method.
+    pub fn lenient() -> ParseOptions {
+        ParseOptions { strict: false, ..ParseOptions::new() }
+    }
+
+    /// Sets the symbol used as the indeterminate. Defaults to `x`.
+    ///
+    /// An invalid symbol is reported as the `PolynomialParsingError::InvalidVariable` error
+    /// when parsing; see `FormatOptions::variable` for the rules.
+    pub fn variable(mut self, variable: &str) -> ParseOptions {
+        self.variable = variable.to_string();
+        self
+    }
+
+    /// Sets whether the coefficients use a decimal comma, e.g. `0,5`, instead of a decimal
+    /// point. Defaults to `false`.
+    pub fn decimal_comma(mut self, decimal_comma: bool) -> ParseOptions {
+        self.decimal_comma = decimal_comma;
+        self
+    }
+
+    /// Sets the maximum allowed degree. A term of a higher degree is reported as the
+    /// `PolynomialParsingError::ExponentOverflow` error. There is no limit by default.
+    pub fn max_degree(mut self, max_degree: u32) -> ParseOptions {
+        self.max_degree = Some(max_degree);
+        self
+    }
+
+    /// Sets whether the string is parsed as a definition or an equation, as described in the
+    /// `Polynomial.from_equation` method. Defaults to `false`.
+    pub fn equation(mut self, equation: bool) -> ParseOptions {
+        self.equation = equation;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::new()
+    }
+}
 
 impl Polynomial {
     /// Constructs a new instance from a given polynomial string representation.
     ///
-    /// The function returns `Ok(Polynomial)` if parsing is successful or `Err(&str)` if the string
-    /// format was incorrect.
+    /// The function returns `Ok(Polynomial)` if parsing is successful or
+    /// `Err(PolynomialParsingError)` if the string format was incorrect. The same parser is
+    /// used by the [`FromStr`] implementation, so `string.parse::<Polynomial>()` is equivalent.
     ///
     /// # Supported string format
     ///
     /// The string must follow the pattern `<term> +/- <term> +/- ... +/- <term>` where each `<term>`
-    /// is of the form `<coefficient>x<power>`. Spaces between the terms and plus or minus signs
+    /// is of the form `<coefficient>x<power>`. The coefficient is a decimal number or a fraction
+    /// of two decimal numbers, e.g. `3/4`. Spaces between the terms and plus or minus signs
     /// are optional. Terms of degree equal to one may be written as `<coefficient>x` or
     /// `<coefficient>x1`, while in the terms of degree zero the `x` might be omitted entirely.
     ///
     /// Additionally:
     /// - An asterisk `*` sign might be inserted after the coefficient, with optional
-    /// spaces around.
-    /// - A caret `^` character may be inserted before the power.
+    ///   spaces around.
+    /// - A caret `^` character or a double asterisk `**` may be inserted before the power.
+    /// - The power may be written with Unicode superscript digits instead, e.g. `x²` or `x¹²`.
+    /// - The digits of coefficients and powers may be grouped with underscores `_` or thin
+    ///   spaces, e.g. `1_000_000`. A separator must be placed between two digits.
+    /// - A coefficient may be written in scientific notation, e.g. `1.5e-3` or `2E+4`. The sign
+    ///   of the exponent does not separate the terms. The notation is not recognized if the
+    ///   indeterminate starts with `e` or `E`, since `2e3` then reads as `2e^3`.
+    /// - A term may be preceded by two signs, possibly separated by spaces. The signs `+-` and
+    ///   `-+` mean minus, while `--` means plus, e.g. `x - -2` is `x + 2`. A doubled plus
+    ///   sign and longer runs of signs are rejected.
+    /// - The Unicode minus sign `−` may be used in place of `-`, and the middle dot `·` in
+    ///   place of the asterisk.
     ///
     /// Terms of the same degree may occur multiple times in the string.
-    /// Only the character `x` may be used as an indeterminate.
+    /// Only the character `x` may be used as an indeterminate; use
+    /// [`from_string_with_variable`](Polynomial::from_string_with_variable) for other symbols.
+    ///
+    /// # Round-trip
+    ///
+    /// The output of `to_string` and of `format_with` in the `PolynomialFormat::Standard` and
+    /// `PolynomialFormat::Concise` formats is parsed back to an equal polynomial, as long as
+    /// all coefficients are finite. The `PolynomialFormat::Latex` format is not accepted.
     ///
     /// # Examples
     ///
@@ -54,131 +347,932 @@ impl Polynomial {
     ///
     /// let poly = Polynomial::from_string("-2 * x^2 -3*x + 5").unwrap();
     /// ```
-    pub fn from_string(string: &str) -> Result<Polynomial, &str> {
+    ///
+    /// Parse a Python expression:
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_string("3*x**2 - x + 1").unwrap();
+    /// assert_eq!(vec![3.0, -1.0, 1.0], poly.get_coefficients());
+    /// ```
+    ///
+    /// Parse a string copied from rendered math:
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_string("2x³ − x² + 5").unwrap();
+    /// assert_eq!(vec![2.0, -1.0, 0.0, 5.0], poly.get_coefficients());
+    /// ```
+    ///
+    /// Parse coefficients in scientific notation:
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_string("1.5e-3x^2 + 2e4x - 1").unwrap();
+    /// assert_eq!(vec![0.0015, 20000.0, -1.0], poly.get_coefficients());
+    /// ```
+    ///
+    /// Parse a string using the [`FromStr`] trait:
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly: Polynomial = "x^2 - 1".parse().unwrap();
+    /// assert_eq!(vec![1.0, 0.0, -1.0], poly.get_coefficients());
+    /// ```
+    pub fn from_string(string: &str) -> Result<Polynomial, PolynomialParsingError> {
+        string.parse()
+    }
 
-        let mut poly = Polynomial::zero();
-        let err = Err("Invalid string format.");
+    /// Constructs a new instance from a given polynomial string representation, skipping the
+    /// fragments which are not valid terms.
+    ///
+    /// Unlike [`from_string`](Polynomial::from_string), which rejects any unrecognized
+    /// input, this method ignores stray characters and signs not followed by a term, and
+    /// treats terms not preceded by a sign as added. A power too large to fit into `u32` is
+    /// still an error.
+    ///
+    /// Prefer `from_string` for validating user input, since this method may silently return
+    /// a different polynomial than intended.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// assert!(Polynomial::from_string("2x^2 + 3y - 1").is_err());
+    ///
+    /// let poly = Polynomial::from_string_lenient("2x^2 + 3y - 1").unwrap();
+    /// assert_eq!(vec![2.0, 0.0, 2.0], poly.get_coefficients());
+    /// ```
+    pub fn from_string_lenient(string: &str) -> Result<Polynomial, PolynomialParsingError> {
+        Polynomial::parse_with(string, &ParseOptions::lenient())
+    }
 
-        let pat = r"(?<sign>[+-])[ \n]*(?<coefficient>\d+(\.\d*)?)?[ \n]*\*?[ \n]*(?<variable>x)?(?:\^?(?<power>\d+))?";
-        let re = Regex::new(pat).unwrap();
+    /// Constructs a new instance from a given polynomial string representation, using the given
+    /// symbol as the indeterminate instead of `x`.
+    ///
+    /// The string format is the same as in [`from_string`](Polynomial::from_string), and the
+    /// parsing is strict, so the default `x` is rejected unless it is the requested symbol.
+    /// The symbol must not be confused with the rest of the syntax, otherwise the
+    /// `PolynomialParsingError::InvalidVariable` error is returned; see
+    /// `FormatOptions::variable` for the rules.
+    ///
+    /// This is a shorthand for `parse_with` with the `ParseOptions::strict().variable(variable)`
+    /// options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{Polynomial, PolynomialParsingError};
+    ///
+    /// let poly = Polynomial::from_string_with_variable("3t^2 - t + 1", "t").unwrap();
+    /// assert_eq!(vec![3.0, -1.0, 1.0], poly.get_coefficients());
+    ///
+    /// let err = Polynomial::from_string_with_variable("2i + 1", "i").unwrap_err();
+    /// assert_eq!(PolynomialParsingError::InvalidVariable { variable: String::from("i") }, err);
+    /// ```
+    pub fn from_string_with_variable(
+        string: &str,
+        variable: &str
+    ) -> Result<Polynomial, PolynomialParsingError> {
+        Polynomial::parse_with(string, &ParseOptions::strict().variable(variable))
+    }
 
-        // Add a trailing sign if it is not present
-        let string = if let Some(c) = string.trim().chars().next() {
-            if c == '-' || c == '+' {
-                string.trim()
-            } else {
-                &format!("+ {}", string)
-            }
-        } else{
-            return Ok(poly)
-        };
+    /// Constructs a new instance from a given polynomial string representation, using the
+    /// given [options](ParseOptions).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{ParseOptions, Polynomial, PolynomialParsingError};
+    ///
+    /// let options = ParseOptions::lenient().variable("s");
+    /// let poly = Polynomial::parse_with("s^2 + 3s ? 2", &options).unwrap();
+    /// assert_eq!(vec![1.0, 3.0, 2.0], poly.get_coefficients());
+    ///
+    /// let options = ParseOptions::strict().max_degree(2);
+    /// let err = Polynomial::parse_with("x^3 + 1", &options).unwrap_err();
+    /// assert_eq!(PolynomialParsingError::ExponentOverflow { span: 2..3 }, err);
+    /// ```
+    pub fn parse_with(
+        string: &str,
+        options: &ParseOptions
+    ) -> Result<Polynomial, PolynomialParsingError> {
+        let variable = &options.variable;
+        if !is_valid_variable(variable) {
+            return Err(PolynomialParsingError::InvalidVariable { variable: variable.to_string() });
+        }
+        if options.equation {
+            parse_equation(string, options)
+        } else {
+            parse(string, options)
+        }
+    }
+}
 
-        let mut captured_terms = String::new();
+/// Parses a polynomial in the format described in the `Polynomial.from_string` method.
+impl FromStr for Polynomial {
+    type Err = PolynomialParsingError;
 
-        for caps in re.captures_iter(string) {
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        parse(string, &ParseOptions::new())
+    }
+}
 
-            captured_terms.push_str(&caps[0]);
+/// Returns the regex matching a single term with the given indeterminate and decimal separator.
+/// The regex for the default `x` and decimal point is compiled once and shared between the
+/// calls.
+fn term_regex(variable: &str, decimal_separator: char) -> Cow<'static, Regex> {
+    static DEFAULT_REGEX: OnceLock<Regex> = OnceLock::new();
 
-            let sign: i8 = match caps.name("sign").unwrap().as_str() {
-                "+" => 1,
-                "-" => -1,
-                _ => panic!("Sign was supposed to be '+' or '-'.")
-            };
+    let compile = |variable: &str, decimal_separator: char| {
+        let pattern = format!(
+            concat!(
+                r"(?<sign>[+\-−](?:\s*[+\-−])*)?\s*",
+                r"(?:(?<coefficient>{number}(/{number})?)\s*(?:(?<asterisk>[*·])\s*)?)?",
+                r"(?:(?<variable>{variable})",
+                r"(?:(?:\^|\*\*)?(?<power>\d[\d_\x{{2009}}]*)|(?<superscript>[⁰¹²³⁴⁵⁶⁷⁸⁹]+))?)?",
+            ),
+            number = format!(
+                r"\d[\d_\x{{2009}}]*({}[\d_\x{{2009}}]*)?{}",
+                regex::escape(&decimal_separator.to_string()),
+                // The exponent would be mistaken for a term in an indeterminate starting with
+                // `e`, e.g. `2e3` for `2e^3`
+                if variable.starts_with(['e', 'E']) { "" } else { r"([eE][+\-]?\d+)?" }
+            ),
+            variable = regex::escape(variable)
+        );
+        Regex::new(&pattern).unwrap()
+    };
+    if variable == "x" && decimal_separator == '.' {
+        Cow::Borrowed(DEFAULT_REGEX.get_or_init(|| compile("x", '.')))
+    } else {
+        Cow::Owned(compile(variable, decimal_separator))
+    }
+}
 
-            let coefficient: Option<f64> = if let Some(mat) = caps.name("coefficient") {
-                Some(mat.as_str().parse().unwrap())
-            } else {
-                None
-            };
+/// Parses the string with the given options, ignoring the `equation` option. The variable is
+/// assumed to be valid.
+fn parse(string: &str, options: &ParseOptions) -> Result<Polynomial, PolynomialParsingError> {
+    let mut poly = Polynomial::zero();
 
-            let variable: Option<char> = if let Some(mat) = caps.name("variable") {
-                Some(mat.as_str().chars().next().unwrap())
-            } else if coefficient.is_none() {
-                return err
-            } else {
-                None
-            };
+    let strict = options.strict;
+    let decimal_separator = if options.decimal_comma { ',' } else { '.' };
+    let re = term_regex(&options.variable, decimal_separator);
 
-            let power: u32 = if let Some(mat) = caps.name("power") {
-                mat.as_str().parse().unwrap()
-            } else if variable.is_none() {
-                0
-            } else {
-                1
-            };
+    // End of the last parsed term
+    let mut position = 0;
 
-            // In the case of no coefficient default to 1.0
-            let coefficient = if let Some(coefficient) = coefficient {
-                coefficient
-            } else {
-                1.0
+    for caps in re.captures_iter(string) {
+        let mat = caps.get(0).unwrap();
+
+        // The regex matches whitespace and empty strings between the terms
+        let Some(span) = non_whitespace_span(string, mat.range()) else {
+            continue;
+        };
+
+        // Only whitespace may separate the terms
+        if strict && let Some(span) = non_whitespace_span(string, position..mat.start()) {
+            return Err(PolynomialParsingError::UnexpectedInput { span });
+        }
+
+        let sign = caps.name("sign");
+        let coefficient = caps.name("coefficient");
+        let variable = caps.name("variable");
+
+        // Every term must contain a coefficient or the indeterminate
+        if coefficient.is_none() && variable.is_none() {
+            if !strict {
+                position = mat.end();
+                continue;
+            }
+            let span = match sign {
+                Some(sign) => next_token_span(string, mat.end()).unwrap_or(sign.range()),
+                None => span
             };
+            return Err(PolynomialParsingError::UnexpectedInput { span });
+        }
+
+        // An asterisk must be followed by the indeterminate
+        if strict && variable.is_none() && let Some(asterisk) = caps.name("asterisk") {
+            return Err(PolynomialParsingError::UnexpectedInput { span: asterisk.range() });
+        }
+
+        // Every term except the first one must be preceded by a sign
+        if strict && sign.is_none() && position != 0 {
+            return Err(PolynomialParsingError::UnexpectedInput { span });
+        }
 
-            poly.add_coefficient_at(power, coefficient * sign as f64);
+        // Fold a run of signs, which in strict mode must not be longer than two or consist of
+        // two plus signs
+        let signs: Vec<(usize, char)> = sign
+            .map(|sign| sign.as_str().char_indices().filter(|(_, c)| !c.is_whitespace()).collect())
+            .unwrap_or_default();
+        if strict && signs.len() == 2 && signs.iter().all(|(_, c)| *c == '+') {
+            let start = sign.unwrap().start() + signs[1].0;
+            return Err(PolynomialParsingError::UnexpectedInput { span: start..start + 1 });
+        }
+        if strict && let Some(&(index, c)) = signs.get(2) {
+            let start = sign.unwrap().start() + index;
+            return Err(PolynomialParsingError::UnexpectedInput { span: start..start + c.len_utf8() });
         }
+        let negative_signs = signs.iter().filter(|(_, c)| *c != '+').count();
+        let sign = if negative_signs % 2 == 1 { -1.0 } else { 1.0 };
+
+        // In the case of no coefficient default to 1.0
+        let coefficient = match coefficient {
+            Some(mat) => {
+                let text = remove_digit_separators(mat.as_str(), mat.start(), strict)?;
+                parse_coefficient(&text.replace(decimal_separator, "."), mat.range())?
+            }
+            None => 1.0
+        };
 
-        // Compare captured terms and input string with spaces and newlines removed
-        let captured_terms = captured_terms.replace(" ", "").replace("\n", "");
-        let string = string.replace(" ", "").replace("\n", "");
+        let power_mat = caps.name("power").or(caps.name("superscript"));
+        let power: u32 = if let Some(mat) = caps.name("power") {
+            remove_digit_separators(mat.as_str(), mat.start(), strict)?.parse().map_err(|_| {
+                PolynomialParsingError::ExponentOverflow { span: mat.range() }
+            })?
+        } else if let Some(mat) = caps.name("superscript") {
+            parse_superscript(mat.as_str()).ok_or_else(|| {
+                PolynomialParsingError::ExponentOverflow { span: mat.range() }
+            })?
+        } else if variable.is_none() {
+            0
+        } else {
+            1
+        };
 
-        if captured_terms != string {
-            return err
+        if options.max_degree.is_some_and(|max_degree| power > max_degree) {
+            let span = power_mat.or(variable).map_or(span, |mat| mat.range());
+            return Err(PolynomialParsingError::ExponentOverflow { span });
         }
 
-        Ok(poly)
+        poly.add_coefficient_at(power, coefficient * sign);
+        position = mat.end();
+    }
+
+    if strict && let Some(span) = non_whitespace_span(string, position..string.len()) {
+        return Err(PolynomialParsingError::UnexpectedInput { span });
+    }
+
+    Ok(poly)
+}
+
+/// Removes the underscores and thin spaces used as digit separators from a number starting at
+/// the given byte offset. If `strict` is set, a separator not placed between two digits is
+/// rejected.
+fn remove_digit_separators(
+    text: &str,
+    offset: usize,
+    strict: bool
+) -> Result<String, PolynomialParsingError> {
+    let is_separator = |c: char| c == '_' || c == '\u{2009}';
+    if strict {
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        for (index, &(position, c)) in chars.iter().enumerate() {
+            let is_digit_at = |index: usize| chars.get(index).is_some_and(|(_, c)| c.is_ascii_digit());
+            if is_separator(c) && !(is_digit_at(index.wrapping_sub(1)) && is_digit_at(index + 1)) {
+                let start = offset + position;
+                let span = start..start + c.len_utf8();
+                return Err(PolynomialParsingError::MisplacedDigitSeparator { span });
+            }
+        }
+    }
+    Ok(text.chars().filter(|c| !is_separator(*c)).collect())
+}
+
+/// Parses a coefficient written as a decimal number or as a fraction of two decimal numbers.
+/// A coefficient that is not finite, e.g. because its exponent is out of range, is invalid.
+fn parse_coefficient(text: &str, span: Range<usize>) -> Result<f64, PolynomialParsingError> {
+    let invalid = || PolynomialParsingError::InvalidCoefficient { text: text.to_string(), span: span.clone() };
+    let parse_number = |number: &str| -> Result<f64, PolynomialParsingError> {
+        number.parse::<f64>().ok().filter(|value| value.is_finite()).ok_or_else(invalid)
+    };
+
+    let Some((numerator, denominator)) = text.split_once('/') else {
+        return parse_number(text);
+    };
+    let denominator = parse_number(denominator)?;
+    if denominator == 0.0 {
+        return Err(PolynomialParsingError::DivisionByZero { span });
+    }
+    Some(parse_number(numerator)? / denominator).filter(|value| value.is_finite()).ok_or_else(invalid)
+}
+
+/// Parses a run of Unicode superscript digits, returning `None` if the number does not fit
+/// into `u32`.
+fn parse_superscript(superscript: &str) -> Option<u32> {
+    superscript.chars().try_fold(0u32, |power, c| {
+        let digit = match c {
+            '¹' => 1,
+            '²' => 2,
+            '³' => 3,
+            _ => c as u32 - '⁰' as u32,
+        };
+        power.checked_mul(10)?.checked_add(digit)
+    })
+}
+
+impl TryFrom<&str> for Polynomial {
+    type Error = PolynomialParsingError;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        string.parse()
+    }
+}
+
+impl TryFrom<String> for Polynomial {
+    type Error = PolynomialParsingError;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        string.parse()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Polynomial;
+    use super::{ParseOptions, Polynomial, PolynomialParsingError};
+    use crate::{ExpressionLang, FormatOptions, PolynomialFormat};
+
+    /// Parses the string through both `Polynomial::from_string` and `FromStr`, checking that
+    /// they agree.
+    fn parse(string: &str) -> Result<Polynomial, PolynomialParsingError> {
+        let result = Polynomial::from_string(string);
+        assert_eq!(result, string.parse::<Polynomial>());
+        result
+    }
+
+    /// Returns pseudo-random polynomials generated by a fixed-seed xorshift generator.
+    fn random_polynomials(count: usize) -> Vec<Polynomial> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        let mut polys = vec![Polynomial::zero()];
+        while polys.len() < count {
+            let mut poly = Polynomial::zero();
+            for _ in 0..next() % 8 {
+                let power = (next() % 30) as u32;
+                let coefficient = match next() % 4 {
+                    0 => (next() % 21) as f64 - 10.0,
+                    1 => if next() % 2 == 0 { 1.0 } else { -1.0 },
+                    2 => f64::from_bits(next() & 0x7fef_ffff_ffff_ffff) * if next() % 2 == 0 { 1.0 } else { -1.0 },
+                    _ => (next() % 100_000) as f64 / 7.0 - 5000.0,
+                };
+                poly.set_coefficient_at(power, coefficient);
+            }
+            polys.push(poly);
+        }
+        polys
+    }
 
     #[test]
     fn from_string_integer_coefficients() {
-        let poly = Polynomial::from_string("-x^4 - 2x^3 + 10x2 - x + 5").unwrap();
+        let poly = parse("-x^4 - 2x^3 + 10x2 - x + 5").unwrap();
         assert_eq!(vec![-1.0, -2.0, 10.0, -1.0, 5.0], poly.get_coefficients());
     }
 
     #[test]
     fn from_string_decimal_coefficients() {
-        let poly = Polynomial::from_string("1.5x^2 - 0.5x + 2.125").unwrap();
+        let poly = parse("1.5x^2 - 0.5x + 2.125").unwrap();
         assert_eq!(vec![1.5, -0.5, 2.125], poly.get_coefficients());
     }
 
+    #[test]
+    fn from_string_scientific_notation() {
+        assert_eq!(vec![1000.0], parse("1e3").unwrap().get_coefficients());
+        assert_eq!(vec![-0.025, 0.0], parse("-2.5E-2x").unwrap().get_coefficients());
+        assert_eq!(vec![100.0, 0.0, 0.0, 0.0], parse("1e+2x^3").unwrap().get_coefficients());
+
+        let poly = parse("1.5e-3x^2 + 2e4x - 1").unwrap();
+        assert_eq!(vec![0.0015, 20000.0, -1.0], poly.get_coefficients());
+        let poly = parse("1e-3x2-2e1x+1E0").unwrap();
+        assert_eq!(vec![0.001, -20.0, 1.0], poly.get_coefficients());
+        let poly = parse("3e2/1e1 * x - 1_000e-3").unwrap();
+        assert_eq!(vec![30.0, -1.0], poly.get_coefficients());
+
+        // Plain numbers are parsed as before
+        assert_eq!(vec![12.0, 0.5], parse("12x + 0.5").unwrap().get_coefficients());
+    }
+
+    #[test]
+    fn from_string_incomplete_scientific_notation() {
+        for (string, span) in [("2e", 1..2), ("2ex", 1..2), ("2e-x", 1..2), ("2e+ 3", 1..2), ("1.5e3.2", 5..6)] {
+            assert_eq!(Err(PolynomialParsingError::UnexpectedInput { span }), parse(string), "{string}");
+        }
+    }
+
+    #[test]
+    fn from_string_out_of_range_scientific_notation() {
+        let cases = [("1e400x", "1e400", 0..5), ("-1e309", "1e309", 1..6), ("x + 1e300/1e-300", "1e300/1e-300", 4..16)];
+        for (string, text, span) in cases {
+            let text = text.to_string();
+            assert_eq!(Err(PolynomialParsingError::InvalidCoefficient { text, span }), parse(string), "{string}");
+        }
+        assert_eq!(vec![1e308, 0.0], parse("1e308x").unwrap().get_coefficients());
+    }
+
+    #[test]
+    fn scientific_notation_with_variable_starting_with_e() {
+        let poly = Polynomial::from_string_with_variable("2e3 + 1", "e").unwrap();
+        assert_eq!(vec![2.0, 0.0, 0.0, 1.0], poly.get_coefficients());
+
+        let options = ParseOptions::strict().decimal_comma(true);
+        let poly = Polynomial::parse_with("1,5e-3x + 2", &options).unwrap();
+        assert_eq!(vec![0.0015, 2.0], poly.get_coefficients());
+    }
+
     #[test]
     fn from_string_concise_spacing() {
-        let poly = Polynomial::from_string("x^2+x-5").unwrap();
+        let poly = parse("x^2+x-5").unwrap();
         assert_eq!(vec![1.0, 1.0, -5.0], poly.get_coefficients());
     }
 
     #[test]
     fn from_string_omitted_carets() {
-        let poly = Polynomial::from_string("x4 - 2x3 + 5x2 - x").unwrap();
+        let poly = parse("x4 - 2x3 + 5x2 - x").unwrap();
         assert_eq!(vec![1.0, -2.0, 5.0, -1.0, 0.0], poly.get_coefficients());
     }
 
     #[test]
     fn from_string_with_asterisks() {
-        let poly = Polynomial::from_string("- 2 * x^2 -3*x + 5").unwrap();
+        let poly = parse("- 2 * x^2 -3*x + 5").unwrap();
         assert_eq!(vec![-2.0, -3.0, 5.0], poly.get_coefficients());
     }
 
     #[test]
     fn from_string_with_repeated_terms() {
-        let poly = Polynomial::from_string("x^2 + x + x^2 - x + 5 - 10").unwrap();
+        let poly = parse("x^2 + x + x^2 - x + 5 - 10").unwrap();
         assert_eq!(vec![2.0, 0.0, -5.0], poly.get_coefficients());
     }
 
     #[test]
     fn from_string_invalid_formats() {
-        assert!(Polynomial::from_string("x^2 + + 3x").is_err());
-        assert!(Polynomial::from_string("2y^2 + 3y").is_err());
-        assert!(Polynomial::from_string("2x^2.5").is_err());
+        assert!(parse("x^2 + + 3x").is_err());
+        assert!(parse("2y^2 + 3y").is_err());
+        assert!(parse("2x^2.5").is_err());
     }
 
     #[test]
     fn from_string_empty() {
-        let poly = Polynomial::from_string("").unwrap();
+        let poly = parse("").unwrap();
         assert!(poly.is_zero());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn standard_format_round_trip() {
+        for poly in random_polynomials(200) {
+            let string = poly.to_string();
+            assert_eq!(poly, parse(&string).unwrap(), "{string}");
+        }
+    }
+
+    #[test]
+    fn concise_format_round_trip() {
+        for poly in random_polynomials(200) {
+            let string = poly.format_with(PolynomialFormat::Concise);
+            assert_eq!(poly, parse(&string).unwrap(), "{string}");
+        }
+    }
+
+    #[test]
+    fn concise_format_round_trip_around_ten() {
+        for power in [9, 10, 23] {
+            let mut poly = Polynomial::from_coefficients(&vec![12.0, 0.0, -2.0]);
+            poly.set_coefficient_at(power, 3.0);
+            let string = poly.format_with(PolynomialFormat::Concise);
+            assert_eq!(poly, parse(&string).unwrap(), "{string}");
+        }
+    }
+
+    #[test]
+    fn asterisk_multiplication_sign_round_trip() {
+        let options = FormatOptions::new().multiplication_sign(" * ");
+        for poly in random_polynomials(50) {
+            let string = poly.format_opts(&options);
+            assert_eq!(poly, parse(&string).unwrap(), "{string}");
+        }
+    }
+
+    #[test]
+    fn middle_dot_multiplication_sign_round_trip() {
+        let options = FormatOptions::new().multiplication_sign("·");
+        for poly in random_polynomials(50) {
+            let string = poly.format_opts(&options);
+            assert_eq!(poly, parse(&string).unwrap(), "{string}");
+        }
+    }
+
+    #[test]
+    fn unicode_superscripts_and_minus() {
+        let poly = parse("2x³ − x² + 5").unwrap();
+        assert_eq!(vec![2.0, -1.0, 0.0, 5.0], poly.get_coefficients());
+
+        let poly = parse("x¹² − 3·x^4 + 2 * x¹ −1").unwrap();
+        let mut expected = Polynomial::from_coefficients(&vec![-3.0, 0.0, 0.0, 2.0, -1.0]);
+        expected.set_coefficient_at(12, 1.0);
+        assert_eq!(expected, poly);
+
+        let err = parse("−x⁰ + 4x¹⁰⁵ - x⁴x").unwrap_err();
+        assert_eq!(PolynomialParsingError::UnexpectedInput { span: 27..28 }, err);
+    }
+
+    #[test]
+    fn unicode_superscript_overflow() {
+        let err = parse("x⁹⁹⁹⁹⁹⁹⁹⁹⁹⁹").unwrap_err();
+        assert_eq!(PolynomialParsingError::ExponentOverflow { span: 1..31 }, err);
+    }
+
+    #[test]
+    fn custom_variable() {
+        let poly = Polynomial::from_string_with_variable("3t^2 - t + 1", "t").unwrap();
+        assert_eq!(vec![3.0, -1.0, 1.0], poly.get_coefficients());
+
+        let poly = Polynomial::from_string_with_variable("2z10-1", "z").unwrap();
+        let mut expected = Polynomial::from_coefficients(&vec![-1.0]);
+        expected.set_coefficient_at(10, 2.0);
+        assert_eq!(expected, poly);
+
+        let poly = Polynomial::from_string_with_variable("x12 - 2x1 + 1", "x1").unwrap();
+        assert_eq!(vec![1.0, -2.0, 1.0], poly.get_coefficients());
+    }
+
+    #[test]
+    fn custom_variable_rejects_default_variable() {
+        let err = Polynomial::from_string_with_variable("3t^2 + x", "t").unwrap_err();
+        assert_eq!(PolynomialParsingError::UnexpectedInput { span: 7..8 }, err);
+    }
+
+    #[test]
+    fn custom_variable_round_trip() {
+        let options = FormatOptions::new().variable("s");
+        for poly in random_polynomials(50) {
+            let string = poly.format_opts(&options);
+            let parsed = Polynomial::from_string_with_variable(&string, "s").unwrap();
+            assert_eq!(poly, parsed, "{string}");
+        }
+    }
+
+    #[test]
+    fn invalid_custom_variable() {
+        for variable in ["", "2", "j", "x.", "1/x", "t u"] {
+            let err = Polynomial::from_string_with_variable("1", variable).unwrap_err();
+            let expected = PolynomialParsingError::InvalidVariable {
+                variable: variable.to_string()
+            };
+            assert_eq!(expected, err);
+            assert_eq!(format!("Invalid variable name '{variable}'."), err.to_string());
+        }
+    }
+
+    #[test]
+    fn double_asterisk_power() {
+        let poly = parse("3*x**2 - x + 1").unwrap();
+        assert_eq!(vec![3.0, -1.0, 1.0], poly.get_coefficients());
+
+        let poly = parse("x**3 + 2x^2 - 4 * x**1").unwrap();
+        assert_eq!(vec![1.0, 2.0, -4.0, 0.0], poly.get_coefficients());
+
+        let err = parse("3x**").unwrap_err();
+        assert_eq!(PolynomialParsingError::UnexpectedInput { span: 2..4 }, err);
+
+        let err = parse("3x * *2").unwrap_err();
+        assert_eq!(PolynomialParsingError::UnexpectedInput { span: 3..6 }, err);
+    }
+
+    #[test]
+    fn python_expression_round_trip() {
+        let mut poly = Polynomial::from_coefficients(&vec![2.0, 0.0, -1.0, 5.0]);
+        poly.set_coefficient_at(12, -0.25);
+        let string = poly.to_expression(ExpressionLang::Python);
+        assert_eq!("-0.25 * x**12 + 2.0 * x**3 - x + 5.0", string);
+        assert_eq!(poly, parse(&string).unwrap());
+    }
+
+    #[test]
+    fn fractional_coefficients() {
+        let poly = parse("1/2x^2 - 3/4").unwrap();
+        assert_eq!(vec![0.5, 0.0, -0.75], poly.get_coefficients());
+
+        let poly = parse("-1.5/3 * x + 10/4x - 1/3").unwrap();
+        assert_eq!(vec![2.0, -1.0 / 3.0], poly.get_coefficients());
+
+        let err = parse("x^2 + 1/0x").unwrap_err();
+        assert_eq!(PolynomialParsingError::DivisionByZero { span: 6..9 }, err);
+
+        let err = parse("1/ 2").unwrap_err();
+        assert_eq!(PolynomialParsingError::UnexpectedInput { span: 1..2 }, err);
+    }
+
+    #[test]
+    fn digit_separators() {
+        let poly = parse("1_000_000x^2 - 250\u{2009}000").unwrap();
+        assert_eq!(vec![1_000_000.0, 0.0, -250_000.0], poly.get_coefficients());
+
+        let poly = parse("0.000_1x^1_0 + 1_0/2_0").unwrap();
+        let mut expected = Polynomial::from_coefficients(&vec![0.5]);
+        expected.set_coefficient_at(10, 0.0001);
+        assert_eq!(expected, poly);
+    }
+
+    #[test]
+    fn misplaced_digit_separators() {
+        let cases = [
+            ("1__0x", 1..2),
+            ("10_x", 2..3),
+            ("1_.5", 1..2),
+            ("1._5", 2..3),
+            ("x^2_", 3..4),
+            ("x + 2_/3", 5..6),
+            ("7\u{2009}\u{2009}7", 1..4),
+        ];
+        for (string, span) in cases {
+            let err = parse(string).unwrap_err();
+            assert_eq!(PolynomialParsingError::MisplacedDigitSeparator { span }, err, "{string}");
+        }
+
+        let err = parse("_10x").unwrap_err();
+        assert_eq!(PolynomialParsingError::UnexpectedInput { span: 0..1 }, err);
+
+        let poly = Polynomial::from_string_lenient("1__0x + x^2_").unwrap();
+        assert_eq!(vec![1.0, 10.0, 0.0], poly.get_coefficients());
+    }
+
+    #[test]
+    fn doubled_signs() {
+        let cases = [
+            ("x^2 +- 3", vec![1.0, 0.0, -3.0]),
+            ("x^2 -+ 3", vec![1.0, 0.0, -3.0]),
+            ("x^2 -- 3", vec![1.0, 0.0, 3.0]),
+            ("x^2 - -3", vec![1.0, 0.0, 3.0]),
+            ("x^2 +- x", vec![1.0, -1.0, 0.0]),
+            ("x^2 -+ x", vec![1.0, -1.0, 0.0]),
+            ("x^2 -- x", vec![1.0, 1.0, 0.0]),
+            ("x^2 + −x", vec![1.0, -1.0, 0.0]),
+            ("x^2 +- 2x", vec![1.0, -2.0, 0.0]),
+            ("x^2 -+ 2x", vec![1.0, -2.0, 0.0]),
+            ("x^2 - - 2x", vec![1.0, 2.0, 0.0]),
+            ("--x^2", vec![1.0, 0.0, 0.0]),
+            ("+-x^2", vec![-1.0, 0.0, 0.0]),
+            ("-+x^2", vec![-1.0, 0.0, 0.0]),
+        ];
+        for (string, coefficients) in cases {
+            assert_eq!(coefficients, parse(string).unwrap().get_coefficients(), "{string}");
+        }
+    }
+
+    #[test]
+    fn doubled_plus_sign() {
+        for string in ["x^2 ++ 3", "x^2 + + x", "++2x"] {
+            assert!(parse(string).is_err(), "{string}");
+        }
+        let poly = Polynomial::from_string_lenient("x^2 ++ 3").unwrap();
+        assert_eq!(vec![1.0, 0.0, 3.0], poly.get_coefficients());
+    }
+
+    #[test]
+    fn long_sign_runs() {
+        let err = parse("x^2 +-- 3").unwrap_err();
+        assert_eq!(PolynomialParsingError::UnexpectedInput { span: 6..7 }, err);
+
+        let err = parse("- - -x").unwrap_err();
+        assert_eq!(PolynomialParsingError::UnexpectedInput { span: 4..5 }, err);
+
+        let poly = Polynomial::from_string_lenient("x^2 +-- 3 - - -x").unwrap();
+        assert_eq!(vec![1.0, -1.0, 3.0], poly.get_coefficients());
+    }
+
+    #[test]
+    fn parse_options_defaults() {
+        assert_eq!(ParseOptions::new(), ParseOptions::default());
+        assert_eq!(ParseOptions::new(), ParseOptions::strict());
+        for string in ["2x^2 - 1/2x + 3", "x^2 + 3y", "x^2 -+ 1", ""] {
+            let options = ParseOptions::default();
+            assert_eq!(parse(string).ok(), Polynomial::parse_with(string, &options).ok(), "{string}");
+        }
+    }
+
+    #[test]
+    fn parse_options_lenient() {
+        let options = ParseOptions::lenient();
+        let poly = Polynomial::parse_with("2x^2 + 3y - 1", &options).unwrap();
+        assert_eq!(vec![2.0, 0.0, 2.0], poly.get_coefficients());
+    }
+
+    #[test]
+    fn parse_options_variable() {
+        let options = ParseOptions::strict().variable("z");
+        let poly = Polynomial::parse_with("z^2 - 1", &options).unwrap();
+        assert_eq!(vec![1.0, 0.0, -1.0], poly.get_coefficients());
+        assert!(Polynomial::parse_with("x^2 - 1", &options).is_err());
+
+        let options = ParseOptions::lenient().variable("1x");
+        let err = Polynomial::parse_with("x", &options).unwrap_err();
+        assert_eq!(PolynomialParsingError::InvalidVariable { variable: String::from("1x") }, err);
+    }
+
+    #[test]
+    fn parse_options_decimal_comma() {
+        let options = ParseOptions::strict().decimal_comma(true);
+        let poly = Polynomial::parse_with("0,5x^2 - 1_000,25x + 3", &options).unwrap();
+        assert_eq!(vec![0.5, -1000.25, 3.0], poly.get_coefficients());
+
+        let err = Polynomial::parse_with("0.5x", &options).unwrap_err();
+        assert_eq!(PolynomialParsingError::UnexpectedInput { span: 1..2 }, err);
+        assert!(parse("0,5x").is_err());
+    }
+
+    #[test]
+    fn parse_options_max_degree() {
+        let options = ParseOptions::strict().max_degree(3);
+        let poly = Polynomial::parse_with("x^3 + x² + x", &options).unwrap();
+        assert_eq!(vec![1.0, 1.0, 1.0, 0.0], poly.get_coefficients());
+
+        let err = Polynomial::parse_with("x^3 + x⁴", &options).unwrap_err();
+        assert_eq!(PolynomialParsingError::ExponentOverflow { span: 7..10 }, err);
+
+        let options = ParseOptions::strict().max_degree(0);
+        let err = Polynomial::parse_with("5 - 2x", &options).unwrap_err();
+        assert_eq!(PolynomialParsingError::ExponentOverflow { span: 5..6 }, err);
+
+        let options = ParseOptions::lenient().max_degree(2);
+        assert!(Polynomial::parse_with("x^3 ?", &options).is_err());
+    }
+
+    #[test]
+    fn parse_options_equation() {
+        let options = ParseOptions::strict().equation(true);
+        let poly = Polynomial::parse_with("P(x) = x^2 - 1 = 3", &options).unwrap();
+        assert_eq!(vec![1.0, 0.0, -4.0], poly.get_coefficients());
+        assert!(parse("P(x) = x^2 - 1").is_err());
+    }
+
+    #[test]
+    fn parse_options_combined() {
+        let options = ParseOptions::strict()
+            .variable("t")
+            .decimal_comma(true)
+            .max_degree(10)
+            .equation(true);
+        let poly = Polynomial::parse_with("f(t) = 1,5t^2 − t = 0,5", &options).unwrap();
+        assert_eq!(vec![1.5, -1.0, -0.5], poly.get_coefficients());
+
+        let err = Polynomial::parse_with("f(t) = 1,5t^2 − x", &options).unwrap_err();
+        assert_eq!(PolynomialParsingError::UnexpectedInput { span: 18..19 }, err);
+
+        let err = Polynomial::parse_with("f(t) = t^11", &options).unwrap_err();
+        assert_eq!(PolynomialParsingError::ExponentOverflow { span: 9..11 }, err);
+
+        let options = ParseOptions::lenient().variable("t").decimal_comma(true).max_degree(2);
+        let poly = Polynomial::parse_with("2,5t^2 + 3y - 0,5", &options).unwrap();
+        assert_eq!(vec![2.5, 0.0, 2.5], poly.get_coefficients());
+    }
+
+    #[test]
+    fn try_from_str_and_string() {
+        let poly: Polynomial = "x^2-1".try_into().unwrap();
+        assert_eq!(vec![1.0, 0.0, -1.0], poly.get_coefficients());
+
+        let poly = Polynomial::try_from(String::from("2x + 3")).unwrap();
+        assert_eq!(vec![2.0, 3.0], poly.get_coefficients());
+
+        let result: Result<Polynomial, _> = "2y".try_into();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn error_propagates_through_box_dyn_error() {
+        fn parse_and_evaluate(string: &str) -> Result<f64, Box<dyn std::error::Error>> {
+            let poly = Polynomial::try_from(string)?;
+            Ok(poly.evaluate(2.0))
+        }
+
+        assert_eq!(3.0, parse_and_evaluate("x^2 - 1").unwrap());
+        let err = parse_and_evaluate("x^2 + banana").unwrap_err();
+        assert_eq!("Unexpected input at bytes 6..12.", err.to_string());
+    }
+
+    #[test]
+    fn error_variants_and_spans() {
+        assert_eq!(
+            Err(PolynomialParsingError::UnexpectedInput { span: 6..7 }),
+            parse("x^2 + + 3x")
+        );
+        assert_eq!(
+            Err(PolynomialParsingError::UnexpectedInput { span: 1..3 }),
+            parse("2y^2 + 3y")
+        );
+        assert_eq!(
+            Err(PolynomialParsingError::UnexpectedInput { span: 1..2 }),
+            parse("3^2")
+        );
+        assert_eq!(
+            Err(PolynomialParsingError::UnexpectedInput { span: 4..5 }),
+            parse("2x^2.5")
+        );
+        assert_eq!(
+            Err(PolynomialParsingError::UnexpectedInput { span: 4..6 }),
+            parse("  5 5x")
+        );
+        assert_eq!(
+            Err(PolynomialParsingError::UnexpectedInput { span: 7..8 }),
+            parse("3x - 1 -")
+        );
+        assert_eq!(
+            Err(PolynomialParsingError::ExponentOverflow { span: 6..17 }),
+            parse("1 + x^99999999999")
+        );
+    }
+
+    #[test]
+    fn error_display() {
+        let err = parse("x + 1 ?").unwrap_err();
+        assert_eq!("Unexpected input at bytes 6..7.", err.to_string());
+        let err = parse("x^4294967296").unwrap_err();
+        assert_eq!("Power too large at bytes 2..12.", err.to_string());
+    }
+
+    #[test]
+    fn strict_parsing_rejects_garbage() {
+        let garbage = [
+            "2x^2 + 3y - oops",
+            "5 5x",
+            "x^2 ++ 3",
+            "x^2 +-- 3",
+            "- - -x",
+            "^2 + x",
+            "x^",
+            "x^2 + 3 -",
+            "* x",
+            "3x * * 2",
+            "3 * + x",
+            "2 *",
+            "2 x x",
+            "xx",
+            "y",
+            "x^2 banana",
+            "1.2.3x",
+            "(x + 1)",
+            "x^2, x",
+        ];
+        for string in garbage {
+            assert!(
+                matches!(parse(string), Err(PolynomialParsingError::UnexpectedInput { .. })),
+                "{string}"
+            );
+        }
+    }
+
+    #[test]
+    fn strict_parsing_reports_first_unrecognized_fragment() {
+        let cases = [
+            ("2y^2 + banana", 1..3),
+            ("2x^2 + banana", 7..13),
+            ("x^2 ++ 3", 5..6),
+            ("^2 + x", 0..1),
+            ("x + ^3", 4..5),
+            ("x^2 + 3 )", 8..9),
+            ("x^2 + 3x!!", 8..10),
+        ];
+        for (string, span) in cases {
+            assert_eq!(Err(PolynomialParsingError::UnexpectedInput { span }), parse(string), "{string}");
+        }
+
+        // The lenient parser still skips the fragments
+        assert_eq!(vec![2.0, 0.0, 0.0], Polynomial::from_string_lenient("2x^2 + banana").unwrap().get_coefficients());
+    }
+
+    #[test]
+    fn strict_parsing_accepts_documented_forms() {
+        let valid = [
+            ("-3x2 + 4x - 5", vec![-3.0, 4.0, -5.0]),
+            ("2x5-x4+4x2-3", vec![2.0, -1.0, 0.0, 4.0, 0.0, -3.0]),
+            ("x^4 - x^2 + x", vec![1.0, 0.0, -1.0, 1.0, 0.0]),
+            ("-2 * x^2 -3*x + 5", vec![-2.0, -3.0, 5.0]),
+            ("+ 2x1 + 3", vec![2.0, 3.0]),
+            ("  x \n - 1  ", vec![1.0, -1.0]),
+        ];
+        for (string, coefficients) in valid {
+            assert_eq!(coefficients, parse(string).unwrap().get_coefficients(), "{string}");
+        }
+    }
+
+    #[test]
+    fn lenient_parsing_skips_garbage() {
+        let poly = Polynomial::from_string_lenient("2x^2 + 3y - oops").unwrap();
+        assert_eq!(vec![2.0, 0.0, 3.0], poly.get_coefficients());
+
+        let poly = Polynomial::from_string_lenient("5 5x").unwrap();
+        assert_eq!(vec![5.0, 5.0], poly.get_coefficients());
+
+        let poly = Polynomial::from_string_lenient("x^2 + + 3x -").unwrap();
+        assert_eq!(vec![1.0, 3.0, 0.0], poly.get_coefficients());
+
+        assert!(Polynomial::from_string_lenient("x^99999999999").is_err());
+    }
+}
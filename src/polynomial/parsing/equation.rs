@@ -0,0 +1,157 @@
+//! Module containing the parsing of polynomials written as equations, such as
+//! `P(x) = 2x^2 - 3x + 1` or `x^2 = 2x - 1`.
+use std::sync::OnceLock;
+use regex::Regex;
+use super::{parse, ParseOptions, PolynomialParsingError};
+use crate::Polynomial;
+
+/// Parses an equation with the given options. A leading `<name>(<variable>) =` or `<name> =`
+/// prefix is stripped, and the remaining sides of an equation are subtracted.
+pub(super) fn parse_equation(
+    string: &str,
+    options: &ParseOptions
+) -> Result<Polynomial, PolynomialParsingError> {
+    let variable = options.variable.as_str();
+    static PREFIX_REGEX: OnceLock<Regex> = OnceLock::new();
+    let re = PREFIX_REGEX.get_or_init(|| Regex::new(r"^\s*(?<name>\p{L}[\p{L}\d_']*)\s*(?:\(\s*(?<variable>[^()=]*?)\s*\))?\s*=").unwrap());
+
+    // Position of the first character after the prefix
+    let mut position = 0;
+    if let Some(caps) = re.captures(string) {
+        match caps.name("variable") {
+            Some(declared) if declared.as_str() != variable => {
+                return Err(PolynomialParsingError::VariableMismatch { span: declared.range() });
+            }
+            Some(_) => position = caps.get(0).unwrap().end(),
+            // A bare indeterminate is the left-hand side of an equation, e.g. `x = 1`
+            None if caps.name("name").unwrap().as_str() != variable => {
+                position = caps.get(0).unwrap().end();
+            }
+            None => {}
+        }
+    }
+
+    let Some(equals) = string[position..].find('=').map(|index| position + index) else {
+        // The prefix must be followed by a polynomial
+        if position > 0 && string[position..].trim().is_empty() {
+            return Err(PolynomialParsingError::UnexpectedInput { span: position - 1..position });
+        }
+        return parse_side(string, position, string.len(), options);
+    };
+    if let Some(index) = string[equals + 1..].find('=') {
+        let start = equals + 1 + index;
+        return Err(PolynomialParsingError::UnexpectedInput { span: start..start + 1 });
+    }
+
+    // Both sides must contain a polynomial
+    for (start, end) in [(position, equals), (equals + 1, string.len())] {
+        if string[start..end].trim().is_empty() {
+            return Err(PolynomialParsingError::UnexpectedInput { span: equals..equals + 1 });
+        }
+    }
+
+    let lhs = parse_side(string, position, equals, options)?;
+    let rhs = parse_side(string, equals + 1, string.len(), options)?;
+    Ok(lhs - rhs)
+}
+
+/// Parses the fragment of the string between the given byte offsets.
+fn parse_side(
+    string: &str,
+    start: usize,
+    end: usize,
+    options: &ParseOptions
+) -> Result<Polynomial, PolynomialParsingError> {
+    parse(&string[start..end], options).map_err(|err| err.shifted(start))
+}
+
+impl Polynomial {
+    /// Constructs a new instance from a polynomial written as a definition or an equation.
+    ///
+    /// A leading `<name>(x) =` or `<name> =` prefix, such as `P(x) =` or `y =`, is ignored.
+    /// If the variable in parentheses is not `x`, the
+    /// `PolynomialParsingError::VariableMismatch` error is returned. If the remaining string
+    /// is an equation `<lhs> = <rhs>`, the polynomial `<lhs> - <rhs>` is returned, so the
+    /// `<lhs> = 0` form gives `<lhs>` itself. Both sides follow the format described in the
+    /// [`from_string`](Polynomial::from_string) method.
+    ///
+    /// This is a shorthand for `parse_with` with the `ParseOptions::new().equation(true)`
+    /// options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_equation("P(x) = 2x^2 - 3x + 1").unwrap();
+    /// assert_eq!(vec![2.0, -3.0, 1.0], poly.get_coefficients());
+    ///
+    /// let poly = Polynomial::from_equation("x^2 - 4 = 0").unwrap();
+    /// assert_eq!(vec![1.0, 0.0, -4.0], poly.get_coefficients());
+    ///
+    /// let poly = Polynomial::from_equation("x^2 = 2x - 1").unwrap();
+    /// assert_eq!(vec![1.0, -2.0, 1.0], poly.get_coefficients());
+    /// ```
+    pub fn from_equation(string: &str) -> Result<Polynomial, PolynomialParsingError> {
+        Polynomial::parse_with(string, &ParseOptions::new().equation(true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ParseOptions, Polynomial, PolynomialParsingError};
+
+    fn coefficients(string: &str) -> Vec<f64> {
+        Polynomial::from_equation(string).unwrap().get_coefficients()
+    }
+
+    #[test]
+    fn prefixes() {
+        assert_eq!(vec![2.0, -3.0, 1.0], coefficients("P(x) = 2x^2 - 3x + 1"));
+        assert_eq!(vec![1.0, -2.0], coefficients("y = x - 2"));
+        assert_eq!(vec![1.0, 0.0], coefficients("  f ( x )=x"));
+        assert_eq!(vec![1.0, 0.0, 0.0], coefficients("p_1(x) = x^2"));
+        assert_eq!(vec![1.0, 1.0], coefficients("x + 1"));
+    }
+
+    #[test]
+    fn custom_variable_prefix() {
+        let options = ParseOptions::new().variable("t").equation(true);
+        let poly = Polynomial::parse_with("f(t)=t^3-1", &options).unwrap();
+        assert_eq!(vec![1.0, 0.0, 0.0, -1.0], poly.get_coefficients());
+    }
+
+    #[test]
+    fn mismatched_variable() {
+        let err = Polynomial::from_equation("f(t)=t^3-1").unwrap_err();
+        assert_eq!(PolynomialParsingError::VariableMismatch { span: 2..3 }, err);
+
+        let options = ParseOptions::new().variable("t").equation(true);
+        let err = Polynomial::parse_with("P(x) = x - 1", &options).unwrap_err();
+        assert_eq!(PolynomialParsingError::VariableMismatch { span: 2..3 }, err);
+    }
+
+    #[test]
+    fn equations() {
+        assert_eq!(vec![1.0, 0.0, -4.0], coefficients("x^2 - 4 = 0"));
+        assert_eq!(vec![1.0, -2.0, 1.0], coefficients("x^2 = 2x - 1"));
+        assert_eq!(vec![1.0, -3.0], coefficients("x = 3"));
+        assert_eq!(vec![3.0, 0.0, 0.0], coefficients("P(x) = 2x^2 = -x^2"));
+    }
+
+    #[test]
+    fn invalid_equations() {
+        let cases = [
+            ("x = 1 = 2", 6..7),
+            ("P(x) =", 5..6),
+            ("= x", 0..1),
+            ("x^2 =  ", 4..5),
+            ("P(x) = x^2 + y", 13..14),
+            ("x^2 = 3y", 7..8),
+        ];
+        for (string, span) in cases {
+            let err = Polynomial::from_equation(string).unwrap_err();
+            assert_eq!(PolynomialParsingError::UnexpectedInput { span }, err, "{string}");
+        }
+    }
+}
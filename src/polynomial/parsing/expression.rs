@@ -0,0 +1,450 @@
+//! Module containing a recursive-descent parser of arithmetic expressions in the indeterminate,
+//! such as products and powers of parenthesized polynomials.
+use std::ops::Range;
+use super::{PolynomialParsingError, remove_digit_separators};
+use crate::Polynomial;
+
+/// The maximum degree of the polynomial expanded from an expression and of its sub-expressions.
+const MAX_DEGREE: u32 = 1000;
+
+/// The maximum number of nested parentheses and unary signs in an expression.
+const MAX_NESTING_DEPTH: usize = 128;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+enum TokenKind {
+    Number(f64),
+    Variable,
+    Plus,
+    Minus,
+    Asterisk,
+    Slash,
+    Caret,
+    LeftParenthesis,
+    RightParenthesis,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    span: Range<usize>,
+}
+
+/// Splits the string into tokens, skipping whitespace.
+fn tokenize(string: &str) -> Result<Vec<Token>, PolynomialParsingError> {
+    let mut tokens = Vec::new();
+    let mut chars = string.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let kind = match c {
+            _ if c.is_whitespace() => continue,
+            'x' => TokenKind::Variable,
+            '+' => TokenKind::Plus,
+            '-' | '−' => TokenKind::Minus,
+            '*' if chars.next_if(|(_, c)| *c == '*').is_some() => {
+                tokens.push(Token { kind: TokenKind::Caret, span: start..start + 2 });
+                continue;
+            }
+            '*' | '·' => TokenKind::Asterisk,
+            '/' => TokenKind::Slash,
+            '^' => TokenKind::Caret,
+            '(' => TokenKind::LeftParenthesis,
+            ')' => TokenKind::RightParenthesis,
+            '0'..='9' | '.' => {
+                let mut end = start + 1;
+                while let Some((index, '0'..='9' | '.' | '_')) = chars.peek().copied() {
+                    end = index + 1;
+                    chars.next();
+                }
+                let text = &string[start..end];
+                let number = remove_digit_separators(text, start, true)?.parse().map_err(|_| {
+                    PolynomialParsingError::InvalidCoefficient {
+                        text: text.to_string(),
+                        span: start..end
+                    }
+                })?;
+                tokens.push(Token { kind: TokenKind::Number(number), span: start..end });
+                continue;
+            }
+            _ => {
+                let span = start..start + c.len_utf8();
+                return Err(PolynomialParsingError::UnexpectedInput { span });
+            }
+        };
+        tokens.push(Token { kind, span: start..start + c.len_utf8() });
+    }
+    Ok(tokens)
+}
+
+/// Parser of the grammar
+///
+/// ```text
+/// expression := term (("+" | "-") term)*
+/// term       := unary (("*" | "/") unary | implicit_factor)*
+/// unary      := ("+" | "-") unary | power
+/// power      := primary ("^" integer)?
+/// primary    := number | "x" | "(" expression ")"
+/// ```
+///
+/// where `implicit_factor` is a `power` starting with the indeterminate or a parenthesis.
+struct Parser<'a> {
+    string: &'a str,
+    tokens: Vec<Token>,
+    position: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<TokenKind> {
+        self.tokens.get(self.position).map(|token| token.kind)
+    }
+
+    /// Returns the span of the current token, or an empty span at the end of the string.
+    fn span(&self) -> Range<usize> {
+        match self.tokens.get(self.position) {
+            Some(token) => token.span.clone(),
+            None => self.string.len()..self.string.len(),
+        }
+    }
+
+    /// Returns the span from the given start to the end of the last consumed token.
+    fn span_from(&self, start: usize) -> Range<usize> {
+        start..self.tokens[self.position - 1].span.end
+    }
+
+    fn unexpected(&self) -> PolynomialParsingError {
+        PolynomialParsingError::UnexpectedInput { span: self.span() }
+    }
+
+    /// Consumes the current token and parses the rest with the given rule one level of nesting
+    /// deeper, so that the recursion is bounded.
+    fn nested(
+        &mut self,
+        rule: fn(&mut Self) -> Result<Polynomial, PolynomialParsingError>
+    ) -> Result<Polynomial, PolynomialParsingError> {
+        if self.depth == MAX_NESTING_DEPTH {
+            return Err(PolynomialParsingError::NestingTooDeep { span: self.span() });
+        }
+        self.position += 1;
+        self.depth += 1;
+        let result = rule(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// Multiplies the factors of a term starting at the given byte offset, unless the degree of
+    /// the product would exceed the limit.
+    fn multiply(
+        &self,
+        lhs: Polynomial,
+        rhs: &Polynomial,
+        start: usize
+    ) -> Result<Polynomial, PolynomialParsingError> {
+        let degree = lhs.degree().zip(rhs.degree()).map(|(lhs, rhs)| u64::from(lhs) + u64::from(rhs));
+        if degree.is_some_and(|degree| degree > u64::from(MAX_DEGREE)) {
+            return Err(PolynomialParsingError::DegreeTooHigh { span: self.span_from(start) });
+        }
+        Ok(lhs * rhs)
+    }
+
+    fn expression(&mut self) -> Result<Polynomial, PolynomialParsingError> {
+        let mut result = self.term()?;
+        loop {
+            match self.peek() {
+                Some(TokenKind::Plus) => {
+                    self.position += 1;
+                    result += self.term()?;
+                }
+                Some(TokenKind::Minus) => {
+                    self.position += 1;
+                    result -= self.term()?;
+                }
+                _ => return Ok(result),
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<Polynomial, PolynomialParsingError> {
+        let start = self.span().start;
+        let mut result = self.unary()?;
+        loop {
+            match self.peek() {
+                Some(TokenKind::Asterisk) => {
+                    self.position += 1;
+                    let factor = self.unary()?;
+                    result = self.multiply(result, &factor, start)?;
+                }
+                Some(TokenKind::Slash) => {
+                    self.position += 1;
+                    let start = self.span().start;
+                    let divisor = self.unary()?;
+                    let span = self.span_from(start);
+                    if divisor.is_zero() {
+                        return Err(PolynomialParsingError::DivisionByZero { span });
+                    }
+                    if divisor.degree() != Some(0) {
+                        return Err(PolynomialParsingError::DivisionByNonConstant { span });
+                    }
+                    result /= divisor.get_coefficient_at(0);
+                }
+                Some(TokenKind::Variable | TokenKind::LeftParenthesis) => {
+                    let factor = self.power()?;
+                    result = self.multiply(result, &factor, start)?;
+                }
+                _ => return Ok(result),
+            }
+        }
+    }
+
+    fn unary(&mut self) -> Result<Polynomial, PolynomialParsingError> {
+        match self.peek() {
+            Some(TokenKind::Plus) => self.nested(Self::unary),
+            Some(TokenKind::Minus) => Ok(-self.nested(Self::unary)?),
+            _ => self.power(),
+        }
+    }
+
+    fn power(&mut self) -> Result<Polynomial, PolynomialParsingError> {
+        let start = self.span().start;
+        let base = self.primary()?;
+        if self.peek() != Some(TokenKind::Caret) {
+            return Ok(base);
+        }
+        self.position += 1;
+
+        let Some(TokenKind::Number(_)) = self.peek() else {
+            return Err(self.unexpected());
+        };
+        let span = self.span();
+        let text = &self.string[span.clone()];
+        if text.contains('.') {
+            return Err(PolynomialParsingError::UnexpectedInput { span });
+        }
+        let exponent: u32 = remove_digit_separators(text, span.start, true)?.parse().map_err(|_| {
+            PolynomialParsingError::ExponentOverflow { span }
+        })?;
+        self.position += 1;
+        let degree = base.degree().map(|degree| u64::from(degree) * u64::from(exponent));
+        if degree.is_some_and(|degree| degree > u64::from(MAX_DEGREE)) {
+            return Err(PolynomialParsingError::DegreeTooHigh { span: self.span_from(start) });
+        }
+        Ok(base.pow(exponent))
+    }
+
+    fn primary(&mut self) -> Result<Polynomial, PolynomialParsingError> {
+        let result = match self.peek() {
+            Some(TokenKind::Number(number)) => Polynomial::from_coefficients(&vec![number]),
+            Some(TokenKind::Variable) => Polynomial::from_coefficients(&vec![1.0, 0.0]),
+            Some(TokenKind::LeftParenthesis) => {
+                let result = self.nested(Self::expression)?;
+                if self.peek() != Some(TokenKind::RightParenthesis) {
+                    return Err(self.unexpected());
+                }
+                result
+            }
+            _ => return Err(self.unexpected()),
+        };
+        self.position += 1;
+        Ok(result)
+    }
+}
+
+impl Polynomial {
+    /// Constructs a new instance by expanding an arithmetic expression in the indeterminate `x`.
+    ///
+    /// Unlike [`from_string`](Polynomial::from_string), which accepts a flat list of terms,
+    /// this method accepts factored forms built from numbers, the indeterminate, the
+    /// operators `+`, `-`, `*`, `/` and `^` (or `**`), and parentheses. The usual precedence rules
+    /// apply, and the unary minus binds weaker than the power, so `-x^2` is `-(x^2)`.
+    ///
+    /// Additionally:
+    /// - The multiplication sign may be omitted before the indeterminate or an opening
+    ///   parenthesis, e.g. `2x`, `3(x + 1)` or `(x - 1)(x + 2)`.
+    /// - The exponent must be a non-negative integer literal.
+    /// - The expanded polynomial and every product or power in the expression may be of degree
+    ///   at most 1000, otherwise the `PolynomialParsingError::DegreeTooHigh` error is
+    ///   returned before the expansion.
+    /// - Parentheses and unary signs may be nested at most 128 levels deep, otherwise the
+    ///   `PolynomialParsingError::NestingTooDeep` error is returned.
+    /// - Only division by a non-zero constant is allowed, otherwise the
+    ///   `PolynomialParsingError::DivisionByNonConstant` or
+    ///   `PolynomialParsingError::DivisionByZero` error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_expression("(x - 1)(x + 2)^2 - 4(x^2 + 1)").unwrap();
+    /// assert_eq!(vec![1.0, -1.0, 0.0, -8.0], poly.get_coefficients());
+    ///
+    /// let poly = Polynomial::from_expression("(2x + 1)^2 / 4").unwrap();
+    /// assert_eq!(vec![1.0, 1.0, 0.25], poly.get_coefficients());
+    ///
+    /// assert!(Polynomial::from_expression("1 / (x + 1)").is_err());
+    /// ```
+    pub fn from_expression(string: &str) -> Result<Polynomial, PolynomialParsingError> {
+        let mut parser = Parser { string, tokens: tokenize(string)?, position: 0, depth: 0 };
+        if parser.peek().is_none() {
+            return Ok(Polynomial::zero());
+        }
+        let result = parser.expression()?;
+        if parser.peek().is_some() {
+            return Err(parser.unexpected());
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Add;
+    use crate::{Polynomial, PolynomialParsingError};
+
+    fn expand(string: &str) -> Vec<f64> {
+        Polynomial::from_expression(string).unwrap().get_coefficients()
+    }
+
+    #[test]
+    fn flat_term_lists() {
+        assert_eq!(vec![2.0, 3.0, -1.0], expand("2x^2 + 3x - 1"));
+        assert_eq!(vec![-2.0, -3.0, 5.0], expand("-2 * x^2 -3*x + 5"));
+        assert!(expand("").is_empty());
+        assert!(expand("x - x").is_empty());
+    }
+
+    #[test]
+    fn products_of_factors() {
+        assert_eq!(vec![1.0, 1.0, -2.0], expand("(x - 1)(x + 2)"));
+        assert_eq!(vec![1.0, 0.0, -1.0], expand("(x - 1) * (x + 1)"));
+        assert_eq!(vec![6.0, -6.0, -12.0], expand("3(x - 2)(2x + 2)"));
+        assert_eq!(vec![1.0, 1.0, 0.0], expand("x(x + 1)"));
+        assert_eq!(vec![1.0, 0.0, 0.0, 0.0], expand("x x x"));
+    }
+
+    #[test]
+    fn powers_of_sub_expressions() {
+        assert_eq!(vec![1.0, 3.0, 3.0, 1.0], expand("(x + 1)^3"));
+        assert_eq!(vec![1.0, 5.0, 6.0, -4.0, -8.0], expand("(x - 1)(x + 2)^3"));
+        assert_eq!(vec![1.0, 5.0, 2.0, -4.0, -12.0], expand("(x-1)(x+2)^3 - 4(x^2+1)"));
+        assert_eq!(vec![1.0], expand("(x + 5)^0"));
+        assert_eq!(vec![1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0], expand("((x^2)^3)"));
+
+        let poly = Polynomial::from_expression("(x - 1)^10").unwrap();
+        assert_eq!(-252.0, poly.get_coefficient_at(5));
+        assert_eq!(1.0, poly.evaluate(2.0));
+    }
+
+    #[test]
+    fn double_asterisk_power() {
+        assert_eq!(vec![3.0, -1.0, 1.0], expand("3*x**2 - x + 1"));
+        assert_eq!(vec![1.0, 0.0, 3.0, 0.0, 0.0], expand("(x**2 + 3)*x^2"));
+        assert_eq!(vec![-8.0, 0.0, 0.0, 0.0], expand("2*-(x)**3*4"));
+    }
+
+    #[test]
+    fn unary_minus() {
+        assert_eq!(vec![-1.0, 0.0, 0.0], expand("-x^2"));
+        assert_eq!(vec![1.0, 0.0, 0.0], expand("(-x)^2"));
+        assert_eq!(vec![1.0, 1.0], expand("-(-x - 1)"));
+        assert_eq!(vec![-2.0, 0.0], expand("2 * -x"));
+        assert_eq!(vec![1.0, 3.0], expand("x − −3"));
+    }
+
+    #[test]
+    fn division_by_constants() {
+        assert_eq!(vec![0.5, 1.0], expand("(x + 2) / 2"));
+        assert_eq!(vec![0.25, 0.0], expand("x / 2 / 2"));
+        assert_eq!(vec![1.0, 0.0], expand("x / (3 - 2)"));
+    }
+
+    #[test]
+    fn invalid_divisions() {
+        let err = Polynomial::from_expression("1 / (x + 1)").unwrap_err();
+        assert_eq!(PolynomialParsingError::DivisionByNonConstant { span: 4..11 }, err);
+
+        let err = Polynomial::from_expression("x / (2 - 2)").unwrap_err();
+        assert_eq!(PolynomialParsingError::DivisionByZero { span: 4..11 }, err);
+
+        let err = Polynomial::from_expression("x^2 / x").unwrap_err();
+        assert_eq!(PolynomialParsingError::DivisionByNonConstant { span: 6..7 }, err);
+    }
+
+    #[test]
+    fn invalid_expressions() {
+        let cases = [
+            ("(x + 1", 6..6),
+            ("x + 1)", 5..6),
+            ("x +", 3..3),
+            ("2 3", 2..3),
+            ("x 2", 2..3),
+            ("()", 1..2),
+            ("x^-1", 2..3),
+            ("x^1.5", 2..5),
+            ("x^x", 2..3),
+            ("2y", 1..2),
+            ("x * * 2", 4..5),
+            ("x**", 3..3),
+            ("x** -1", 4..5),
+        ];
+        for (string, span) in cases {
+            let err = Polynomial::from_expression(string).unwrap_err();
+            assert_eq!(PolynomialParsingError::UnexpectedInput { span }, err, "{string}");
+        }
+    }
+
+    #[test]
+    fn digit_separators() {
+        assert_eq!(vec![1_000.0, -2_500.0], expand("1_000(x - 2.5)"));
+        assert_eq!(11, Polynomial::from_expression("(x + 1)^1_0 * x").unwrap().degree().unwrap());
+
+        let err = Polynomial::from_expression("x + 1_").unwrap_err();
+        assert_eq!(PolynomialParsingError::MisplacedDigitSeparator { span: 5..6 }, err);
+    }
+
+    #[test]
+    fn invalid_numbers() {
+        let err = Polynomial::from_expression("1.2.3x").unwrap_err();
+        let expected = PolynomialParsingError::InvalidCoefficient {
+            text: String::from("1.2.3"),
+            span: 0..5
+        };
+        assert_eq!(expected, err);
+
+        let err = Polynomial::from_expression("x^99999999999").unwrap_err();
+        assert_eq!(PolynomialParsingError::ExponentOverflow { span: 2..13 }, err);
+    }
+
+    #[test]
+    fn degree_limit() {
+        assert_eq!(Some(1000), Polynomial::from_expression("x^1000").unwrap().degree());
+        assert_eq!(Some(1000), Polynomial::from_expression("(x^500)(x + 1)^500").unwrap().degree());
+        assert_eq!(vec![1.0], expand("(x + 1)^0 * 0^4294967295 + 1"));
+
+        let cases = [
+            ("(x+1)^1000000", 0..13),
+            ("x^1001", 0..6),
+            ("2 + (x^2)^501", 4..13),
+            ("x^500 * (x + 1)^501", 0..19),
+            ("3x^999 x x", 0..10),
+        ];
+        for (string, span) in cases {
+            let err = Polynomial::from_expression(string).unwrap_err();
+            assert_eq!(PolynomialParsingError::DegreeTooHigh { span }, err, "{string}");
+        }
+    }
+
+    #[test]
+    fn nesting_limit() {
+        let nested = |depth| format!("{}x{}", "(".repeat(depth), ")".repeat(depth));
+        assert_eq!(vec![1.0, 0.0], expand(&nested(128)));
+        let err = Polynomial::from_expression(&nested(129)).unwrap_err();
+        assert_eq!(PolynomialParsingError::NestingTooDeep { span: 128..129 }, err);
+        let err = Polynomial::from_expression(&nested(100_000)).unwrap_err();
+        assert_eq!(PolynomialParsingError::NestingTooDeep { span: 128..129 }, err);
+
+        assert_eq!(vec![1.0, 0.0], expand(&"-".repeat(128).add("x")));
+        let err = Polynomial::from_expression(&"-".repeat(100_000).add("x")).unwrap_err();
+        assert_eq!(PolynomialParsingError::NestingTooDeep { span: 128..129 }, err);
+        assert_eq!("Nesting too deep at bytes 128..129.", err.to_string());
+    }
+}
@@ -0,0 +1,92 @@
+//! Module containing the plotting of polynomials with [`plotters`], enabled with the
+//! `plotters` feature.
+use std::error::Error;
+use std::ops::Range;
+use std::path::Path;
+use plotters::prelude::*;
+use super::Polynomial;
+use crate::FormatOptions;
+
+/// The number of points of the plotted curve.
+const SAMPLES: usize = 500;
+
+/// The size of the plot in pixels.
+const SIZE: (u32, u32) = (800, 600);
+
+/// Returns the limits of the y axis containing all the values, with a margin.
+fn y_limits(points: &[(f64, f64)]) -> Range<f64> {
+    let (min, max) = points
+        .iter()
+        .map(|(_, y)| *y)
+        .filter(|y| y.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), y| (min.min(y), max.max(y)));
+    if min > max {
+        return -1.0..1.0;
+    }
+    let margin = if min == max { 1.0 } else { (max - min) * 0.05 };
+    min - margin..max + margin
+}
+
+impl Polynomial {
+    /// Plots the polynomial on the given range of x to an SVG file.
+    ///
+    /// The limits of the y axis are chosen to fit the plotted curve, and the polynomial
+    /// formatted with the given options is used as the caption.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use polynomials::{FormatOptions, Polynomial};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -2.0]);
+    /// poly.plot_to_file("parabola.svg", -2.0..2.0, &FormatOptions::new()).unwrap();
+    /// ```
+    pub fn plot_to_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+        range: Range<f64>,
+        options: &FormatOptions
+    ) -> Result<(), Box<dyn Error>> {
+        let points = self.sample(range.clone(), SAMPLES);
+
+        let root = SVGBackend::new(path.as_ref(), SIZE).into_drawing_area();
+        root.fill(&WHITE)?;
+        let mut chart = ChartBuilder::on(&root)
+            .caption(self.format_opts(options), ("sans-serif", 24))
+            .margin(10)
+            .x_label_area_size(30)
+            .y_label_area_size(50)
+            .build_cartesian_2d(range, y_limits(&points))?;
+        chart.configure_mesh().draw()?;
+        chart.draw_series(LineSeries::new(points, &BLUE))?;
+        root.present()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use super::y_limits;
+    use crate::{FormatOptions, Polynomial};
+
+    #[test]
+    fn y_limits_contain_values() {
+        let limits = y_limits(&[(0.0, -2.0), (1.0, 8.0), (2.0, 3.0)]);
+        assert_eq!(-2.5..8.5, limits);
+        assert_eq!(3.0..5.0, y_limits(&[(0.0, 4.0), (1.0, 4.0)]));
+        assert_eq!(-1.0..1.0, y_limits(&[(0.0, f64::NAN)]));
+    }
+
+    #[test]
+    fn plot_to_file_writes_svg() {
+        let path = std::env::temp_dir().join(format!("polynomials-plot-{}.svg", std::process::id()));
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -2.0]);
+        poly.plot_to_file(&path, -2.0..2.0, &FormatOptions::new()).unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert!(contents.starts_with("<svg"));
+        assert!(contents.contains("x^2 - 2"));
+    }
+}
@@ -0,0 +1,326 @@
+use super::Polynomial;
+
+/// The maximum number of subintervals examined before the answer is given up as too close to
+/// call.
+const MAX_SUBINTERVALS: usize = 4096;
+
+/// Returns a bound of the relative rounding error of the computations on a polynomial of the
+/// given degree, with a safety factor over the usual `n * eps` bounds.
+fn error_factor(degree: usize) -> f64 {
+    8.0 * (degree + 2) as f64 * f64::EPSILON
+}
+
+/// Returns the coefficients in ascending order of powers, with the zero terms included.
+fn dense_coefficients(poly: &Polynomial) -> Vec<f64> {
+    let mut coefficients = poly.get_coefficients();
+    coefficients.reverse();
+    coefficients
+}
+
+/// Returns the rounding error of the floating point sum `a + b`, computed with Knuth's TwoSum.
+fn sum_error(a: f64, b: f64, sum: f64) -> f64 {
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (a - a_virtual) + (b - b_virtual)
+}
+
+/// A value computed in floating point arithmetic with a bound of its error.
+struct Certified {
+    value: f64,
+    error: f64,
+}
+
+impl Certified {
+    fn is_certainly_negative(&self) -> bool {
+        self.value + self.error < 0.0
+    }
+
+    fn is_certainly_positive(&self) -> bool {
+        self.value - self.error > 0.0
+    }
+
+    fn is_exactly_zero(&self) -> bool {
+        self.value == 0.0 && self.error == 0.0
+    }
+}
+
+/// Evaluates the polynomial with Horner's method, using error-free transformations to detect
+/// whether no rounding occurred, in which case the error is zero.
+fn evaluate_certified(coefficients: &[f64], x: f64) -> Certified {
+    let Some((leading, rest)) = coefficients.split_last() else {
+        return Certified { value: 0.0, error: 0.0 };
+    };
+    let mut value = *leading;
+    let mut absolute = leading.abs();
+    let mut exact = true;
+    for coefficient in rest.iter().rev() {
+        let product = value * x;
+        let product_error = value.mul_add(x, -product);
+        let sum = product + coefficient;
+        exact &= product_error == 0.0 && sum_error(product, *coefficient, sum) == 0.0;
+        value = sum;
+        absolute = absolute * x.abs() + coefficient.abs();
+    }
+    let error = if exact { 0.0 } else { error_factor(coefficients.len()) * absolute };
+    Certified { value, error }
+}
+
+/// Divides the polynomial by `x - root` if the division is exact and leaves no remainder.
+fn deflate_exactly(coefficients: &[f64], root: f64) -> Option<Vec<f64>> {
+    let (leading, rest) = coefficients.split_last()?;
+    let mut quotient = vec![0.0; rest.len()];
+    let mut carry = *leading;
+    for (index, coefficient) in rest.iter().enumerate().rev() {
+        let product = carry * root;
+        let sum = product + coefficient;
+        if carry.mul_add(root, -product) != 0.0 || sum_error(product, *coefficient, sum) != 0.0 {
+            return None;
+        }
+        quotient[index] = carry;
+        carry = sum;
+    }
+    (carry == 0.0).then_some(quotient)
+}
+
+/// Returns the Bernstein coefficients of the polynomial on the interval, and the bounds of
+/// their rounding errors.
+///
+/// The polynomial is shifted and scaled to `t` in `[0, 1]`, and the coefficients are converted
+/// with `b_i = Σ C(i, k) / C(n, k) c_k`. The same computation on the absolute values bounds the
+/// accumulated rounding errors.
+fn bernstein_coefficients(coefficients: &[f64], low: f64, high: f64) -> Vec<Certified> {
+    let degree = coefficients.len() - 1;
+    let width = high - low;
+    let mut shifted = coefficients.to_vec();
+    let mut absolute: Vec<f64> = coefficients.iter().map(|c| c.abs()).collect();
+    for i in 0..degree {
+        for j in (i..degree).rev() {
+            shifted[j] += low * shifted[j + 1];
+            absolute[j] += low.abs() * absolute[j + 1];
+        }
+    }
+    let mut scale = 1.0;
+    for k in 0..=degree {
+        shifted[k] *= scale;
+        absolute[k] *= scale;
+        scale *= width;
+    }
+
+    let mut bernstein: Vec<Certified> = (0..=degree)
+        .map(|_| Certified { value: 0.0, error: 0.0 })
+        .collect();
+    let mut binomial = 1.0;
+    for k in 0..=degree {
+        // ratio = C(i, k) / C(n, k), starting from i = k
+        let mut ratio = 1.0 / binomial;
+        for (i, coefficient) in bernstein.iter_mut().enumerate().skip(k) {
+            if i > k {
+                ratio *= i as f64 / (i - k) as f64;
+            }
+            coefficient.value += ratio * shifted[k];
+            coefficient.error += ratio * absolute[k];
+        }
+        binomial *= (degree - k) as f64 / (k + 1) as f64;
+    }
+    let factor = error_factor(degree);
+    for coefficient in bernstein.iter_mut() {
+        coefficient.error *= factor;
+    }
+    bernstein
+}
+
+/// Decides whether the polynomial is positive, or nonnegative if `strict` is false, on the
+/// interval.
+fn certify(coefficients: &[f64], a: f64, b: f64, strict: bool) -> Option<bool> {
+    if coefficients.iter().all(|coefficient| *coefficient == 0.0) {
+        return Some(!strict);
+    }
+
+    let mut intervals = vec![(a, b)];
+    let mut examined = 0;
+    let mut points = vec![a, b];
+    while let Some((low, high)) = intervals.pop() {
+        examined += 1;
+        if examined > MAX_SUBINTERVALS {
+            return None;
+        }
+        points.push(low + (high - low) / 2.0);
+        for x in points.drain(..) {
+            let value = evaluate_certified(coefficients, x);
+            if value.is_certainly_negative() {
+                return Some(false);
+            }
+            if value.is_exactly_zero() {
+                return if strict { Some(false) } else { certify_at_root(coefficients, a, b, x) };
+            }
+        }
+
+        let bernstein = bernstein_coefficients(coefficients, low, high);
+        if bernstein.iter().all(Certified::is_certainly_positive) {
+            continue;
+        }
+        let middle = low + (high - low) / 2.0;
+        if middle <= low || middle >= high {
+            return None;
+        }
+        intervals.push((low, middle));
+        intervals.push((middle, high));
+    }
+    Some(true)
+}
+
+/// Decides whether the polynomial, which has the exact root in the interval, is nonnegative on
+/// the interval, by factoring out the root as many times as possible.
+fn certify_at_root(coefficients: &[f64], a: f64, b: f64, root: f64) -> Option<bool> {
+    let mut quotient = coefficients.to_vec();
+    let mut multiplicity = 0;
+    while evaluate_certified(&quotient, root).is_exactly_zero() {
+        quotient = deflate_exactly(&quotient, root)?;
+        multiplicity += 1;
+    }
+
+    if multiplicity % 2 == 0 || root == a {
+        certify(&quotient, a, b, false)
+    } else if root == b {
+        let negated: Vec<f64> = quotient.iter().map(|coefficient| -coefficient).collect();
+        certify(&negated, a, b, false)
+    } else {
+        // The polynomial changes its sign at a root of odd multiplicity inside the interval
+        let value = evaluate_certified(&quotient, root);
+        (value.is_certainly_positive() || value.is_certainly_negative()).then_some(false)
+    }
+}
+
+impl Polynomial {
+    /// Decides whether the polynomial is positive at every point of the closed interval
+    /// `[a, b]`, or returns `None` if the answer is too close to call.
+    ///
+    /// The answer is certified despite the rounding errors: the interval is subdivided until
+    /// the Bernstein coefficients of the polynomial on every subinterval are positive with a
+    /// margin bounding their rounding errors, which proves the positivity, or until the
+    /// polynomial is found to be negative or exactly zero at a point. `None` is returned when
+    /// neither happens in a limited number of subdivisions, e.g. when the minimum of the
+    /// polynomial is zero up to the rounding errors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is not finite, or if `a` is greater than `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -1.0]);
+    /// assert_eq!(Some(true), poly.is_positive_on(1.5, 3.0));
+    /// assert_eq!(Some(false), poly.is_positive_on(0.0, 3.0));
+    /// ```
+    pub fn is_positive_on(&self, a: f64, b: f64) -> Option<bool> {
+        assert!(a.is_finite() && b.is_finite() && a <= b, "Invalid interval [{a}, {b}].");
+        certify(&dense_coefficients(self), a, b, true)
+    }
+
+    /// Decides whether the polynomial is nonnegative at every point of the closed interval
+    /// `[a, b]`, or returns `None` if the answer is too close to call.
+    ///
+    /// The answer is certified in the same way as in the
+    /// [`is_positive_on`](Polynomial::is_positive_on) method. The roots at which the
+    /// polynomial evaluates exactly to zero are factored out, so that the roots of even
+    /// multiplicity inside the interval, e.g. at integers or halves, are handled correctly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is not finite, or if `a` is greater than `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // (x - 1)^2
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 1.0]);
+    /// assert_eq!(Some(true), poly.is_nonnegative_on(0.0, 2.0));
+    /// assert_eq!(Some(false), poly.is_positive_on(0.0, 2.0));
+    /// ```
+    pub fn is_nonnegative_on(&self, a: f64, b: f64) -> Option<bool> {
+        assert!(a.is_finite() && b.is_finite() && a <= b, "Invalid interval [{a}, {b}].");
+        certify(&dense_coefficients(self), a, b, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Polynomial;
+
+    #[test]
+    fn sum_of_squares_is_positive_everywhere() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]);
+        for (a, b) in [(-1.0, 1.0), (0.0, 0.0), (-1e6, 1e6), (3.0, 7.5), (-1e-9, 1e-9)] {
+            assert_eq!(Some(true), poly.is_positive_on(a, b));
+            assert_eq!(Some(true), poly.is_nonnegative_on(a, b));
+        }
+    }
+
+    #[test]
+    fn double_root_is_nonnegative_but_not_positive() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 1.0]);
+        assert_eq!(Some(true), poly.is_nonnegative_on(0.0, 2.0));
+        assert_eq!(Some(false), poly.is_positive_on(0.0, 2.0));
+        assert_eq!(Some(true), poly.is_positive_on(1.5, 2.0));
+
+        // (x - 1)^2 (x + 0.75)^4 (x^2 + 1)
+        let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 1.0])
+            * &Polynomial::from_coefficients(&vec![1.0, 1.5, 0.5625])
+            * &Polynomial::from_coefficients(&vec![1.0, 1.5, 0.5625])
+            * &Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]);
+        assert_eq!(Some(true), poly.is_nonnegative_on(-3.0, 5.0));
+        assert_eq!(Some(false), poly.is_positive_on(-3.0, 5.0));
+    }
+
+    #[test]
+    fn odd_roots() {
+        // x (x - 1)^2 (x - 2)
+        let poly = Polynomial::from_coefficients(&vec![1.0, -4.0, 5.0, -2.0, 0.0]);
+        assert_eq!(Some(false), poly.is_nonnegative_on(0.5, 1.5));
+        assert_eq!(Some(false), poly.is_nonnegative_on(-1.0, 3.0));
+        // Nonpositive on [0, 2], with the roots of odd multiplicity at the ends
+        assert_eq!(Some(true), (-poly.clone()).is_nonnegative_on(0.0, 2.0));
+        assert_eq!(Some(true), poly.is_nonnegative_on(2.0, 3.0));
+        assert_eq!(Some(false), poly.is_positive_on(2.0, 3.0));
+    }
+
+    #[test]
+    fn tiny_dip_below_zero_is_not_positive() {
+        // (x - 1)^2 - 1e-12
+        let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 1.0 - 1e-12]);
+        assert_eq!(Some(false), poly.is_positive_on(0.0, 2.0));
+        assert_eq!(Some(false), poly.is_nonnegative_on(0.0, 2.0));
+
+        // (x - 1/3)^2 - 1e-12, with the minimum not at a representable point
+        let poly = Polynomial::from_coefficients(&vec![1.0, -2.0 / 3.0, 1.0 / 9.0 - 1e-12]);
+        assert_ne!(Some(true), poly.is_positive_on(0.0, 1.0));
+        assert_ne!(Some(true), poly.is_nonnegative_on(0.0, 1.0));
+    }
+
+    #[test]
+    fn too_close_to_call() {
+        // (x - 1/3)^2 has a double root which cannot be represented exactly
+        let poly = Polynomial::from_coefficients(&vec![1.0, -2.0 / 3.0, 1.0 / 9.0]);
+        assert_ne!(Some(true), poly.is_positive_on(0.0, 1.0));
+        assert_eq!(Some(true), poly.is_positive_on(0.5, 1.0));
+    }
+
+    #[test]
+    fn constants_and_zero() {
+        assert_eq!(Some(true), Polynomial::one().is_positive_on(-5.0, 5.0));
+        assert_eq!(Some(false), (-Polynomial::one()).is_nonnegative_on(-5.0, 5.0));
+        assert_eq!(Some(false), Polynomial::zero().is_positive_on(-5.0, 5.0));
+        assert_eq!(Some(true), Polynomial::zero().is_nonnegative_on(-5.0, 5.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid interval")]
+    fn reversed_interval() {
+        let _ = Polynomial::one().is_positive_on(1.0, 0.0);
+    }
+}
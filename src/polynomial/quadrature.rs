@@ -0,0 +1,128 @@
+use super::Polynomial;
+
+/// Depth at which the subdivision stops even if the error estimate is above the tolerance,
+/// which bounds the number of evaluations for the integrands with singular derivatives.
+const MAX_DEPTH: u32 = 20;
+
+/// The Simpson's rule estimate on `[a, b]` from the values at the endpoints and the midpoint.
+fn simpson(a: f64, b: f64, fa: f64, fm: f64, fb: f64) -> f64 {
+    (b - a) / 6.0 * (fa + 4.0 * fm + fb)
+}
+
+/// Integrates the function on the interval from `a` to `b` with the adaptive Simpson's rule,
+/// aiming at the absolute error below the tolerance.
+///
+/// The interval is halved while the difference between the estimates on it and on its halves
+/// is above 15 times the tolerance, which is split evenly between the halves. The difference is
+/// then added to the estimate as the Richardson extrapolation.
+pub(super) fn integrate_fn(f: impl Fn(f64) -> f64, a: f64, b: f64, tolerance: f64) -> f64 {
+    let (fa, fm, fb) = (f(a), f(a + (b - a) / 2.0), f(b));
+    let whole = simpson(a, b, fa, fm, fb);
+    integrate_adaptive(&f, (a, fa), (b, fb), fm, whole, tolerance, 0)
+}
+
+fn integrate_adaptive(
+    f: &impl Fn(f64) -> f64,
+    (a, fa): (f64, f64),
+    (b, fb): (f64, f64),
+    fm: f64,
+    whole: f64,
+    tolerance: f64,
+    depth: u32,
+) -> f64 {
+    let m = a + (b - a) / 2.0;
+    let (left_m, right_m) = (a + (m - a) / 2.0, m + (b - m) / 2.0);
+    let (f_left, f_right) = (f(left_m), f(right_m));
+    let left = simpson(a, m, fa, f_left, fm);
+    let right = simpson(m, b, fm, f_right, fb);
+    let difference = left + right - whole;
+    if depth >= MAX_DEPTH || difference.abs() <= 15.0 * tolerance {
+        return left + right + difference / 15.0;
+    }
+    integrate_adaptive(f, (a, fa), (m, fm), f_left, left, tolerance / 2.0, depth + 1)
+        + integrate_adaptive(f, (m, fm), (b, fb), f_right, right, tolerance / 2.0, depth + 1)
+}
+
+impl Polynomial {
+    /// Returns the length of the graph of the polynomial on the interval between `a` and `b`,
+    /// i.e. the integral of `sqrt(1 + P'(x)^2)`.
+    ///
+    /// The integral is computed with the adaptive Simpson's rule, aiming at the absolute error
+    /// below the tolerance. The subdivision depth is limited, so a tolerance too small for the
+    /// rounding errors of the integrand is not reached. The length is nonnegative for both
+    /// orders of the endpoints.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tolerance is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // The line y = 0.75x from 0 to 4 is the hypotenuse of the 3-4-5 triangle
+    /// let line = Polynomial::from_coefficients(&vec![0.75, 0.0]);
+    /// assert!((line.arc_length(0.0, 4.0, 1e-12) - 5.0).abs() < 1e-12);
+    /// ```
+    pub fn arc_length(&self, a: f64, b: f64, tolerance: f64) -> f64 {
+        assert!(tolerance > 0.0, "Invalid tolerance");
+        let (a, b) = if a <= b { (a, b) } else { (b, a) };
+        let derivative = self.derivative();
+        integrate_fn(|x| derivative.evaluate(x).hypot(1.0), a, b, tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::integrate_fn;
+    use crate::Polynomial;
+
+    /// The length of the graph of `x^2` on `[0, b]`.
+    fn parabola_length(b: f64) -> f64 {
+        let slope = 2.0 * b;
+        (slope * slope.hypot(1.0) + slope.asinh()) / 4.0
+    }
+
+    #[test]
+    fn integrate_fn_works() {
+        assert!((integrate_fn(f64::sin, 0.0, std::f64::consts::PI, 1e-12) - 2.0).abs() < 1e-12);
+        assert!((integrate_fn(|x| x.sqrt(), 0.0, 1.0, 1e-9) - 2.0 / 3.0).abs() < 1e-8);
+        assert_eq!(0.0, integrate_fn(f64::exp, 1.0, 1.0, 1e-9));
+    }
+
+    #[test]
+    fn arc_length_of_a_line() {
+        let line = Polynomial::from_coefficients(&vec![-2.0, 1.0]);
+        let expected = (3.0f64.powi(2) + 6.0f64.powi(2)).sqrt();
+        assert!((line.arc_length(-1.0, 2.0, 1e-10) - expected).abs() < 1e-12);
+        assert!((line.arc_length(2.0, -1.0, 1e-10) - expected).abs() < 1e-12);
+        assert!((Polynomial::zero().arc_length(-1.0, 2.0, 1e-10) - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn arc_length_of_a_parabola() {
+        let parabola = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0]);
+        assert!((parabola.arc_length(0.0, 3.0, 1e-10) - parabola_length(3.0)).abs() < 1e-10);
+        assert!((parabola.arc_length(-3.0, 3.0, 1e-10) - 2.0 * parabola_length(3.0)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn arc_length_error_follows_tolerance() {
+        let parabola = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0]);
+        let exact = parabola_length(10.0);
+        let mut previous_error = f64::INFINITY;
+        for tolerance in [1e-2, 1e-4, 1e-6, 1e-8, 1e-10] {
+            let error = (parabola.arc_length(0.0, 10.0, tolerance) - exact).abs();
+            assert!(error <= tolerance, "{error} > {tolerance}");
+            assert!(error <= previous_error);
+            previous_error = error;
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid tolerance")]
+    fn arc_length_with_zero_tolerance() {
+        Polynomial::one().arc_length(0.0, 1.0, 0.0);
+    }
+}
@@ -0,0 +1,652 @@
+use std::error::Error;
+use std::f64::consts::PI;
+use std::fmt;
+use super::Polynomial;
+use super::complex::{evaluate, Complex};
+use super::inequality::distinct_real_roots_unscaled;
+
+/// Error returned by [`Polynomial::solve`] when the roots have no closed form.
+#[derive(PartialEq, Debug, Clone)]
+pub enum SolveError {
+    /// The polynomial is zero, so every number is its root.
+    ZeroPolynomial,
+
+    /// The degree is above four, for which there is no general formula in radicals.
+    DegreeTooHigh {
+        degree: u32,
+    },
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::ZeroPolynomial => write!(f, "Every number is a root of the zero polynomial."),
+            SolveError::DegreeTooHigh { degree } => {
+                write!(f, "No closed-form roots for degree {degree}, above four.")
+            }
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+/// Returns whether the difference of the terms is zero up to the rounding errors of their
+/// computation, so that a discriminant within it is snapped to zero and the double roots come
+/// out real.
+fn cancels(minuend: f64, subtrahend: f64) -> bool {
+    (minuend - subtrahend).abs() <= 16.0 * f64::EPSILON * (minuend.abs() + subtrahend.abs())
+}
+
+/// Returns the roots of `x^2 + p x + q`.
+///
+/// The root of the larger magnitude is computed first and the other one from their product
+/// `q`, which avoids the cancellation in the quadratic formula.
+fn solve_quadratic(p: f64, q: f64) -> [Complex; 2] {
+    let half = -p / 2.0;
+    let square = half * half;
+    if cancels(square, q) {
+        return [Complex::real(half); 2];
+    }
+    let discriminant = square - q;
+    if discriminant < 0.0 {
+        let im = (-discriminant).sqrt();
+        return [Complex::new(half, -im), Complex::new(half, im)];
+    }
+    let large = half + discriminant.sqrt().copysign(half);
+    let small = if large == 0.0 { 0.0 } else { q / large };
+    [Complex::real(small), Complex::real(large)]
+}
+
+/// Returns the roots of `x^3 + a x^2 + b x + c` with Cardano's formula, or its trigonometric
+/// form when all the roots are real.
+fn solve_cubic(a: f64, b: f64, c: f64) -> [Complex; 3] {
+    // x = t - a / 3 gives t^3 + p t + q
+    let shift = -a / 3.0;
+    let p = b - a * a / 3.0;
+    let q = 2.0 * a * a * a / 27.0 - a * b / 3.0 + c;
+
+    let half_q_square = q * q / 4.0;
+    let third_p_cube = p * p * p / 27.0;
+    if !cancels(half_q_square, -third_p_cube) && half_q_square + third_p_cube > 0.0 {
+        // One real root and a pair of complex conjugate ones, the cube root with the larger
+        // magnitude is computed first to avoid the cancellation
+        let sqrt = (half_q_square + third_p_cube).sqrt();
+        let u = (-q / 2.0 - sqrt.copysign(q)).cbrt();
+        let v = if u == 0.0 { 0.0 } else { -p / (3.0 * u) };
+        let re = shift - (u + v) / 2.0;
+        let im = (u - v).abs() * 3f64.sqrt() / 2.0;
+        return [Complex::real(shift + u + v), Complex::new(re, -im), Complex::new(re, im)];
+    }
+    if p >= 0.0 {
+        // Both p and q are zero up to the rounding errors, a triple root
+        return [Complex::real(shift); 3];
+    }
+    let radius = 2.0 * (-p / 3.0).sqrt();
+    let cosine = (3.0 * q / (p * radius)).clamp(-1.0, 1.0);
+    let angle = cosine.acos() / 3.0;
+    [0.0, 1.0, 2.0].map(|k| Complex::real(shift + radius * (angle - 2.0 * PI * k / 3.0).cos()))
+}
+
+/// Returns the roots of `x^4 + a x^3 + b x^2 + c x + d` with Ferrari's method.
+fn solve_quartic(a: f64, b: f64, c: f64, d: f64) -> [Complex; 4] {
+    // x = y - a / 4 gives y^4 + p y^2 + q y + r
+    let shift = -a / 4.0;
+    let a2 = a * a;
+    let p = b - 3.0 * a2 / 8.0;
+    let q = c - a * b / 2.0 + a2 * a / 8.0;
+    let r = d - a * c / 4.0 + a2 * b / 16.0 - 3.0 * a2 * a2 / 256.0;
+
+    let roots = if cancels(c + a2 * a / 8.0, a * b / 2.0) {
+        // q is zero, and the roots are the square roots of the roots of z^2 + p z + r
+        let [z1, z2] = solve_quadratic(p, r);
+        let (y1, y2) = (z1.sqrt(), z2.sqrt());
+        [-y1, y1, -y2, y2]
+    } else {
+        // The largest real root of the resolvent cubic, which is positive since q is not zero
+        let m = solve_cubic(p, p * p / 4.0 - r, -q * q / 8.0)
+            .into_iter()
+            .filter(|root| root.im == 0.0)
+            .map(|root| root.re)
+            .fold(f64::NEG_INFINITY, f64::max);
+        // y^4 + p y^2 + q y + r = (y^2 + s y + p / 2 + m - q / 2s)(y^2 - s y + p / 2 + m + q / 2s)
+        let s = (2.0 * m).sqrt();
+        let [y1, y2] = solve_quadratic(s, p / 2.0 + m - q / (2.0 * s));
+        let [y3, y4] = solve_quadratic(-s, p / 2.0 + m + q / (2.0 * s));
+        [y1, y2, y3, y4]
+    };
+    roots.map(|root| root + Complex::real(shift))
+}
+
+/// Finds the root of the polynomial between the points at which its values have opposite
+/// signs with Newton's method, falling back to bisection when a Newton step leaves the
+/// bracket of the root.
+///
+/// The iteration stops when the step or the bracket is not longer than the tolerance, or
+/// after the maximal number of iterations.
+fn newton(poly: &Polynomial, mut low: f64, mut high: f64, tolerance: f64, max_iterations: usize) -> f64 {
+    let derivative = poly.derivative();
+    let low_is_negative = poly.evaluate(low) < 0.0;
+    let mut x = low + (high - low) / 2.0;
+    for _ in 0..max_iterations {
+        let value = poly.evaluate(x);
+        if value == 0.0 {
+            return x;
+        }
+        if (value < 0.0) == low_is_negative {
+            low = x;
+        } else {
+            high = x;
+        }
+        // The step is NaN or infinite at a zero of the derivative, and then it is rejected
+        let step = x - value / derivative.evaluate(x);
+        let next = if low < step && step < high { step } else { low + (high - low) / 2.0 };
+        if (next - x).abs() <= tolerance || high - low <= tolerance {
+            return next;
+        }
+        x = next;
+    }
+    x
+}
+
+/// Returns the complex roots of a polynomial with the Aberth–Ehrlich iteration, started from
+/// the points on the circle of the radius given by Cauchy's bound of the roots.
+///
+/// Every root is corrected by the Newton step for the polynomial divided by the factors of the
+/// other approximations, and the corrected value is used right away for the following ones.
+/// The iteration stops when no correction is larger than the tolerance relative to the
+/// magnitude of the root, or after the maximal number of iterations.
+fn aberth(poly: &Polynomial, tolerance: f64, max_iterations: usize) -> Vec<Complex> {
+    let Some(degree) = poly.degree().filter(|degree| *degree > 0) else {
+        return Vec::new();
+    };
+    let derivative = poly.derivative();
+    let leading_coefficient = poly.get_coefficient_at(degree).abs();
+    let radius = 1.0 + poly.coefficients
+        .range(..degree)
+        .map(|(_, coefficient)| coefficient.abs() / leading_coefficient)
+        .fold(0.0, f64::max);
+    // The offset of the angles keeps the starting points off the real axis, which is symmetric
+    let mut roots: Vec<Complex> = (0..degree)
+        .map(|k| Complex::from_angle(2.0 * PI * k as f64 / degree as f64 + 0.4).scale(radius))
+        .collect();
+
+    for _ in 0..max_iterations {
+        let mut converged = true;
+        for k in 0..roots.len() {
+            let z = roots[k];
+            let value = evaluate(poly, z);
+            if value == Complex::default() {
+                continue;
+            }
+            let ratio = value / evaluate(&derivative, z);
+            let repulsion = roots.iter()
+                .enumerate()
+                .filter(|(j, _)| *j != k)
+                .fold(Complex::default(), |sum, (_, root)| sum + Complex::real(1.0) / (z - *root));
+            let correction = ratio / (Complex::real(1.0) - ratio * repulsion);
+            // At a critical point the step is not finite, and the point is left to be moved by
+            // the corrections of the other roots
+            if !(correction.re.is_finite() && correction.im.is_finite()) {
+                converged = false;
+                continue;
+            }
+            roots[k] = z - correction;
+            if correction.abs() > tolerance * z.abs().max(1.0) {
+                converged = false;
+            }
+        }
+        if converged {
+            break;
+        }
+    }
+    roots
+}
+
+/// Makes the imaginary parts within the tolerance zero, and pairs every root in the upper half
+/// plane with the nearest one in the lower half plane into exact complex conjugates, as the
+/// roots of a real polynomial are.
+fn pair_conjugates(roots: Vec<Complex>, tolerance: f64) -> Vec<Complex> {
+    let mut paired = Vec::with_capacity(roots.len());
+    let mut upper = Vec::new();
+    let mut lower = Vec::new();
+    for root in roots {
+        if root.im.abs() <= tolerance * root.abs().max(1.0) {
+            paired.push(Complex::real(root.re));
+        } else if root.im > 0.0 {
+            upper.push(root);
+        } else {
+            lower.push(root);
+        }
+    }
+    for root in upper {
+        let nearest = lower.iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (root.conj() - **a).abs().total_cmp(&(root.conj() - **b).abs()))
+            .map(|(index, _)| index);
+        match nearest {
+            Some(index) => {
+                let other = lower.swap_remove(index);
+                let average = Complex::new((root.re + other.re) / 2.0, (root.im - other.im) / 2.0);
+                paired.push(average);
+                paired.push(average.conj());
+            }
+            None => paired.push(root),
+        }
+    }
+    paired.extend(lower);
+    paired
+}
+
+impl Polynomial {
+    /// Returns all the complex roots of a polynomial of degree at most four, repeated according
+    /// to their multiplicities, as `(re, im)` pairs sorted by the real and then the imaginary
+    /// part.
+    ///
+    /// The roots are computed with the closed-form formulas: the quadratic formula, Cardano's
+    /// formula for the cubics and Ferrari's method for the quartics. A discriminant that is
+    /// zero up to the rounding errors is snapped to zero, so that the real roots, including
+    /// the double ones, have the imaginary part exactly `0.0`. A nonzero constant has no
+    /// roots.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolveError::ZeroPolynomial`] for the zero polynomial and
+    /// [`SolveError::DegreeTooHigh`] for the degrees above four.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // x^2 - 2x + 5
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 5.0]);
+    /// assert_eq!(Ok(vec![(1.0, -2.0), (1.0, 2.0)]), poly.solve());
+    ///
+    /// // (x - 1)^2 (x + 2)
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -3.0, 2.0]);
+    /// let roots = poly.solve().unwrap();
+    /// assert!(roots.iter().all(|(_, im)| *im == 0.0));
+    /// assert!(roots.iter().zip([-2.0, 1.0, 1.0]).all(|((re, _), x)| (re - x).abs() < 1e-12));
+    /// ```
+    pub fn solve(&self) -> Result<Vec<(f64, f64)>, SolveError> {
+        let degree = self.degree().ok_or(SolveError::ZeroPolynomial)?;
+        let leading_coefficient = self.get_coefficient_at(degree);
+        let coefficient = |power: u32| self.get_coefficient_at(power) / leading_coefficient;
+        let mut roots = match degree {
+            0 => Vec::new(),
+            1 => vec![Complex::real(-coefficient(0))],
+            2 => solve_quadratic(coefficient(1), coefficient(0)).to_vec(),
+            3 => solve_cubic(coefficient(2), coefficient(1), coefficient(0)).to_vec(),
+            4 => solve_quartic(coefficient(3), coefficient(2), coefficient(1), coefficient(0)).to_vec(),
+            _ => return Err(SolveError::DegreeTooHigh { degree }),
+        };
+        roots.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+        Ok(roots.into_iter().map(Complex::to_pair).collect())
+    }
+
+    /// Returns all the complex roots of the polynomial, repeated according to their
+    /// multiplicities, as `(re, im)` pairs sorted by the real and then the imaginary part.
+    ///
+    /// The roots at zero are split off as the lowest power of the indeterminate, and the
+    /// other ones are found with the Aberth–Ehrlich iteration for the
+    /// [balanced](Polynomial::balanced) monic polynomial, from the starting points on a circle
+    /// of the radius of Cauchy's bound. The iteration stops when no root is corrected by more
+    /// than the tolerance relative to its magnitude, or after `max_iterations` iterations.
+    /// The imaginary parts within the tolerance are made zero, and the other roots are paired
+    /// into the exact complex conjugates. The multiple roots converge slower and are less
+    /// accurate than the simple ones.
+    ///
+    /// The zero polynomial and the nonzero constants have no roots to return.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tolerance is negative or `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // x^4 - 1
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0, 0.0, -1.0]);
+    /// let roots = poly.find_complex_roots(1e-12, 100);
+    /// let expected = [(-1.0, 0.0), (0.0, -1.0), (0.0, 1.0), (1.0, 0.0)];
+    /// assert!(roots.iter().zip(expected).all(|((re, im), (x, y))| (re - x).abs() < 1e-12 && (im - y).abs() < 1e-12));
+    /// ```
+    pub fn find_complex_roots(&self, tolerance: f64, max_iterations: usize) -> Vec<(f64, f64)> {
+        assert!(tolerance >= 0.0, "Invalid tolerance");
+        let Some((lowest_power, _)) = self.coefficients.first_key_value() else {
+            return Vec::new();
+        };
+        let mut roots = vec![Complex::default(); *lowest_power as usize];
+
+        let mut reduced = Polynomial::zero();
+        for (power, coefficient) in &self.coefficients {
+            reduced.set_coefficient_at(power - lowest_power, *coefficient);
+        }
+        let (scale, balanced) = reduced.balanced();
+        let found = aberth(&balanced.into_monic(), tolerance, max_iterations);
+        roots.extend(pair_conjugates(found, tolerance).into_iter().map(|root| root.scale(scale)));
+
+        roots.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+        roots.into_iter().map(Complex::to_pair).collect()
+    }
+
+    /// Returns the real roots of the polynomial in ascending order, with every multiple root
+    /// returned once.
+    ///
+    /// The roots of the derivative, found recursively, split the real line into the intervals
+    /// on which the polynomial is monotonic. The interval from the first to the last of them
+    /// is extended to both sides up to Cauchy's bound of the roots. Every interval with a sign
+    /// change contains one simple root, which is refined with Newton's method safeguarded by
+    /// bisection until a step is not longer than the tolerance, or for at most
+    /// `max_iterations` iterations, while the roots of the derivatives are refined to the full
+    /// precision. The roots of the derivative at which the polynomial is zero
+    /// up to the rounding errors are the multiple roots. The roots are searched for in the
+    /// [balanced](Polynomial::balanced) polynomial, so the tolerance is relative to the
+    /// magnitude of the roots, and the roots closer than the tolerance to each other are merged.
+    ///
+    /// The zero polynomial and the nonzero constants have no roots to return.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tolerance is negative or `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // (x - 1)(x - 2)(x + 3)
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -7.0, 6.0]);
+    /// let roots = poly.find_real_roots(1e-12, 100);
+    /// assert_eq!(3, roots.len());
+    /// assert!(roots.iter().zip([-3.0, 1.0, 2.0]).all(|(root, x)| (root - x).abs() < 1e-10));
+    /// ```
+    pub fn find_real_roots(&self, tolerance: f64, max_iterations: usize) -> Vec<f64> {
+        assert!(tolerance >= 0.0, "Invalid tolerance");
+        let (scale, balanced) = self.balanced();
+        // The roots of the derivatives bound the brackets, so they are found to the full
+        // precision, and the tolerance only applies to the roots of the polynomial itself
+        let degree = balanced.degree();
+        let refine = |poly: &Polynomial, low: f64, high: f64| {
+            let tolerance = if poly.degree() == degree { tolerance } else { 0.0 };
+            newton(poly, low, high, tolerance, max_iterations)
+        };
+        let mut roots: Vec<f64> = Vec::new();
+        for root in distinct_real_roots_unscaled(&balanced, &refine) {
+            match roots.last() {
+                Some(last) if root - last <= tolerance => {}
+                _ => roots.push(root),
+            }
+        }
+        for root in &mut roots {
+            *root *= scale;
+        }
+        roots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::newton;
+    use crate::{Polynomial, SolveError};
+    use crate::polynomial::strategy::polynomials_with;
+
+    fn assert_roots(expected: &[f64], actual: &[f64], tolerance: f64) {
+        assert_eq!(expected.len(), actual.len(), "{expected:?} != {actual:?}");
+        for (x, root) in expected.iter().zip(actual) {
+            assert!((x - root).abs() < tolerance, "{expected:?} != {actual:?}");
+        }
+    }
+
+    fn from_roots(roots: &[f64]) -> Polynomial {
+        roots.iter()
+            .map(|root| Polynomial::from_coefficients(&vec![1.0, -root]))
+            .product()
+    }
+
+    #[test]
+    fn simple_roots() {
+        let poly = from_roots(&[1.0, 2.0, -3.5]);
+        assert_roots(&[-3.5, 1.0, 2.0], &poly.find_real_roots(1e-12, 100), 1e-8);
+    }
+
+    #[test]
+    fn double_root() {
+        let poly = from_roots(&[1.5, 1.5, -2.0]);
+        assert_roots(&[-2.0, 1.5], &poly.find_real_roots(1e-12, 100), 1e-8);
+
+        let poly = from_roots(&[0.25, 0.25, 0.25, 3.0, 3.0]);
+        assert_roots(&[0.25, 3.0], &poly.find_real_roots(1e-12, 100), 1e-4);
+    }
+
+    #[test]
+    fn complex_roots_are_skipped() {
+        // (x^2 + 1)(x - 4)
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]) * from_roots(&[4.0]);
+        assert_roots(&[4.0], &poly.find_real_roots(1e-12, 100), 1e-8);
+        assert!(Polynomial::from_coefficients(&vec![1.0, 0.0, 2.0]).find_real_roots(1e-12, 100).is_empty());
+    }
+
+    #[test]
+    fn badly_scaled_roots() {
+        let poly = from_roots(&[1e-60, 3e-60, -2e-60]);
+        let roots: Vec<f64> = poly.find_real_roots(1e-12, 100).into_iter().map(|root| root * 1e60).collect();
+        assert_roots(&[-2.0, 1.0, 3.0], &roots, 1e-8);
+    }
+
+    #[test]
+    fn close_roots_are_separated() {
+        let poly = from_roots(&[1.0, 1.0 + 1e-4, 5.0]);
+        assert_roots(&[1.0, 1.0 + 1e-4, 5.0], &poly.find_real_roots(1e-12, 100), 1e-8);
+        let poly = from_roots(&[1.0, 1.0 + 1e-6, 5.0]);
+        assert_roots(&[1.0, 1.0 + 1e-6, 5.0], &poly.find_real_roots(1e-14, 100), 1e-8);
+        // Too close to be told apart from a double root
+        let poly = from_roots(&[1.0, 1.0 + 1e-9, 5.0]);
+        assert_roots(&[1.0, 5.0], &poly.find_real_roots(1e-14, 100), 1e-8);
+    }
+
+    #[test]
+    fn constants_have_no_roots() {
+        assert!(Polynomial::zero().find_real_roots(1e-12, 100).is_empty());
+        assert!(Polynomial::from_coefficients(&vec![3.0]).find_real_roots(1e-12, 100).is_empty());
+    }
+
+    #[test]
+    fn newton_falls_back_to_bisection() {
+        // x^3 - x, the bracket is [-0.5, 0.75] after the first iteration, and the Newton
+        // step from 0.75 leaves it
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -1.0, 0.0]);
+        assert!(newton(&poly, -0.5, 2.0, 1e-15, 100).abs() < 1e-14);
+        // The Newton step is infinite at the zero of the derivative
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -3.0, 0.0]);
+        let root = newton(&poly, 0.2, 1.8, 1e-15, 100);
+        assert!((root - 3f64.sqrt()).abs() < 1e-14);
+        // Without iterations the midpoint is returned
+        assert_eq!(1.0, newton(&poly, 0.2, 1.8, 1e-15, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid tolerance")]
+    fn negative_tolerance() {
+        Polynomial::one().find_real_roots(-1.0, 10);
+    }
+
+    fn assert_complex_roots(expected: &[(f64, f64)], actual: &[(f64, f64)], tolerance: f64) {
+        assert_eq!(expected.len(), actual.len(), "{expected:?} != {actual:?}");
+        for ((re, im), (root_re, root_im)) in expected.iter().zip(actual) {
+            assert!((re - root_re).abs() < tolerance && (im - root_im).abs() < tolerance, "{expected:?} != {actual:?}");
+        }
+    }
+
+    #[test]
+    fn solve_linear_and_constant() {
+        assert_eq!(Ok(vec![(-1.5, 0.0)]), Polynomial::from_coefficients(&vec![2.0, 3.0]).solve());
+        assert_eq!(Ok(vec![]), Polynomial::from_coefficients(&vec![-2.0]).solve());
+    }
+
+    #[test]
+    fn solve_quadratic() {
+        let poly = from_roots(&[-3.0, 0.5]) * 2.0;
+        assert_eq!(Ok(vec![(-3.0, 0.0), (0.5, 0.0)]), poly.solve());
+        // The smaller root is not lost to the cancellation
+        let poly = from_roots(&[1e-9, 1e9]);
+        let roots = poly.solve().unwrap();
+        assert_complex_roots(&[(1e-9, 0.0)], &roots[..1], 1e-24);
+    }
+
+    #[test]
+    fn solve_quadratic_double_root() {
+        let poly = from_roots(&[0.1, 0.1]);
+        assert_eq!(Ok(vec![(0.1, 0.0), (0.1, 0.0)]), poly.solve());
+    }
+
+    #[test]
+    fn solve_purely_complex_pair() {
+        // x^2 + 4
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 4.0]);
+        assert_eq!(Ok(vec![(0.0, -2.0), (0.0, 2.0)]), poly.solve());
+        // x^4 + 5x^2 + 4 = (x^2 + 1)(x^2 + 4)
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 5.0, 0.0, 4.0]);
+        let roots = poly.solve().unwrap();
+        assert_complex_roots(&[(0.0, -2.0), (0.0, -1.0), (0.0, 1.0), (0.0, 2.0)], &roots, 1e-12);
+    }
+
+    #[test]
+    fn solve_cubic() {
+        let poly = from_roots(&[-1.0, 2.0, 4.0]);
+        let roots = poly.solve().unwrap();
+        assert_complex_roots(&[(-1.0, 0.0), (2.0, 0.0), (4.0, 0.0)], &roots, 1e-12);
+        assert!(roots.iter().all(|(_, im)| *im == 0.0));
+
+        // (x - 2)(x^2 + 2x + 5)
+        let poly = from_roots(&[2.0]) * Polynomial::from_coefficients(&vec![1.0, 2.0, 5.0]);
+        let roots = poly.solve().unwrap();
+        assert_complex_roots(&[(-1.0, -2.0), (-1.0, 2.0), (2.0, 0.0)], &roots, 1e-12);
+        assert_eq!(0.0, roots[2].1);
+    }
+
+    #[test]
+    fn solve_cubic_multiple_roots() {
+        let poly = from_roots(&[3.0, -1.5, -1.5]);
+        let roots = poly.solve().unwrap();
+        assert_complex_roots(&[(-1.5, 0.0), (-1.5, 0.0), (3.0, 0.0)], &roots, 1e-7);
+        assert!(roots.iter().all(|(_, im)| *im == 0.0));
+
+        let poly = from_roots(&[0.5, 0.5, 0.5]);
+        assert_eq!(Ok(vec![(0.5, 0.0); 3]), poly.solve());
+    }
+
+    #[test]
+    fn solve_quartic() {
+        let poly = from_roots(&[-2.0, 0.5, 1.0, 3.0]);
+        let roots = poly.solve().unwrap();
+        assert_complex_roots(&[(-2.0, 0.0), (0.5, 0.0), (1.0, 0.0), (3.0, 0.0)], &roots, 1e-12);
+        assert!(roots.iter().all(|(_, im)| *im == 0.0));
+
+        // (x^2 - 2x + 2)(x^2 + 6x + 13)
+        let poly = Polynomial::from_coefficients(&vec![1.0, -2.0, 2.0]) * Polynomial::from_coefficients(&vec![1.0, 6.0, 13.0]);
+        let roots = poly.solve().unwrap();
+        assert_complex_roots(&[(-3.0, -2.0), (-3.0, 2.0), (1.0, -1.0), (1.0, 1.0)], &roots, 1e-12);
+    }
+
+    #[test]
+    fn solve_quartic_double_roots() {
+        let poly = from_roots(&[1.0, 1.0, -2.0, -2.0]);
+        let roots = poly.solve().unwrap();
+        assert_complex_roots(&[(-2.0, 0.0), (-2.0, 0.0), (1.0, 0.0), (1.0, 0.0)], &roots, 1e-7);
+        assert!(roots.iter().all(|(_, im)| *im == 0.0));
+
+        let poly = from_roots(&[1.0, 1.0, 2.0, 5.0]);
+        let roots = poly.solve().unwrap();
+        assert_complex_roots(&[(1.0, 0.0), (1.0, 0.0), (2.0, 0.0), (5.0, 0.0)], &roots, 1e-7);
+        assert!(roots.iter().all(|(_, im)| *im == 0.0));
+    }
+
+    #[test]
+    fn solve_errors() {
+        assert_eq!(Err(SolveError::ZeroPolynomial), Polynomial::zero().solve());
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0, 0.0, 0.0, -1.0]);
+        assert_eq!(Err(SolveError::DegreeTooHigh { degree: 5 }), poly.solve());
+        assert_eq!("No closed-form roots for degree 5, above four.", SolveError::DegreeTooHigh { degree: 5 }.to_string());
+    }
+
+    /// Asserts that the residuals are within the tolerance relative to the sum of the
+    /// magnitudes of the terms, which bounds the rounding errors of the evaluation.
+    fn assert_residuals(poly: &Polynomial, roots: &[(f64, f64)], tolerance: f64) {
+        let absolute = poly.coefficients.values().map(|coefficient| coefficient.abs()).collect::<Vec<_>>();
+        for (re, im) in roots {
+            let (value_re, value_im) = poly.evaluate_complex((*re, *im));
+            let magnitude = re.hypot(*im);
+            let terms: f64 = poly.coefficients.keys().zip(&absolute).map(|(power, c)| c * magnitude.powi(*power as i32)).sum();
+            assert!(value_re.hypot(value_im) <= tolerance * terms, "P({re}, {im}) = ({value_re}, {value_im})");
+        }
+    }
+
+    #[test]
+    fn roots_of_unity() {
+        // x^6 + x^5 + ... + 1, the seventh roots of unity other than 1
+        let poly = Polynomial::from_coefficients(&vec![1.0; 7]);
+        let roots = poly.find_complex_roots(1e-14, 100);
+        let mut expected: Vec<(f64, f64)> = (1..7)
+            .map(|k| {
+                let angle = 2.0 * std::f64::consts::PI * k as f64 / 7.0;
+                (angle.cos(), angle.sin())
+            })
+            .collect();
+        expected.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+        assert_complex_roots(&expected, &roots, 1e-12);
+        assert_residuals(&poly, &roots, 1e-12);
+    }
+
+    #[test]
+    fn complex_roots_are_conjugate_pairs() {
+        // (x^2 + 2x + 5)(x - 3)(x^2 + 1)
+        let poly = Polynomial::from_coefficients(&vec![1.0, 2.0, 5.0])
+            * from_roots(&[3.0])
+            * Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]);
+        let roots = poly.find_complex_roots(1e-14, 100);
+        assert_complex_roots(&[(-1.0, -2.0), (-1.0, 2.0), (0.0, -1.0), (0.0, 1.0), (3.0, 0.0)], &roots, 1e-12);
+        assert_eq!(0.0, roots[4].1);
+        assert_eq!((roots[0].0, -roots[0].1), roots[1]);
+        assert_eq!((roots[2].0, -roots[2].1), roots[3]);
+    }
+
+    #[test]
+    fn zero_roots_are_split_off() {
+        // x^3 (x^2 - 4)
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -4.0, 0.0, 0.0, 0.0]);
+        let roots = poly.find_complex_roots(1e-14, 100);
+        assert_complex_roots(&[(-2.0, 0.0), (0.0, 0.0), (0.0, 0.0), (0.0, 0.0), (2.0, 0.0)], &roots, 1e-12);
+        assert_eq!(vec![(0.0, 0.0); 3], Polynomial::from_coefficients(&vec![5.0, 0.0, 0.0, 0.0]).find_complex_roots(1e-14, 100));
+    }
+
+    #[test]
+    fn complex_roots_of_constants() {
+        assert!(Polynomial::zero().find_complex_roots(1e-14, 100).is_empty());
+        assert!(Polynomial::from_coefficients(&vec![-3.0]).find_complex_roots(1e-14, 100).is_empty());
+    }
+
+    #[test]
+    fn complex_roots_of_badly_scaled_polynomial() {
+        let poly = from_roots(&[1e-50, -2e-50]) * Polynomial::from_coefficients(&vec![1.0, 0.0, 9e-100]);
+        let roots: Vec<(f64, f64)> = poly.find_complex_roots(1e-14, 100)
+            .into_iter()
+            .map(|(re, im)| (re * 1e50, im * 1e50))
+            .collect();
+        assert_complex_roots(&[(-2.0, 0.0), (0.0, -3.0), (0.0, 3.0), (1.0, 0.0)], &roots, 1e-10);
+    }
+
+    proptest! {
+        #[test]
+        fn complex_roots_have_small_residuals(
+            poly in polynomials_with((-9..=9).prop_map(f64::from), 20),
+        ) {
+            let roots = poly.find_complex_roots(1e-12, 500);
+            prop_assert_eq!(poly.degree().unwrap_or(0) as usize, roots.len());
+            assert_residuals(&poly, &roots, 1e-8);
+        }
+    }
+}
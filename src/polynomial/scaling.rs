@@ -0,0 +1,345 @@
+use super::Polynomial;
+use super::arithmetic::binary_pow;
+use super::inequality::Sign;
+
+/// A value `m * 2^e` with the magnitude of the mantissa `m` in `[0.5, 1)`, or zero as `(0, 0)`.
+/// The exponent is wider than the one returned, so that the intermediate results of the
+/// evaluation cannot overflow it even for the highest powers.
+type Scaled = (f64, i64);
+
+/// Multiplies the value by `2^exponent` in steps, so that the result is exact whenever it is
+/// a normal number, even if `2^exponent` itself is not representable.
+pub(super) fn mul_power_of_two(mut value: f64, mut exponent: i32) -> f64 {
+    const STEP: i32 = 1000;
+    while exponent > STEP {
+        value *= 2f64.powi(STEP);
+        exponent -= STEP;
+    }
+    while exponent < -STEP {
+        value *= 2f64.powi(-STEP);
+        exponent += STEP;
+    }
+    value * 2f64.powi(exponent)
+}
+
+/// Splits the value into the mantissa and the binary exponent, like C's `frexp`. Zero and the
+/// non-finite values are returned with the exponent zero.
+pub(super) fn frexp(value: f64) -> (f64, i32) {
+    if value == 0.0 || !value.is_finite() {
+        return (value, 0);
+    }
+    // Subnormal values are brought into the normal range first
+    let (value, offset) = if value.abs() < f64::MIN_POSITIVE {
+        (value * 2f64.powi(64), -64)
+    } else {
+        (value, 0)
+    };
+    let bits = value.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i32 - 1022;
+    let mantissa = f64::from_bits((bits & !(0x7ff << 52)) | (1022 << 52));
+    (mantissa, exponent + offset)
+}
+
+fn scaled(value: f64) -> Scaled {
+    let (mantissa, exponent) = frexp(value);
+    (mantissa, exponent.into())
+}
+
+fn normalize((mantissa, exponent): Scaled) -> Scaled {
+    let (mantissa, shift) = scaled(mantissa);
+    if mantissa == 0.0 {
+        return (0.0, 0);
+    }
+    (mantissa, exponent + shift)
+}
+
+/// Narrows the exponent to `i32`, saturating a value too large to `(±0.5, i32::MAX)` and a
+/// value too small to `(0.0, 0)`.
+fn saturate((mantissa, exponent): Scaled) -> (f64, i32) {
+    match i32::try_from(exponent) {
+        Ok(exponent) => (mantissa, exponent),
+        Err(_) if !mantissa.is_finite() => (mantissa, 0),
+        Err(_) if exponent > 0 => (0.5f64.copysign(mantissa), i32::MAX),
+        Err(_) => (0.0, 0),
+    }
+}
+
+fn scaled_mul(a: Scaled, b: Scaled) -> Scaled {
+    normalize((a.0 * b.0, a.1 + b.1))
+}
+
+fn scaled_add(a: Scaled, b: Scaled) -> Scaled {
+    if a.0 == 0.0 {
+        return b;
+    }
+    if b.0 == 0.0 {
+        return a;
+    }
+    let exponent = a.1.max(b.1);
+    // A shift below the smallest subnormal exponent makes a mantissa vanish all the same
+    let shift = |e: i64| (e - exponent).max(-1100) as i32;
+    normalize((mul_power_of_two(a.0, shift(a.1)) + mul_power_of_two(b.0, shift(b.1)), exponent))
+}
+
+fn scaled_pow(base: Scaled, exponent: u32) -> Scaled {
+    binary_pow(base, exponent, (0.5, 1), |a, b| Some(scaled_mul(*a, *b)))
+        .expect("Multiplication cannot fail")
+}
+
+impl Polynomial {
+    /// Evaluates the polynomial at `x`, returning the value as the mantissa `m` and the binary
+    /// exponent `e` of `m * 2^e`, so that values far outside the range of `f64` are represented.
+    ///
+    /// The magnitude of the mantissa is in `[0.5, 1)`, and zero is returned as `(0.0, 0)`. The
+    /// intermediate results of Horner's method are renormalized after every operation, so they
+    /// neither overflow nor underflow. If the exponent of the value does not fit into `i32`, the
+    /// result saturates to `(±0.5, i32::MAX)` for values too large and to `(0.0, 0)` for values
+    /// too small. An infinite or `NaN` argument or coefficient gives a non-finite mantissa.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![3.0, 0.0, 0.0, 0.0, 1.0]);
+    /// assert_eq!(f64::INFINITY, poly.evaluate(1e100));
+    ///
+    /// let (mantissa, exponent) = poly.evaluate_scaled(2f64.powi(300));
+    /// assert_eq!((0.75, 1202), (mantissa, exponent));
+    /// ```
+    pub fn evaluate_scaled(&self, x: f64) -> (f64, i32) {
+        let x = scaled(x);
+        let mut result = (0.0, 0);
+        let mut last_power: Option<u32> = None;
+
+        for (power, coefficient) in self.coefficients.iter().rev() {
+            if let Some(last_power) = last_power {
+                result = scaled_mul(result, scaled_pow(x, last_power - *power));
+            }
+            result = scaled_add(result, scaled(*coefficient));
+            last_power = Some(*power);
+        }
+
+        // Multiply by the lowest power of x present in the polynomial
+        if let Some(last_power) = last_power {
+            result = scaled_mul(result, scaled_pow(x, last_power));
+        }
+        saturate(result)
+    }
+
+    /// Returns the sign of the value of the polynomial at `x`, computed with
+    /// [`evaluate_scaled`](Polynomial::evaluate_scaled) so that it is correct even if the value
+    /// itself overflows. A `NaN` value has the sign [`Sign::Zero`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{Polynomial, Sign};
+    ///
+    /// // x^2 - 1e300 x
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, -1e300, 0.0]);
+    /// assert_eq!(f64::NEG_INFINITY, poly.evaluate(0.5e300));
+    /// assert_eq!(Sign::Positive, poly.sign_at(2e300));
+    /// assert_eq!(Sign::Negative, poly.sign_at(0.5e300));
+    /// ```
+    pub fn sign_at(&self, x: f64) -> Sign {
+        match self.evaluate_scaled(x).0 {
+            mantissa if mantissa > 0.0 => Sign::Positive,
+            mantissa if mantissa < 0.0 => Sign::Negative,
+            _ => Sign::Zero,
+        }
+    }
+
+    /// Returns the power of two `s` and the polynomial `Q(y) = c * P(s * y)`, where `c` is a
+    /// power of two as well, whose coefficients are of a similar magnitude.
+    ///
+    /// The roots of `Q` multiplied by `s` are the roots of `P`. The scale is the power of two
+    /// closest to `|a_m / a_n|^(1 / (n - m))`, the geometric mean of the magnitudes of the
+    /// nonzero roots, where `a_n` is the leading coefficient and `a_m` is the lowest nonzero
+    /// one. The factor `c` brings the largest coefficient close to one. Multiplying by powers of
+    /// two is exact, so no rounding errors are introduced unless a coefficient overflows or
+    /// becomes subnormal. Polynomials with fewer than two terms are returned unchanged with the
+    /// scale one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // (x - 1024)(x - 4096)
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, -5120.0, 4194304.0]);
+    /// let (scale, balanced) = poly.balanced();
+    /// assert_eq!(2048.0, scale);
+    /// assert_eq!(vec![0.5, -1.25, 0.5], balanced.get_coefficients());
+    /// assert_eq!(0.0, balanced.evaluate(1024.0 / scale));
+    /// ```
+    pub fn balanced(&self) -> (f64, Polynomial) {
+        let (Some((low, low_coefficient)), Some((high, high_coefficient))) =
+            (self.coefficients.first_key_value(), self.coefficients.last_key_value()) else {
+            return (1.0, self.clone());
+        };
+        if low == high || !self.coefficients.values().all(|coefficient| coefficient.is_finite()) {
+            return (1.0, self.clone());
+        }
+
+        // log2 of the geometric mean of the magnitudes of the nonzero roots
+        let ratio_log = (low_coefficient.abs().log2() - high_coefficient.abs().log2()) / (high - low) as f64;
+        let exponent = ratio_log.round() as i32;
+
+        // log2 of the largest scaled coefficient
+        let shift = self.coefficients
+            .iter()
+            .map(|(power, coefficient)| coefficient.abs().log2() + (*power as f64) * exponent as f64)
+            .fold(f64::NEG_INFINITY, f64::max)
+            .round() as i32;
+
+        let mut result = Polynomial::zero();
+        for (power, coefficient) in &self.coefficients {
+            let coefficient_exponent = (*power as i32).saturating_mul(exponent).saturating_sub(shift);
+            result.set_coefficient_at(*power, mul_power_of_two(*coefficient, coefficient_exponent));
+        }
+        (mul_power_of_two(1.0, exponent), result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+    use num_traits::{Signed, ToPrimitive, Zero};
+    use super::{frexp, mul_power_of_two};
+    use crate::{Polynomial, Sign};
+
+    /// Evaluates the polynomial exactly.
+    fn evaluate_exactly(poly: &Polynomial, x: f64) -> BigRational {
+        let x = BigRational::from_float(x).unwrap();
+        (0..=poly.degree().unwrap_or(0))
+            .map(|power| BigRational::from_float(poly.get_coefficient_at(power)).unwrap() * x.pow(power as i32))
+            .fold(BigRational::zero(), |sum, term| sum + term)
+    }
+
+    /// Asserts that the scaled value is the exact value up to the relative error.
+    fn assert_scaled_eq(exact: &BigRational, (mantissa, exponent): (f64, i32)) {
+        let power_of_two = BigRational::from_integer(BigInt::from(1) << exponent.unsigned_abs());
+        let exact_mantissa = if exponent >= 0 { exact / power_of_two } else { exact * power_of_two };
+        let exact_mantissa = exact_mantissa.to_f64().unwrap();
+        assert!((mantissa - exact_mantissa).abs() < 1e-12, "{mantissa} != {exact_mantissa}");
+    }
+
+    #[test]
+    fn frexp_works() {
+        assert_eq!((0.5, 1), frexp(1.0));
+        assert_eq!((-0.75, 2), frexp(-3.0));
+        assert_eq!((0.5, -1073), frexp(f64::from_bits(1)));
+        assert_eq!((0.0, 0), frexp(0.0));
+        let (mantissa, exponent) = frexp(f64::MAX);
+        assert_eq!(f64::MAX, mul_power_of_two(mantissa, exponent));
+    }
+
+    #[test]
+    fn evaluate_scaled_matches_evaluate_in_range() {
+        let poly = Polynomial::from_coefficients(&vec![2.0, -3.0, 0.0, 5.0, -7.0]);
+        for x in [-3.5, -1.0, 0.0, 0.25, 2.0, 10.0] {
+            let (mantissa, exponent) = poly.evaluate_scaled(x);
+            assert_eq!(poly.evaluate(x), mul_power_of_two(mantissa, exponent));
+        }
+        assert_eq!((0.0, 0), Polynomial::zero().evaluate_scaled(1e300));
+        assert_eq!((0.0, 0), Polynomial::from_coefficients(&vec![1.0, 0.0]).evaluate_scaled(0.0));
+    }
+
+    #[test]
+    fn evaluate_scaled_of_degree_60_at_1e8() {
+        let coefficients: Vec<f64> = (0..=60).map(|i| ((i * 7) % 11) as f64 - 5.0).collect();
+        let poly = Polynomial::from_coefficients(&coefficients);
+        assert!(poly.evaluate(1e8).is_infinite());
+        for x in [1e8, -1e8, 3.7e7] {
+            assert_scaled_eq(&evaluate_exactly(&poly, x), poly.evaluate_scaled(x));
+        }
+    }
+
+    #[test]
+    fn evaluate_scaled_at_huge_and_tiny_arguments() {
+        // (x - 1e300)(x + 1) with the constant term cancelling far below the others
+        let poly = Polynomial::from_coefficients(&vec![1.0, 1.0 - 1e300, -1e300]);
+        for x in [3e300, 1.5e300, 0.5e300, -1e300, -1e-300] {
+            let exact = evaluate_exactly(&poly, x);
+            let scaled = poly.evaluate_scaled(x);
+            assert_scaled_eq(&exact, scaled);
+            let expected_sign = if exact.is_positive() { Sign::Positive } else { Sign::Negative };
+            assert_eq!(expected_sign, poly.sign_at(x));
+        }
+        assert!(!poly.evaluate(3e300).is_finite());
+
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(0.0, poly.evaluate(1e-100));
+        assert_scaled_eq(&evaluate_exactly(&poly, 1e-100), poly.evaluate_scaled(1e-100));
+    }
+
+    #[test]
+    fn evaluate_scaled_saturates_the_exponent() {
+        // The exponent of 1e300^3000000 is about 3e9, beyond the range of i32
+        let mut poly = Polynomial::zero();
+        poly.set_coefficient_at(3_000_000, -1.0);
+        poly.set_coefficient_at(0, 1.0);
+        assert_eq!((-0.5, i32::MAX), poly.evaluate_scaled(1e300));
+        assert_eq!(Sign::Negative, poly.sign_at(1e300));
+        poly.set_coefficient_at(3_000_001, 1.0);
+        assert_eq!((0.5, i32::MAX), poly.evaluate_scaled(1e300));
+        assert_eq!(Sign::Positive, poly.sign_at(1e300));
+        assert_eq!(Sign::Negative, poly.sign_at(-1e300));
+
+        let mut poly = Polynomial::zero();
+        poly.set_coefficient_at(3_000_000, 1.0);
+        assert_eq!((0.0, 0), poly.evaluate_scaled(1e-300));
+    }
+
+    #[test]
+    fn sign_at_roots_and_non_finite_values() {
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -4.0]);
+        assert_eq!(Sign::Zero, poly.sign_at(2.0));
+        assert_eq!(Sign::Zero, poly.sign_at(f64::NAN));
+        assert_eq!(Sign::Positive, poly.sign_at(f64::MAX));
+        assert_eq!(Sign::Zero, Polynomial::zero().sign_at(1.0));
+    }
+
+    #[test]
+    fn mul_power_of_two_is_exact_beyond_the_range_of_the_factor() {
+        assert_eq!(3.0, mul_power_of_two(3.0 * 2f64.powi(-1000), 1000));
+        assert_eq!(1.5 * 2f64.powi(1000), mul_power_of_two(1.5 * 2f64.powi(-1000), 2000));
+        assert_eq!(2f64.powi(-1000), mul_power_of_two(2f64.powi(1000), -2000));
+    }
+
+    #[test]
+    fn balancing_is_exact() {
+        let poly = Polynomial::from_coefficients(&vec![3e-20, 0.0, -1.1e-3, 7.3, 0.0, 2.9e18]);
+        let (scale, balanced) = poly.balanced();
+        assert_eq!(scale, 2f64.powi(scale.log2() as i32));
+        assert!(scale > 1e7);
+
+        // Substituting x / s back and multiplying by the power of two restores the original
+        let unscaled = balanced.compose(&Polynomial::from_coefficients(&vec![1.0 / scale, 0.0]));
+        let factor = poly.get_coefficient_at(0) / unscaled.get_coefficient_at(0);
+        assert_eq!(factor, 2f64.powi(factor.log2() as i32));
+        assert_eq!(poly, unscaled * factor);
+    }
+
+    #[test]
+    fn balancing_evens_out_the_coefficients() {
+        // (x - 1e-8)(x - 3e-8)(x - 5e-8) has coefficients from 1 down to 1.5e-23
+        let poly = Polynomial::from_coefficients(&vec![1.0, -1e-8])
+            * &Polynomial::from_coefficients(&vec![1.0, -3e-8])
+            * &Polynomial::from_coefficients(&vec![1.0, -5e-8]);
+        let (scale, balanced) = poly.balanced();
+        assert!(scale > 1e-8 && scale < 1e-7);
+        for coefficient in balanced.get_coefficients() {
+            assert!(coefficient.abs() > 0.1 && coefficient.abs() <= 2.0, "{balanced}");
+        }
+    }
+
+    #[test]
+    fn balancing_short_polynomials() {
+        for poly in [Polynomial::zero(), Polynomial::from_coefficients(&vec![5.0]), Polynomial::from_coefficients(&vec![5.0, 0.0])] {
+            assert_eq!((1.0, poly.clone()), poly.balanced());
+        }
+    }
+}
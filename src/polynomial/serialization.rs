@@ -0,0 +1,214 @@
+//! Module containing the [`serde`] support, enabled with the `serde` feature.
+//!
+//! By default, a [`Polynomial`] is serialized as a sparse map from the powers to the non-zero
+//! coefficients, e.g. `{"0": -1.0, "2": 3.0}` in JSON. The [`dense`] and [`string`] modules
+//! provide alternative representations, usable with the `#[serde(with = "...")]` attribute.
+//!
+//! # Examples
+//!
+//! ```
+//! use polynomials::Polynomial;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Filter {
+//!     numerator: Polynomial,
+//!     #[serde(with = "polynomials::serialization::dense")]
+//!     denominator: Polynomial,
+//!     #[serde(with = "polynomials::serialization::string")]
+//!     description: Polynomial,
+//! }
+//!
+//! let filter = Filter {
+//!     numerator: Polynomial::from_coefficients(&vec![3.0, 0.0, -1.0]),
+//!     denominator: Polynomial::from_coefficients(&vec![1.0, 0.5]),
+//!     description: Polynomial::from_coefficients(&vec![2.0, 0.0]),
+//! };
+//! let json = serde_json::to_string(&filter).unwrap();
+//! assert_eq!(
+//!     r#"{"numerator":{"0":-1.0,"2":3.0},"denominator":[1.0,0.5],"description":"2x"}"#,
+//!     json
+//! );
+//! ```
+use std::collections::BTreeMap;
+use std::fmt;
+use serde::de::{Error, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use super::Polynomial;
+
+impl Serialize for Polynomial {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.coefficients.iter())
+    }
+}
+
+/// Visitor reading the sparse map representation.
+struct SparseVisitor;
+
+impl<'de> Visitor<'de> for SparseVisitor {
+    type Value = Polynomial;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map from powers to coefficients")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut coefficients = BTreeMap::new();
+        while let Some((power, coefficient)) = map.next_entry::<u32, f64>()? {
+            if coefficients.insert(power, coefficient).is_some() {
+                return Err(A::Error::custom(format!("duplicate power {power}")));
+            }
+        }
+
+        // Explicit zeros are allowed in the input, but must not be stored
+        let mut poly = Polynomial { coefficients };
+        poly.remove_zero_coefficients();
+        Ok(poly)
+    }
+}
+
+impl<'de> Deserialize<'de> for Polynomial {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(SparseVisitor)
+    }
+}
+
+/// Serializes a [`Polynomial`] as a dense sequence of coefficients, in the same order as
+/// returned by the `Polynomial.get_coefficients` method.
+///
+/// # Examples
+///
+/// ```
+/// use polynomials::Polynomial;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Wrapper(#[serde(with = "polynomials::serialization::dense")] Polynomial);
+///
+/// let poly = Polynomial::from_coefficients(&vec![2.0, 0.0, -1.0]);
+/// assert_eq!("[2.0,0.0,-1.0]", serde_json::to_string(&Wrapper(poly)).unwrap());
+/// ```
+pub mod dense {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use crate::Polynomial;
+
+    pub fn serialize<S: Serializer>(poly: &Polynomial, serializer: S) -> Result<S::Ok, S::Error> {
+        poly.get_coefficients().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Polynomial, D::Error> {
+        let coefficients = Vec::<f64>::deserialize(deserializer)?;
+        Ok(Polynomial::from_coefficients(&coefficients))
+    }
+}
+
+/// Serializes a [`Polynomial`] as a human-readable string, written by the [`Display`]
+/// implementation and read by the `Polynomial.from_string` method.
+///
+/// The representation is intended for self-describing formats like JSON or TOML. The
+/// polynomials with non-finite coefficients cannot be read back.
+///
+/// [`Display`]: std::fmt::Display
+///
+/// # Examples
+///
+/// ```
+/// use polynomials::Polynomial;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Wrapper(#[serde(with = "polynomials::serialization::string")] Polynomial);
+///
+/// let poly = Polynomial::from_coefficients(&vec![2.0, 0.0, -1.0]);
+/// assert_eq!(r#""2x^2 - 1""#, serde_json::to_string(&Wrapper(poly)).unwrap());
+/// ```
+pub mod string {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use crate::Polynomial;
+
+    pub fn serialize<S: Serializer>(poly: &Polynomial, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(poly)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Polynomial, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        Polynomial::from_string(&string).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use crate::Polynomial;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Representations {
+        sparse: Polynomial,
+        #[serde(with = "super::dense")]
+        dense: Polynomial,
+        #[serde(with = "super::string")]
+        string: Polynomial,
+    }
+
+    fn sample_polynomials() -> Vec<Polynomial> {
+        let mut high_degree = Polynomial::from_coefficients(&vec![1.0 / 3.0]);
+        high_degree.set_coefficient_at(40, -1e-300);
+        vec![
+            Polynomial::zero(),
+            Polynomial::from_coefficients(&vec![-7.5]),
+            Polynomial::from_coefficients(&vec![2.0, 0.0, -0.1, 5.0]),
+            high_degree,
+        ]
+    }
+
+    #[test]
+    fn json_round_trip() {
+        for poly in sample_polynomials() {
+            let value = Representations { sparse: poly.clone(), dense: poly.clone(), string: poly };
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(value, serde_json::from_str(&json).unwrap(), "{json}");
+        }
+    }
+
+    #[test]
+    fn postcard_round_trip() {
+        for poly in sample_polynomials() {
+            let bytes = postcard::to_allocvec(&poly).unwrap();
+            assert_eq!(poly, postcard::from_bytes::<Polynomial>(&bytes).unwrap());
+        }
+    }
+
+    #[test]
+    fn sparse_format() {
+        let poly = Polynomial::from_coefficients(&vec![3.0, 0.0, -1.0]);
+        assert_eq!(r#"{"0":-1.0,"2":3.0}"#, serde_json::to_string(&poly).unwrap());
+        assert_eq!("{}", serde_json::to_string(&Polynomial::zero()).unwrap());
+    }
+
+    #[test]
+    fn deserialization_drops_explicit_zeros() {
+        let poly: Polynomial = serde_json::from_str(r#"{"3": 0.0, "1": 2.0, "0": -0.0}"#).unwrap();
+        assert_eq!(Polynomial::from_coefficients(&vec![2.0, 0.0]), poly);
+        assert_eq!(Some(1), poly.degree());
+
+        #[derive(Deserialize)]
+        struct Dense(#[serde(with = "super::dense")] Polynomial);
+        let Dense(poly) = serde_json::from_str("[0.0, 0.0, 1.0, 0.0]").unwrap();
+        assert_eq!(Some(1), poly.degree());
+    }
+
+    #[test]
+    fn deserialization_rejects_duplicate_powers() {
+        let result = serde_json::from_str::<Polynomial>(r#"{"1": 2.0, "1": 0.0}"#);
+        assert!(result.unwrap_err().to_string().contains("duplicate power 1"));
+    }
+
+    #[test]
+    fn deserialization_rejects_invalid_strings() {
+        #[derive(Deserialize, Debug)]
+        struct Text(#[serde(with = "super::string")] #[allow(dead_code)] Polynomial);
+        let err = serde_json::from_str::<Text>(r#""2x^2 + 3y""#).unwrap_err();
+        assert!(err.to_string().contains("Unexpected input at bytes 8..9."));
+    }
+}
@@ -0,0 +1,242 @@
+//! Module containing the sparse text format of polynomials, in which only the nonzero terms are
+//! written as `power:coefficient` pairs, e.g. `1000000:1 17:-3 0:1`.
+//!
+//! The pairs are separated by whitespace, including newlines, and written in the descending
+//! order of powers, but they are read in any order. The coefficients are written with the
+//! shortest representation which is parsed back to the same `f64`. The zero polynomial is
+//! written as an empty string.
+use std::collections::btree_map::Entry;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Write;
+use std::ops::Range;
+use super::Polynomial;
+
+/// Error returned by [`Polynomial::from_sparse_str`].
+///
+/// The spans are the ranges of byte offsets of the offending fragment of the string.
+#[derive(PartialEq, Debug, Clone)]
+pub enum SparseParseError {
+    /// A pair is not of the form `power:coefficient`.
+    MalformedPair {
+        span: Range<usize>,
+    },
+
+    /// A power is not a valid `u32`.
+    InvalidPower {
+        span: Range<usize>,
+    },
+
+    /// A coefficient is not a valid number.
+    InvalidCoefficient {
+        span: Range<usize>,
+    },
+
+    /// A power appears in more than one pair.
+    DuplicatePower {
+        power: u32,
+        span: Range<usize>,
+    },
+}
+
+impl SparseParseError {
+    /// Returns the range of byte offsets of the fragment which caused the error.
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            SparseParseError::MalformedPair { span } => span.clone(),
+            SparseParseError::InvalidPower { span } => span.clone(),
+            SparseParseError::InvalidCoefficient { span } => span.clone(),
+            SparseParseError::DuplicatePower { span, .. } => span.clone(),
+        }
+    }
+}
+
+impl fmt::Display for SparseParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Range { start, end } = self.span();
+        match self {
+            SparseParseError::MalformedPair { .. } => {
+                write!(f, "Malformed pair at bytes {start}..{end}.")
+            }
+            SparseParseError::InvalidPower { .. } => {
+                write!(f, "Invalid power at bytes {start}..{end}.")
+            }
+            SparseParseError::InvalidCoefficient { .. } => {
+                write!(f, "Invalid coefficient at bytes {start}..{end}.")
+            }
+            SparseParseError::DuplicatePower { power, .. } => {
+                write!(f, "Duplicate power {power} at bytes {start}..{end}.")
+            }
+        }
+    }
+}
+
+impl Error for SparseParseError {}
+
+/// Returns the whitespace-separated fragments of the string with their byte offsets.
+fn pairs(s: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut rest = s;
+    let mut offset = 0;
+    std::iter::from_fn(move || {
+        let trimmed = rest.trim_start();
+        offset += rest.len() - trimmed.len();
+        if trimmed.is_empty() {
+            return None;
+        }
+        let end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+        let pair = (offset, &trimmed[..end]);
+        offset += end;
+        rest = &trimmed[end..];
+        Some(pair)
+    })
+}
+
+impl Polynomial {
+    /// Returns the polynomial in the sparse text format, as the `power:coefficient`
+    /// pairs of the nonzero terms separated by spaces.
+    ///
+    /// The pairs are written in the descending order of powers, and the coefficients with the
+    /// shortest representation which is parsed back to the same `f64`, so
+    /// [`from_sparse_str`](Polynomial::from_sparse_str) restores the polynomial exactly. The
+    /// zero polynomial is written as an empty string. The length of the string depends only on
+    /// the number of the nonzero terms, not on the degree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// let poly: Polynomial = "x^1000000 - 3x^17 + 1".parse().unwrap();
+    /// assert_eq!("1000000:1 17:-3 0:1", poly.to_sparse_string());
+    /// assert_eq!("", Polynomial::zero().to_sparse_string());
+    /// ```
+    pub fn to_sparse_string(&self) -> String {
+        let mut string = String::new();
+        for (power, coefficient) in self.coefficients.iter().rev() {
+            if !string.is_empty() {
+                string.push(' ');
+            }
+            write!(string, "{power}:{coefficient}").expect("Writing to a String cannot fail");
+        }
+        string
+    }
+
+    /// Parses the polynomial from the sparse text format written by
+    /// [`to_sparse_string`](Polynomial::to_sparse_string).
+    ///
+    /// The pairs may be separated by any whitespace and given in any order. A pair with a zero
+    /// coefficient is accepted, but it doesn't add a term.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SparseParseError`] with the span of the first pair which is malformed, has an
+    /// invalid power or coefficient, or repeats a power.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{Polynomial, SparseParseError};
+    ///
+    /// let poly = Polynomial::from_sparse_str("0:1\n  17:-3\t1000000:1").unwrap();
+    /// assert_eq!(Some(1_000_000), poly.degree());
+    /// assert_eq!(-3.0, poly.get_coefficient_at(17));
+    ///
+    /// let err = Polynomial::from_sparse_str("2:1 0:5 2:3").unwrap_err();
+    /// assert_eq!(SparseParseError::DuplicatePower { power: 2, span: 8..11 }, err);
+    /// ```
+    pub fn from_sparse_str(s: &str) -> Result<Polynomial, SparseParseError> {
+        let mut poly = Polynomial::zero();
+        let mut powers = std::collections::BTreeMap::new();
+        for (offset, pair) in pairs(s) {
+            let span = offset..offset + pair.len();
+            let Some((power, coefficient)) = pair.split_once(':') else {
+                return Err(SparseParseError::MalformedPair { span });
+            };
+            let power: u32 = power.parse().map_err(|_| SparseParseError::InvalidPower {
+                span: offset..offset + power.len(),
+            })?;
+            let coefficient_start = span.end - coefficient.len();
+            let coefficient: f64 = coefficient.parse().map_err(|_| SparseParseError::InvalidCoefficient {
+                span: coefficient_start..span.end,
+            })?;
+            match powers.entry(power) {
+                Entry::Occupied(_) => return Err(SparseParseError::DuplicatePower { power, span }),
+                Entry::Vacant(entry) => entry.insert(()),
+            };
+            poly.set_coefficient_at(power, coefficient);
+        }
+        Ok(poly)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::SparseParseError;
+    use crate::Polynomial;
+    use crate::polynomial::strategy::polynomials_with;
+
+    #[test]
+    fn huge_degree_is_written_in_constant_space() {
+        let mut poly = Polynomial::zero();
+        poly.set_coefficient_at(u32::MAX, 2.5);
+        poly.set_coefficient_at(0, -1.0);
+        let string = poly.to_sparse_string();
+        // get_coefficients would return 2^32 values
+        assert_eq!("4294967295:2.5 0:-1", string);
+        assert_eq!(poly, Polynomial::from_sparse_str(&string).unwrap());
+    }
+
+    #[test]
+    fn whitespace_and_order_are_free() {
+        let poly = Polynomial::from_sparse_str("\n 3:1e-3\r\n\t0:-0.5   1:2\n").unwrap();
+        assert_eq!(vec![0.001, 0.0, 2.0, -0.5], poly.get_coefficients());
+        assert_eq!(Polynomial::zero(), Polynomial::from_sparse_str("  \n").unwrap());
+        assert_eq!(Polynomial::zero(), Polynomial::from_sparse_str("5:0 0:-0").unwrap());
+    }
+
+    #[test]
+    fn errors_are_positioned() {
+        assert_eq!(
+            Err(SparseParseError::MalformedPair { span: 4..7 }),
+            Polynomial::from_sparse_str("1:2 3-4")
+        );
+        assert_eq!(
+            Err(SparseParseError::InvalidPower { span: 5..7 }),
+            Polynomial::from_sparse_str("1:2  -1:4")
+        );
+        assert_eq!(
+            Err(SparseParseError::InvalidPower { span: 0..10 }),
+            Polynomial::from_sparse_str("4294967296:1")
+        );
+        assert_eq!(
+            Err(SparseParseError::InvalidCoefficient { span: 6..9 }),
+            Polynomial::from_sparse_str("1:2\n0:1:2")
+        );
+        assert_eq!(
+            Err(SparseParseError::InvalidCoefficient { span: 2..2 }),
+            Polynomial::from_sparse_str("0:")
+        );
+        assert_eq!(
+            Err(SparseParseError::DuplicatePower { power: 0, span: 5..8 }),
+            Polynomial::from_sparse_str("0:0 \n0:1")
+        );
+    }
+
+    #[test]
+    fn error_display() {
+        let err = Polynomial::from_sparse_str("1:2 1:x").unwrap_err();
+        assert_eq!("Invalid coefficient at bytes 6..7.", err.to_string());
+        let err = Polynomial::from_sparse_str("1:2 1:3").unwrap_err();
+        assert_eq!("Duplicate power 1 at bytes 4..7.", err.to_string());
+    }
+
+    proptest! {
+        #[test]
+        fn sparse_string_round_trips(
+            poly in polynomials_with(any::<f64>().prop_filter("Finite coefficient", |c| c.is_finite()), u32::MAX)
+        ) {
+            prop_assert_eq!(&poly, &Polynomial::from_sparse_str(&poly.to_sparse_string()).unwrap());
+        }
+    }
+}
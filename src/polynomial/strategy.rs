@@ -0,0 +1,167 @@
+//! Module containing the [`mod@proptest`] strategies generating polynomials, enabled with the
+//! `proptest` feature.
+//!
+//! The generated polynomials are well-formed, i.e. they have finite coefficients and bounded
+//! degrees, and the zero polynomial, constants, sparse polynomials of high degree and
+//! polynomials with all coefficients negative are generated with a reasonable probability.
+//!
+//! # Examples
+//!
+//! ```
+//! use polynomials::Polynomial;
+//! use polynomials::strategy::polynomials;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn derivative_lowers_degree(poly in polynomials(10)) {
+//!         if let Some(degree) = poly.degree().filter(|degree| *degree > 0) {
+//!             prop_assert_eq!(Some(degree - 1), poly.derivative().degree());
+//!         }
+//!     }
+//! }
+//! # derivative_lowers_degree();
+//! ```
+use proptest::prelude::*;
+use proptest::arbitrary::Arbitrary;
+use proptest::strategy::BoxedStrategy;
+use super::Polynomial;
+
+/// Returns a strategy generating polynomials of degree at most `max_degree`, with the
+/// coefficients in the range from `-1e6` to `1e6`.
+pub fn polynomials(max_degree: u32) -> BoxedStrategy<Polynomial> {
+    polynomials_with(-1e6..1e6, max_degree)
+}
+
+/// Returns a strategy generating polynomials of degree at most `max_degree`, with the non-zero
+/// coefficients drawn from the given strategy.
+///
+/// Drawing the coefficients from a small range of integers keeps the arithmetic exact, which
+/// is useful for checking algebraic identities:
+///
+/// ```
+/// use polynomials::strategy::polynomials_with;
+/// use proptest::prelude::*;
+///
+/// let strategy = polynomials_with((-5..=5).prop_map(f64::from), 8);
+/// ```
+pub fn polynomials_with<S>(coefficients: S, max_degree: u32) -> BoxedStrategy<Polynomial>
+where
+    S: Strategy<Value = f64> + Clone + 'static,
+{
+    let max_terms = max_degree as usize + 1;
+    let dense = prop::collection::vec(coefficients.clone(), 1..=max_terms.min(16))
+        .prop_map(|coefficients| Polynomial::from_coefficients(&coefficients));
+    let sparse = prop::collection::btree_map(0..=max_degree, coefficients.clone(), 1..=4)
+        .prop_map(|terms| {
+            let mut poly = Polynomial::zero();
+            for (power, coefficient) in terms {
+                poly.set_coefficient_at(power, coefficient);
+            }
+            poly
+        });
+    let negative = prop::collection::vec(coefficients.clone(), 1..=max_terms.min(16))
+        .prop_map(|coefficients| {
+            let coefficients: Vec<f64> = coefficients.iter().map(|c| -c.abs()).collect();
+            Polynomial::from_coefficients(&coefficients)
+        });
+    let constant = coefficients.prop_map(|coefficient| Polynomial::from_coefficients(&vec![coefficient]));
+
+    prop_oneof![
+        1 => Just(Polynomial::zero()),
+        2 => constant,
+        2 => sparse,
+        2 => negative,
+        5 => dense,
+    ]
+    .boxed()
+}
+
+/// Generates polynomials of degree at most 32 with the [`polynomials`] strategy.
+impl Arbitrary for Polynomial {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Polynomial>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        polynomials(32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::{polynomials, polynomials_with};
+    use crate::Polynomial;
+
+    /// Polynomials with small integer coefficients, for which the arithmetic is exact.
+    fn exact() -> BoxedStrategy<Polynomial> {
+        polynomials_with((-5..=5).prop_map(f64::from), 8)
+    }
+
+    /// Monic polynomials with small integer coefficients, so that the division is exact.
+    fn monic() -> BoxedStrategy<Polynomial> {
+        prop::collection::vec((-5..=5).prop_map(f64::from), 0..6)
+            .prop_map(|lower_coefficients| {
+                let mut coefficients = vec![1.0];
+                coefficients.extend(lower_coefficients);
+                Polynomial::from_coefficients(&coefficients)
+            })
+            .boxed()
+    }
+
+    proptest! {
+        #[test]
+        fn generated_polynomials_are_well_formed(poly in polynomials(40)) {
+            prop_assert!(poly.degree().is_none_or(|degree| degree <= 40));
+            prop_assert!(poly.coefficients.values().all(|c| c.is_finite() && *c != 0.0));
+        }
+
+        #[test]
+        fn addition_is_commutative_and_associative(p in exact(), q in exact(), r in exact()) {
+            prop_assert_eq!(p.clone() + &q, q.clone() + &p);
+            prop_assert_eq!((p.clone() + &q) + &r, p + &(q + &r));
+        }
+
+        #[test]
+        fn addition_identity_and_inverse(p in exact()) {
+            prop_assert_eq!(p.clone() + &Polynomial::zero(), p.clone());
+            prop_assert!((p.clone() + &-p).is_zero());
+        }
+
+        #[test]
+        fn multiplication_is_commutative_and_associative(p in exact(), q in exact(), r in exact()) {
+            prop_assert_eq!(p.clone() * &q, q.clone() * &p);
+            prop_assert_eq!((p.clone() * &q) * &r, p * &(q * &r));
+        }
+
+        #[test]
+        fn multiplication_identity_and_distributivity(p in exact(), q in exact(), r in exact()) {
+            prop_assert_eq!(p.clone() * &Polynomial::one(), p.clone());
+            prop_assert_eq!(p.clone() * &(q.clone() + &r), p.clone() * &q + &(p * &r));
+        }
+
+        #[test]
+        fn product_is_divisible_by_factor(p in exact(), q in monic()) {
+            prop_assert!(((p * &q) % &q).is_zero());
+        }
+
+        #[test]
+        fn parsing_inverts_formatting(p in polynomials(40)) {
+            prop_assert_eq!(p.to_string().parse::<Polynomial>().unwrap(), p);
+        }
+
+        #[test]
+        fn ordering_is_antisymmetric_and_transitive(p in exact(), q in exact(), r in exact()) {
+            prop_assert_eq!(p.cmp(&q), q.cmp(&p).reverse());
+            prop_assert_eq!(p.cmp(&q).is_eq(), p == q);
+            if p <= q && q <= r {
+                prop_assert!(p <= r);
+            }
+        }
+
+        #[test]
+        fn evaluation_distributes_over_addition(p in exact(), q in exact(), x in -3..=3) {
+            let x = f64::from(x);
+            prop_assert_eq!((p.clone() + &q).evaluate(x), p.evaluate(x) + q.evaluate(x));
+        }
+    }
+}
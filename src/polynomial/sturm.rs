@@ -0,0 +1,186 @@
+use super::{Polynomial, Sign};
+use super::inequality::evaluation_error;
+use super::modular::{max_abs_coefficient, TOLERANCE};
+
+/// Returns the sign of the polynomial at `x`, which is zero if the value is zero up to the
+/// rounding errors. At an infinite `x` the sign is the one of the leading term.
+pub(super) fn sign_at(poly: &Polynomial, x: f64) -> Sign {
+    if x.is_finite() {
+        if poly.evaluate(x).abs() <= evaluation_error(poly, x) {
+            return Sign::Zero;
+        }
+        return poly.sign_at(x);
+    }
+    let Some(degree) = poly.degree() else {
+        return Sign::Zero;
+    };
+    let leading_coefficient = poly.get_coefficient_at(degree);
+    let negative = (leading_coefficient < 0.0) != (x < 0.0 && degree % 2 == 1);
+    if negative { Sign::Negative } else { Sign::Positive }
+}
+
+/// Returns the number of the sign changes in the values of the polynomials at `x`, with the
+/// zero values skipped.
+fn sign_variations(sequence: &[Polynomial], x: f64) -> usize {
+    let mut variations = 0;
+    let mut previous = Sign::Zero;
+    for sign in sequence.iter().map(|poly| sign_at(poly, x)) {
+        if sign == Sign::Zero {
+            continue;
+        }
+        if previous != Sign::Zero && sign != previous {
+            variations += 1;
+        }
+        previous = sign;
+    }
+    variations
+}
+
+impl Polynomial {
+    /// Returns the Sturm sequence of the polynomial: the polynomial, its derivative, and then
+    /// the negated remainders of the division of the previous two, up to the last nonzero one.
+    ///
+    /// The coefficients of every remainder smaller than `1e-10` relative to the largest
+    /// coefficient of the polynomial and its derivative are treated as the rounding errors of
+    /// a cancelled term and dropped. The last polynomial is their greatest common divisor, so
+    /// it is a constant unless the polynomial has multiple roots. The sequence of the zero
+    /// polynomial is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // x^3 - 3x + 1
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, -3.0, 1.0]);
+    /// let sequence = poly.sturm_sequence();
+    /// assert_eq!(4, sequence.len());
+    /// assert_eq!(vec![3.0, 0.0, -3.0], sequence[1].get_coefficients());
+    /// assert_eq!(vec![2.0, -1.0], sequence[2].get_coefficients());
+    /// ```
+    pub fn sturm_sequence(&self) -> Vec<Polynomial> {
+        if self.is_zero() {
+            return Vec::new();
+        }
+        let derivative = self.derivative();
+        let threshold = TOLERANCE * max_abs_coefficient(self).max(max_abs_coefficient(&derivative));
+        let mut sequence = vec![self.clone()];
+        let mut next = derivative;
+        while !next.is_zero() {
+            let mut remainder = -(&sequence[sequence.len() - 1] % &next);
+            remainder.coefficients.retain(|_, coefficient| coefficient.abs() > threshold);
+            sequence.push(std::mem::replace(&mut next, remainder));
+        }
+        sequence
+    }
+
+    /// Returns the number of the distinct real roots of the polynomial in the half-open
+    /// interval `(a, b]`, as the difference of the numbers of the sign changes in the Sturm
+    /// sequence at the ends of the interval.
+    ///
+    /// The ends may be infinite, so all the real roots are counted in the interval from
+    /// negative to positive infinity. A multiple root is counted once, since the sequence is
+    /// divided by its last element, the greatest common divisor of the polynomial and its
+    /// derivative, which gives the Sturm sequence of the square-free part. The count is zero
+    /// for an empty interval and for the zero polynomial.
+    ///
+    /// The coefficients are `f64` values, so the values that are zero up to the rounding errors
+    /// of the evaluation are skipped as zeros, which detects a root at an end of the interval.
+    /// The count is exact only as long as the rounding errors in the remainders do not change
+    /// the signs, i.e. unless the roots are very close to each other or to the ends of the
+    /// interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::Polynomial;
+    ///
+    /// // x^5 - 3x + 1, with roots near -1.39, 0.33 and 1.21
+    /// let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0, 0.0, -3.0, 1.0]);
+    /// assert_eq!(3, poly.count_real_roots_in(f64::NEG_INFINITY, f64::INFINITY));
+    /// assert_eq!(2, poly.count_real_roots_in(0.0, 2.0));
+    /// ```
+    pub fn count_real_roots_in(&self, a: f64, b: f64) -> usize {
+        if a >= b {
+            return 0;
+        }
+        let mut sequence = self.sturm_sequence();
+        if let Some(gcd) = sequence.last().filter(|gcd| gcd.degree() > Some(0)).cloned() {
+            for poly in &mut sequence {
+                *poly = (&*poly / &gcd).quotient;
+            }
+        }
+        sign_variations(&sequence, a).saturating_sub(sign_variations(&sequence, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Polynomial;
+
+    fn from_roots(roots: &[f64]) -> Polynomial {
+        roots.iter()
+            .map(|root| Polynomial::from_coefficients(&vec![1.0, -root]))
+            .product()
+    }
+
+    #[test]
+    fn quintic_roots_in_intervals() {
+        // x^5 - 3x + 1, with roots near -1.389, 0.335 and 1.215
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 0.0, 0.0, -3.0, 1.0]);
+        let count = |a, b| poly.count_real_roots_in(a, b);
+        assert_eq!(3, count(f64::NEG_INFINITY, f64::INFINITY));
+        assert_eq!(3, count(-2.0, 2.0));
+        assert_eq!(1, count(-2.0, 0.0));
+        assert_eq!(2, count(0.0, 2.0));
+        assert_eq!(1, count(0.3, 0.4));
+        assert_eq!(0, count(0.4, 1.2));
+        assert_eq!(1, count(1.2, 1.3));
+        assert_eq!(0, count(1.3, f64::INFINITY));
+        assert_eq!(1, count(f64::NEG_INFINITY, -1.0));
+        assert_eq!(0, count(2.0, -2.0));
+    }
+
+    #[test]
+    fn roots_at_the_ends() {
+        // The interval is half-open, so a root at the lower end is not counted
+        let poly = from_roots(&[-1.0, 0.5, 2.0]);
+        assert_eq!(2, poly.count_real_roots_in(-1.0, 2.0));
+        assert_eq!(3, poly.count_real_roots_in(-1.5, 2.0));
+        assert_eq!(1, poly.count_real_roots_in(0.5, 2.0));
+        assert_eq!(1, poly.count_real_roots_in(0.0, 0.5));
+    }
+
+    #[test]
+    fn multiple_roots_are_counted_once() {
+        // (x - 1)^2 (x + 2)^3 (x^2 + 1)
+        let poly = from_roots(&[1.0, 1.0, -2.0, -2.0, -2.0]) * Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]);
+        assert!(poly.sturm_sequence().last().unwrap().degree() > Some(0));
+        assert_eq!(2, poly.count_real_roots_in(f64::NEG_INFINITY, f64::INFINITY));
+        assert_eq!(1, poly.count_real_roots_in(0.0, 1.0));
+        assert_eq!(1, poly.count_real_roots_in(-2.0, 1.0));
+        assert_eq!(1, poly.count_real_roots_in(-3.0, -2.0));
+    }
+
+    #[test]
+    fn sequence_ends_with_a_constant_for_simple_roots() {
+        let poly = from_roots(&[-3.0, 0.5, 1.0, 4.0]);
+        let sequence = poly.sturm_sequence();
+        assert_eq!(5, sequence.len());
+        assert_eq!(poly.derivative(), sequence[1]);
+        assert_eq!(Some(0), sequence.last().unwrap().degree());
+        assert_eq!(4, poly.count_real_roots_in(f64::NEG_INFINITY, f64::INFINITY));
+    }
+
+    #[test]
+    fn constants_have_no_roots() {
+        assert!(Polynomial::zero().sturm_sequence().is_empty());
+        assert_eq!(0, Polynomial::zero().count_real_roots_in(-1.0, 1.0));
+        let constant = Polynomial::from_coefficients(&vec![2.0]);
+        assert_eq!(vec![constant.clone()], constant.sturm_sequence());
+        assert_eq!(0, constant.count_real_roots_in(f64::NEG_INFINITY, f64::INFINITY));
+        // x^2 + 1
+        let poly = Polynomial::from_coefficients(&vec![1.0, 0.0, 1.0]);
+        assert_eq!(0, poly.count_real_roots_in(f64::NEG_INFINITY, f64::INFINITY));
+    }
+}
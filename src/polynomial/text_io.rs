@@ -0,0 +1,229 @@
+//! Module containing the reading and writing of coefficients as plain text, one value per line
+//! or separated by commas, for exchanging polynomials with other tools.
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::io::{BufRead, BufReader};
+use super::Polynomial;
+use super::display::TermOrder;
+
+/// Specifies how the coefficients written by `Polynomial.write_coefficients` are separated.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum CoefficientSeparator {
+    /// Every coefficient is written on its own line.
+    Newline,
+
+    /// The coefficients are written on a single line, separated by commas.
+    Comma,
+}
+
+/// Error returned by [`Polynomial::read_coefficients`].
+#[derive(Debug)]
+pub enum ReadError {
+    /// Reading from the reader failed.
+    Io(io::Error),
+
+    /// The value on the line, counted from one, is not a number.
+    InvalidValue {
+        line: usize,
+        value: String,
+    },
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(error) => write!(f, "Failed to read the coefficients: {error}"),
+            ReadError::InvalidValue { line, value } => {
+                write!(f, "Invalid coefficient '{value}' on line {line}.")
+            }
+        }
+    }
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadError::Io(error) => Some(error),
+            ReadError::InvalidValue { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(error: io::Error) -> Self {
+        ReadError::Io(error)
+    }
+}
+
+impl Polynomial {
+    /// Writes the coefficients of every power from the degree down to zero, or the other way
+    /// around, as plain text. The zero polynomial is written as a single `0`.
+    ///
+    /// The values are written with the shortest representation which is parsed back to the
+    /// same `f64`, so [`read_coefficients`](Polynomial::read_coefficients) restores the
+    /// polynomial exactly. The output ends with a newline.
+    ///
+    /// The order of `numpy.polyfit` and MATLAB's `polyfit` is `TermOrder::Descending`, and the
+    /// order of `numpy.polynomial.Polynomial` is `TermOrder::Ascending`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{CoefficientSeparator, Polynomial, TermOrder};
+    ///
+    /// let poly = Polynomial::from_coefficients(&vec![2.0, 0.0, -0.5]);
+    /// let mut buffer = Vec::new();
+    /// poly.write_coefficients(&mut buffer, TermOrder::Ascending, CoefficientSeparator::Comma).unwrap();
+    /// assert_eq!("-0.5,0,2\n", String::from_utf8(buffer).unwrap());
+    /// ```
+    pub fn write_coefficients<W: io::Write>(
+        &self,
+        mut writer: W,
+        order: TermOrder,
+        separator: CoefficientSeparator,
+    ) -> io::Result<()> {
+        let mut coefficients = self.get_coefficients();
+        if coefficients.is_empty() {
+            coefficients.push(0.0);
+        }
+        if order == TermOrder::Ascending {
+            coefficients.reverse();
+        }
+        let separator = match separator {
+            CoefficientSeparator::Newline => "\n",
+            CoefficientSeparator::Comma => ",",
+        };
+        for (index, coefficient) in coefficients.iter().enumerate() {
+            if index > 0 {
+                writer.write_all(separator.as_bytes())?;
+            }
+            write!(writer, "{coefficient}")?;
+        }
+        writer.write_all(b"\n")
+    }
+
+    /// Reads the coefficients written as plain text in the given order, with the values
+    /// separated by newlines, commas or both.
+    ///
+    /// Everything after a `#` on a line is a comment, the blank lines are skipped, and a comma
+    /// may end a line. The values are parsed as `f64`, so the exponent notation of e.g.
+    /// `numpy.savetxt` is accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ReadError::InvalidValue` with the number of the line of the first value which is
+    /// not a number, and `ReadError::Io` if reading fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polynomials::{Polynomial, TermOrder};
+    ///
+    /// let text = "# fitted cubic\n1.0\n0\n-2.5e-1\n3\n\n";
+    /// let poly = Polynomial::read_coefficients(text.as_bytes(), TermOrder::Descending).unwrap();
+    /// assert_eq!("x^3 - 0.25x + 3", poly.to_string());
+    ///
+    /// let error = Polynomial::read_coefficients("1, 2\n3, x\n".as_bytes(), TermOrder::Ascending);
+    /// assert_eq!("Invalid coefficient 'x' on line 2.", error.unwrap_err().to_string());
+    /// ```
+    pub fn read_coefficients<R: io::Read>(reader: R, order: TermOrder) -> Result<Polynomial, ReadError> {
+        let mut coefficients = Vec::new();
+        for (index, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            let content = line.split('#').next().unwrap_or_default().trim();
+            if content.is_empty() {
+                continue;
+            }
+            let content = content.strip_suffix(',').unwrap_or(content);
+            for value in content.split(',').map(str::trim) {
+                let coefficient = value.parse::<f64>().map_err(|_| ReadError::InvalidValue {
+                    line: index + 1,
+                    value: value.to_string(),
+                })?;
+                coefficients.push(coefficient);
+            }
+        }
+        if order == TermOrder::Ascending {
+            coefficients.reverse();
+        }
+        Ok(Polynomial::from_coefficients(&coefficients))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::Cursor;
+    use super::{CoefficientSeparator, ReadError};
+    use crate::{Polynomial, TermOrder};
+
+    fn round_trip(poly: &Polynomial, order: TermOrder, separator: CoefficientSeparator) -> Polynomial {
+        let mut cursor = Cursor::new(Vec::new());
+        poly.write_coefficients(&mut cursor, order, separator).unwrap();
+        cursor.set_position(0);
+        Polynomial::read_coefficients(cursor, order).unwrap()
+    }
+
+    #[test]
+    fn round_trip_is_exact() {
+        let poly = Polynomial::from_coefficients(&vec![1.0 / 3.0, 0.0, -2e-300, f64::MAX, 0.1, 0.0]);
+        for order in [TermOrder::Ascending, TermOrder::Descending] {
+            for separator in [CoefficientSeparator::Newline, CoefficientSeparator::Comma] {
+                assert_eq!(poly, round_trip(&poly, order, separator));
+                assert_eq!(Polynomial::zero(), round_trip(&Polynomial::zero(), order, separator));
+            }
+        }
+    }
+
+    #[test]
+    fn write_newline_separated() {
+        let poly = Polynomial::from_coefficients(&vec![1.5, 0.0, -2.0]);
+        let mut buffer = Vec::new();
+        poly.write_coefficients(&mut buffer, TermOrder::Descending, CoefficientSeparator::Newline).unwrap();
+        assert_eq!("1.5\n0\n-2\n", String::from_utf8(buffer).unwrap());
+
+        let mut buffer = Vec::new();
+        Polynomial::zero().write_coefficients(&mut buffer, TermOrder::Ascending, CoefficientSeparator::Comma).unwrap();
+        assert_eq!("0\n", String::from_utf8(buffer).unwrap());
+    }
+
+    #[test]
+    fn order_is_explicit() {
+        let text = "1\n2\n3\n";
+        let descending = Polynomial::read_coefficients(text.as_bytes(), TermOrder::Descending).unwrap();
+        let ascending = Polynomial::read_coefficients(text.as_bytes(), TermOrder::Ascending).unwrap();
+        assert_eq!("x^2 + 2x + 3", descending.to_string());
+        assert_eq!("3x^2 + 2x + 1", ascending.to_string());
+    }
+
+    #[test]
+    fn read_tolerates_comments_blank_lines_and_mixed_separators() {
+        let text = "\n# header\n 1, 2 # first two\n\n3,\r\n4\n\n\n";
+        let poly = Polynomial::read_coefficients(text.as_bytes(), TermOrder::Descending).unwrap();
+        assert_eq!(vec![1.0, 2.0, 3.0, 4.0], poly.get_coefficients());
+        let empty = Polynomial::read_coefficients("# nothing\n".as_bytes(), TermOrder::Descending).unwrap();
+        assert!(empty.is_zero());
+    }
+
+    #[test]
+    fn read_reports_line_of_malformed_value() {
+        let text = "# header\n1\n\n2.5.1\n";
+        match Polynomial::read_coefficients(text.as_bytes(), TermOrder::Descending) {
+            Err(ReadError::InvalidValue { line, value }) => assert_eq!((4, "2.5.1"), (line, value.as_str())),
+            result => panic!("unexpected result {result:?}"),
+        }
+        assert!(matches!(
+            Polynomial::read_coefficients("1,,2".as_bytes(), TermOrder::Descending),
+            Err(ReadError::InvalidValue { line: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn read_numpy_polyfit_output() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/polyfit.txt");
+        let poly = Polynomial::read_coefficients(File::open(path).unwrap(), TermOrder::Descending).unwrap();
+        assert_eq!(vec![1.0, -0.25, -1.75, 1.0], poly.get_coefficients());
+        assert_eq!(20.5, poly.evaluate(3.0));
+    }
+}
@@ -0,0 +1,43 @@
+//! Module containing the validation of the names of the indeterminate, shared by the
+//! formatting and parsing methods.
+
+/// Characters which are a part of the polynomial string syntax.
+const RESERVED_CHARACTERS: &str = "+-−*·^/().,";
+
+/// Names which would be mistaken for the imaginary unit.
+const RESERVED_NAMES: [&str; 2] = ["i", "j"];
+
+/// Checks if the given string may be used as the name of the indeterminate.
+///
+/// A valid name is not empty, does not start with a digit, and contains neither whitespace
+/// nor the characters used by the polynomial syntax, so that it cannot be confused with a
+/// coefficient, a power or a sign.
+pub(crate) fn is_valid_variable(variable: &str) -> bool {
+    let Some(first) = variable.chars().next() else {
+        return false;
+    };
+    !first.is_numeric()
+        && !RESERVED_NAMES.contains(&variable)
+        && !variable.chars().any(|c| {
+            c.is_whitespace() || RESERVED_CHARACTERS.contains(c) || "⁰¹²³⁴⁵⁶⁷⁸⁹".contains(c)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_valid_variable;
+
+    #[test]
+    fn valid_variables() {
+        for variable in ["x", "t", "z", "x1", "x_1", "theta", "\\theta", "λ"] {
+            assert!(is_valid_variable(variable), "{variable}");
+        }
+    }
+
+    #[test]
+    fn invalid_variables() {
+        for variable in ["", "1", "2x", "i", "j", ".", "x.y", "a/b", "x y", "-x", "x^", "x²", "(x)"] {
+            assert!(!is_valid_variable(variable), "{variable}");
+        }
+    }
+}
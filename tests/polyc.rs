@@ -0,0 +1,86 @@
+//! Builds the `polyc` example, runs it and compares its output to the expected one.
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use std::sync::OnceLock;
+
+/// Builds the example once, with the features of the tests, and returns its path.
+fn executable() -> &'static PathBuf {
+    static EXECUTABLE: OnceLock<PathBuf> = OnceLock::new();
+    EXECUTABLE.get_or_init(|| {
+        let mut build = Command::new(env!("CARGO"));
+        build.args(["build", "--quiet", "--example", "polyc"]);
+        if cfg!(feature = "serde") {
+            build.args(["--features", "serde"]);
+        }
+        assert!(build.status().unwrap().success(), "the polyc example failed to build");
+
+        let mut path = std::env::current_exe().unwrap();
+        path.pop();
+        if path.ends_with("deps") {
+            path.pop();
+        }
+        path.join("examples").join(format!("polyc{}", std::env::consts::EXE_SUFFIX))
+    })
+}
+
+fn polyc(args: &[&str]) -> Output {
+    Command::new(executable()).args(args).output().unwrap()
+}
+
+fn assert_stdout(args: &[&str], expected: &str) {
+    let output = polyc(args);
+    assert_eq!(Some(0), output.status.code(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(expected, String::from_utf8(output.stdout).unwrap());
+}
+
+fn assert_exit_code(args: &[&str], code: i32) {
+    let output = polyc(args);
+    assert_eq!(Some(code), output.status.code());
+    assert!(output.stdout.is_empty());
+    assert!(output.stderr.starts_with(b"error: "));
+}
+
+#[test]
+fn eval() {
+    assert_stdout(&["eval", "2x^2-3x+1", "--at", "1.5"], "1\n");
+    assert_stdout(&["eval", "--coeffs", "1,0,-1", "--at", "-3"], "8\n");
+}
+
+#[test]
+fn arithmetic() {
+    assert_stdout(&["add", "x + 1", "--coeffs", "1,0,-1"], "x^2 + x\n");
+    assert_stdout(&["sub", "x^2", "x^2 - x"], "x\n");
+    assert_stdout(&["mul", "x + 1", "x - 1"], "x^2 - 1\n");
+    assert_stdout(&["divrem", "x^3 - 2x + 1", "x - 2"], "quotient: x^2 + 2x + 2\nremainder: 5\n");
+    assert_stdout(&["derive", "x^3 - 2x"], "3x^2 - 2\n");
+}
+
+#[test]
+fn formats() {
+    assert_stdout(&["fmt", "x^12 - 3x^2"], "x^12 - 3x^2\n");
+    assert_stdout(&["fmt", "x^12 - 3x^2", "--latex"], "x^{12} - 3x^{2}\n");
+    assert_stdout(&["fmt", "x^12 - 3x^2", "--unicode"], "x¹² - 3x²\n");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json() {
+    assert_stdout(&["eval", "x^2", "--at", "3", "--json"], "9.0\n");
+    assert_stdout(&["mul", "x", "2x", "--json"], "{\"2\":2.0}\n");
+    assert_stdout(
+        &["divrem", "x^2 + 1", "x", "--json"],
+        "{\"quotient\":{\"1\":1.0},\"remainder\":{\"0\":1.0}}\n"
+    );
+}
+
+#[test]
+fn exit_codes() {
+    assert_exit_code(&["add", "x + * 1", "x"], 1);
+    assert_exit_code(&["eval", "--coeffs", "1,a"], 1);
+    assert_exit_code(&[], 2);
+    assert_exit_code(&["pow", "x"], 2);
+    assert_exit_code(&["add", "x"], 2);
+    assert_exit_code(&["eval", "x"], 2);
+    assert_exit_code(&["fmt", "x", "--bold"], 2);
+    assert_exit_code(&["divrem", "x", "0"], 3);
+}